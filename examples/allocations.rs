@@ -0,0 +1,40 @@
+//! This example counts the heap allocations made while planning the scenario
+//! from the `long_plan` example, which is useful to check the cost of
+//! successor generation.
+
+mod common;
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use rust_goap::prelude::*;
+
+/// Wraps the system allocator and counts every allocation.
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn main() {
+    let (start, goal, actions) = common::long_plan();
+
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    let plan = make_plan(&start, &actions, &goal).expect("the long plan is reachable");
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - before;
+
+    println!("Found a plan with {} steps and cost {}", plan.len(), plan.cost());
+    println!("Planning made {allocations} heap allocations");
+}
@@ -8,19 +8,23 @@ fn main() {
     let goal = Goal::new().with("is_hungry", Assert::eq(false));
 
     // Define available actions
-    let buy_food = Action::new("buy_food")
-        .with_effect(Effect { mutations: vec![Mutation::set("has_food", true)], cost: 2 });
+    let buy_food = Action::new("buy_food").with_effect(Effect {
+        mutations: vec![Mutation::set("has_food", true)],
+        cost: 2,
+        ..Default::default()
+    });
 
     let eat =
         Action::new("eat").with_precondition(("has_food", Assert::eq(true))).with_effect(Effect {
             mutations: vec![Mutation::set("is_hungry", false), Mutation::set("has_food", false)],
             cost: 1,
+            ..Default::default()
         });
 
     let actions = vec![buy_food, eat];
 
     // Find the optimal plan
     if let Some(plan) = make_plan(&start, &actions, &goal) {
-        println!("{}", format_plan(plan.clone()));
+        println!("{}", format_plan(&plan));
     }
 }
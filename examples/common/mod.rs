@@ -0,0 +1,42 @@
+//! The scenario of the `long_plan` example, shared with the examples that
+//! measure the planner on it.
+
+use rust_goap::prelude::*;
+
+/// Returns the start state, the goal and the actions of a plan with lots of
+/// steps: robbing people for 7 gold, while sleeping and eating in between to
+/// keep up the energy and keep down the hunger robbing needs.
+pub fn long_plan() -> (WorldState, Goal, [Action; 3]) {
+    let start = WorldState::new().set("energy", 30_i64).set("hunger", 70_i64).set("gold", 0_i64);
+
+    let goal = Goal::new().with("gold", Assert::eq(7_i64));
+
+    let sleep_action = Action::new("sleep").with_effect(Effect {
+        mutations: vec![Mutation::increment("energy", 10)],
+        cost: 1,
+        ..Default::default()
+    });
+
+    let eat_action = Action::new("eat")
+        .with_effect(Effect {
+            mutations: vec![Mutation::decrement("hunger", 10)],
+            cost: 1,
+            ..Default::default()
+        })
+        .with_precondition(("energy", Assert::gt_eq(26_i64)));
+
+    let rob_people = Action::new("rob")
+        .with_effect(Effect {
+            mutations: vec![
+                Mutation::increment("gold", 1),
+                Mutation::decrement("energy", 5),
+                Mutation::increment("hunger", 5),
+            ],
+            cost: 1,
+            ..Default::default()
+        })
+        .with_precondition(("hunger", Assert::lt_eq(50_i64)))
+        .with_precondition(("energy", Assert::gt_eq(50_i64)));
+
+    (start, goal, [sleep_action, eat_action, rob_people])
+}
@@ -0,0 +1,50 @@
+//! This example compares the built-in heuristics on the scenario from the
+//! `long_plan` example, reporting how many nodes each search expands and how
+//! long it takes to find the plan. It fails if `EstimatedApplications` stops
+//! expanding fewer nodes than `SumOfDistances`.
+
+mod common;
+
+use std::time::Instant;
+
+use rust_goap::prelude::*;
+
+/// Number of plans made per heuristic, to get a stable timing.
+const RUNS: u32 = 100;
+
+fn main() {
+    let (start, goal, actions) = common::long_plan();
+
+    let mut nodes_expanded = Vec::new();
+    for heuristic in [
+        HeuristicKind::Zero,
+        HeuristicKind::SumOfDistances,
+        HeuristicKind::EstimatedApplications,
+        HeuristicKind::Hamming,
+    ] {
+        let config = PlanningConfig::new().with_heuristic(heuristic);
+        let (plan, stats) = make_plan_with_config_and_stats(&config, &start, &actions, &goal);
+        let cost = plan.expect("the long plan is reachable").cost();
+
+        let started = Instant::now();
+        for _ in 0..RUNS {
+            make_plan_with_config(&config, &start, &actions, &goal);
+        }
+        let per_plan = started.elapsed() / RUNS;
+
+        println!(
+            "{heuristic:?}: cost {cost}, {} nodes expanded, {per_plan:?} per plan",
+            stats.nodes_expanded
+        );
+        nodes_expanded.push((heuristic, stats.nodes_expanded));
+    }
+
+    // Estimating the applications of each action should guide the search
+    // better than summing the distances of the values
+    let expanded =
+        |kind| nodes_expanded.iter().find(|(heuristic, _)| *heuristic == kind).unwrap().1;
+    assert!(
+        expanded(HeuristicKind::EstimatedApplications) < expanded(HeuristicKind::SumOfDistances),
+        "EstimatedApplications should expand fewer nodes than SumOfDistances"
+    );
+}
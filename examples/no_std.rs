@@ -0,0 +1,31 @@
+//! This example checks that planning works without the standard library. It
+//! is a `#![no_std]` library rather than a binary, since a `no_std` binary
+//! needs a platform-specific entry point and panic handler.
+//!
+//! Build it with the `std` feature disabled:
+//!
+//! ```bash
+//! cargo build --example no_std --no-default-features
+//! ```
+
+#![no_std]
+
+#[macro_use]
+extern crate alloc;
+
+mod common;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use rust_goap::prelude::*;
+
+/// Plans the `long_plan` scenario and returns the keys of its actions.
+pub fn plan_for_gold() -> Option<(Vec<String>, usize)> {
+    let (start, goal, actions) = common::long_plan();
+
+    let plan = make_plan(&start, &actions, &goal)?;
+    let cost = plan.cost();
+    let keys = get_effects_from_plan(&plan).map(|(key, _, _)| key).collect();
+    Some((keys, cost))
+}
@@ -1,7 +1,16 @@
-use std::hash::{Hash, Hasher};
+use alloc::collections::BTreeMap;
+use alloc::{format, string::String, string::ToString, vec, vec::Vec};
+use core::cmp::Ordering;
+use core::fmt::Display;
+use core::hash::{Hash, Hasher};
+use core::ops::Deref;
 
-use crate::basic::assert::{Assert, compare_values};
+use crate::basic::assert::{Assert, compare_values, order};
+use crate::basic::mutation::{Mutation, format_mutations};
+use crate::basic::value::Value;
 use crate::effect::Effect;
+use crate::error::ActionError;
+use crate::goal::Goal;
 use crate::world_state::WorldState;
 
 /// Represents an executable action in a Goal-Oriented Action Planning (GOAP) system.
@@ -23,13 +32,13 @@ use crate::world_state::WorldState;
 /// let attack_action = Action::new("Attack")
 ///     .with_precondition(("has_weapon", Assert::eq(true)))
 ///     .with_precondition(("enemy_in_range", Assert::eq(true)))
-///     .with_effect(Effect::new().with_mutation("enemy_health", Mutation::decrement("enemy_health", 10)));
+///     .with_effect(Effect::new().add_mutation(Mutation::decrement("enemy_health", 10)));
 ///
 /// // Create an action for gathering resources
 /// let gather_action = Action::new("GatherWood")
 ///     .with_precondition(("has_axe", Assert::eq(true)))
 ///     .with_precondition(("near_forest", Assert::eq(true)))
-///     .with_effect(Effect::new().with_mutation("wood_count", Mutation::increment("wood_count", 1)));
+///     .with_effect(Effect::new().add_mutation(Mutation::increment("wood_count", 1)));
 /// ```
 #[derive(Clone, Debug, PartialEq, Default)]
 pub struct Action {
@@ -54,17 +63,112 @@ pub struct Action {
     /// action is successfully executed. If `None`, the action has no effect
     /// on the world state (though it may still have other purposes).
     pub effect: Option<Effect>,
+
+    /// Other outcomes the action may have instead of `effect`.
+    ///
+    /// This models nondeterministic actions, such as an attack that can hit or
+    /// miss. The planner considers every outcome, each with its own cost, as a
+    /// separate way to execute the action and uses whichever suits the plan,
+    /// so the plan assumes that outcome actually happens. Empty by default.
+    pub alternatives: Vec<Effect>,
+
+    /// The maximum number of times this action may appear in a single plan.
+    ///
+    /// This models limited resources that aren't tracked in the world state,
+    /// such as a fixed number of health potions. The planner never expands the
+    /// action beyond its limit and routes around it instead. `None` (the
+    /// default) means the action can be used any number of times.
+    pub max_uses: Option<usize>,
+
+    /// An optional group of mutually exclusive actions this action belongs to.
+    ///
+    /// Once an action of a group is used in a plan, no *other* action of the
+    /// same group can be used later in that plan, which models committing to
+    /// one approach (e.g. "stealth" versus "charge"). Repeating the committed
+    /// action is still allowed. `None` (the default) means the action doesn't
+    /// conflict with any other action.
+    pub group: Option<String>,
 }
 
 impl Hash for Action {
     /// Computes a hash value for the action.
     ///
-    /// The hash includes the action key, all preconditions, and the effect.
+    /// The hash includes the action key, all preconditions, the effect and its
+    /// alternatives, the usage limit, and the exclusive group.
     /// This enables using `Action` instances as keys in hash-based collections.
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.key.hash(state);
         self.preconditions.hash(state);
         self.effect.hash(state);
+        self.alternatives.hash(state);
+        self.max_uses.hash(state);
+        self.group.hash(state);
+    }
+}
+
+impl Display for Action {
+    /// Formats the action on one line: its key, preconditions, and the
+    /// mutations and cost of each outcome, alternatives separated by `|`.
+    /// An action without a main effect prints `(no effect)` in its place,
+    /// followed by any alternatives.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let eat = Action::new("eat")
+    ///     .with_precondition(("has_food", Assert::eq(true)))
+    ///     .with_effect(Effect::new().add_mutation(Mutation::set("is_hungry", false)));
+    /// assert_eq!(
+    ///     format!("{eat}"),
+    ///     "Action \"eat\" [pre: has_food == Value:Bool(true)] -> {set: is_hungry = Value:Bool(false)} cost 1"
+    /// );
+    ///
+    /// let scavenge = Action::new("scavenge")
+    ///     .with_effect(
+    ///         Effect::new()
+    ///             .add_mutation(Mutation::increment("food", 2))
+    ///             .add_mutation(Mutation::decrement("energy", 1))
+    ///             .with_cost(3),
+    ///     )
+    ///     .with_alternative(Effect::new().add_mutation(Mutation::decrement("energy", 1)));
+    /// let formatted = format!("{scavenge}");
+    /// assert!(formatted.contains("[pre: none]"));
+    /// assert!(formatted.contains("increment: food + Value:I64(2), decrement: energy - Value:I64(1)"));
+    /// assert!(formatted.contains("cost 3 | {decrement: energy - Value:I64(1)} cost 1"));
+    ///
+    /// assert_eq!(format!("{}", Action::new("wait")), "Action \"wait\" [pre: none] (no effect)");
+    ///
+    /// let gamble = Action::new("gamble")
+    ///     .with_alternative(Effect::new().add_mutation(Mutation::increment("gold", 5)));
+    /// assert_eq!(
+    ///     format!("{gamble}"),
+    ///     "Action \"gamble\" [pre: none] (no effect) | {increment: gold + Value:I64(5)} cost 1"
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Action {:?} [pre: ", self.key)?;
+        if self.preconditions.is_empty() {
+            write!(f, "none")?;
+        }
+        for (index, (key, assert)) in self.preconditions.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{key} {assert}")?;
+        }
+        write!(f, "]")?;
+        let outcomes = self.effect.iter().map(|effect| (" ->", effect));
+        let alternatives = self.alternatives.iter().map(|effect| (" |", effect));
+        if self.effect.is_none() {
+            write!(f, " (no effect)")?;
+        }
+        for (separator, effect) in outcomes.chain(alternatives) {
+            let mutations = format_mutations(effect.mutations.clone());
+            let mutations = mutations.lines().collect::<Vec<_>>().join(", ");
+            write!(f, "{separator} {{{mutations}}} cost {}", effect.fixed_cost())?;
+        }
+        Ok(())
     }
 }
 
@@ -73,7 +177,7 @@ impl Action {
     ///
     /// # Arguments
     /// * `key` - A unique identifier for the action. Can be any type that
-    ///           implements `Into<String>`.
+    ///   implements `Into<String>`.
     ///
     /// # Returns
     /// A new `Action` instance with no preconditions and no effect.
@@ -88,7 +192,7 @@ impl Action {
     /// assert!(action.effect.is_none());
     /// ```
     pub fn new(key: impl Into<String>) -> Self {
-        Self { key: key.into(), preconditions: vec![], effect: None }
+        Self { key: key.into(), ..Default::default() }
     }
 
     /// Adds a precondition to the action.
@@ -118,13 +222,127 @@ impl Action {
         self
     }
 
+    /// Adds several preconditions to the action at once.
+    ///
+    /// This mirrors [`Goal::from_reqs`](crate::prelude::Goal::from_reqs) and
+    /// appends the conditions after any existing preconditions, exactly as
+    /// chained [`Action::with_precondition`] calls would.
+    ///
+    /// # Arguments
+    /// * `conditions` - A slice of (key, assertion) tuples to require
+    ///
+    /// # Returns
+    /// The modified `Action` instance (for method chaining).
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let conditions = vec![
+    ///     ("has_weapon".to_string(), Assert::eq(true)),
+    ///     ("ammo_count".to_string(), Assert::gt(0)),
+    /// ];
+    /// let effect = Effect::new().add_mutation(Mutation::decrement("ammo_count", 1));
+    ///
+    /// let batched = Action::new("Attack").with_preconditions(&conditions).with_effect(effect.clone());
+    /// let chained = Action::new("Attack")
+    ///     .with_precondition(("has_weapon", Assert::eq(true)))
+    ///     .with_precondition(("ammo_count", Assert::gt(0)))
+    ///     .with_effect(effect);
+    /// assert_eq!(batched, chained);
+    ///
+    /// // Both actions lead to the same plan
+    /// let start = WorldState::new().set("has_weapon", true).set("ammo_count", 2);
+    /// let goal = Goal::new().with("ammo_count", Assert::eq(0));
+    /// let batched_plan = make_plan(&start, &[batched], &goal).unwrap();
+    /// let chained_plan = make_plan(&start, &[chained], &goal).unwrap();
+    /// assert_eq!(batched_plan, chained_plan);
+    /// ```
+    pub fn with_preconditions(mut self, conditions: &[(String, Assert)]) -> Self {
+        self.preconditions.extend_from_slice(conditions);
+        self
+    }
+
+    /// Creates an action with the given identifier and effect.
+    ///
+    /// This is a shortcut for `Action::new(key).with_effect(effect)`.
+    ///
+    /// # Arguments
+    /// * `key` - A unique identifier for the action
+    /// * `effect` - The `Effect` that will be applied to the world state
+    ///   when this action is executed
+    ///
+    /// # Returns
+    /// A new `Action` instance with no preconditions.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let effect = Effect::new().add_mutation(Mutation::increment("wood", 1));
+    /// let action = Action::from_effect("chop", effect.clone());
+    ///
+    /// assert_eq!(action, Action::new("chop").with_effect(effect));
+    /// ```
+    pub fn from_effect(key: impl Into<String>, effect: Effect) -> Self {
+        Self::new(key).with_effect(effect)
+    }
+
+    /// Creates an action that waits by advancing a time key.
+    ///
+    /// An action whose effect has no mutations leads back to the state it was
+    /// applied to, so the planner discards it as an already explored state
+    /// and never uses it (see [`Action::is_noop_in`]). Waiting only matters
+    /// when time is part of the world state: this action increments
+    /// `time_key` by `duration`, so preconditions and goals on the time key
+    /// can become satisfied by waiting. The effect has the default cost of 1;
+    /// build the effect with [`Action::from_effect`] for a different cost.
+    ///
+    /// # Arguments
+    /// * `key` - A unique identifier for the action
+    /// * `time_key` - The state key holding the current time
+    /// * `duration` - How much time passes per wait
+    ///
+    /// # Returns
+    /// A new `Action` instance with no preconditions.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// // The bread is ready at time 3
+    /// let start = WorldState::new().set("time", 0).set("has_bread", false);
+    /// let goal = Goal::new().with("has_bread", Assert::eq(true));
+    ///
+    /// let take_bread = Action::new("take_bread")
+    ///     .with_precondition(("time", Assert::gt_eq(3)))
+    ///     .with_effect(Effect::new().add_mutation(Mutation::set("has_bread", true)));
+    /// let wait = Action::wait("wait", "time", 1);
+    ///
+    /// let plan = make_plan(&start, &[take_bread.clone(), wait], &goal).unwrap();
+    /// let keys: Vec<_> = plan.effects().map(|(key, _, _)| key).collect();
+    /// assert_eq!(keys, ["wait", "wait", "wait", "take_bread"]);
+    ///
+    /// // Waiting without advancing time gets nowhere
+    /// let idle = Action::new("idle").with_effect(Effect::new());
+    /// assert!(make_plan(&start, &[take_bread, idle], &goal).is_none());
+    /// ```
+    pub fn wait(
+        key: impl Into<String>,
+        time_key: impl Into<String>,
+        duration: impl Into<Value>,
+    ) -> Self {
+        let effect = Effect::new().add_mutation(Mutation::increment(time_key, duration));
+        Self::new(key).with_effect(effect)
+    }
+
     /// Sets the effect that occurs when this action is executed.
     ///
     /// This method uses the builder pattern, allowing for fluent chaining.
     ///
     /// # Arguments
     /// * `effect` - The `Effect` that will be applied to the world state
-    ///              when this action is executed.
+    ///   when this action is executed.
     ///
     /// # Returns
     /// The modified `Action` instance (for method chaining).
@@ -133,7 +351,7 @@ impl Action {
     /// ```
     /// use rust_goap::prelude::*;
     ///
-    /// let effect = Effect::new().with_mutation("enemy_health", Mutation::decrement("enemy_health", 10));
+    /// let effect = Effect::new().add_mutation(Mutation::decrement("enemy_health", 10));
     /// let action = Action::new("Attack").with_effect(effect);
     ///
     /// assert!(action.effect.is_some());
@@ -143,6 +361,129 @@ impl Action {
         self
     }
 
+    /// Adds an alternative outcome to the action.
+    ///
+    /// See [`Action::alternatives`] for how the planner uses alternative outcomes.
+    ///
+    /// # Arguments
+    /// * `effect` - An `Effect` that may occur instead of the action's effect
+    ///
+    /// # Returns
+    /// The modified `Action` instance (for method chaining).
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// // Attacking either hits the enemy or misses and alerts them
+    /// let attack = Action::new("attack")
+    ///     .with_effect(Effect::new().add_mutation(Mutation::decrement("enemy_health", 10)))
+    ///     .with_alternative(Effect::new().add_mutation(Mutation::set("enemy_alerted", true)));
+    /// assert_eq!(attack.effects().count(), 2);
+    ///
+    /// let start = WorldState::new().set("enemy_health", 10).set("enemy_alerted", false);
+    ///
+    /// // The planner explores both outcomes and picks the one reaching the goal
+    /// let defeat = Goal::new().with("enemy_health", Assert::eq(0));
+    /// let plan = make_plan(&start, &[attack.clone()], &defeat).unwrap();
+    /// assert_eq!(plan.steps()[1].effect(), attack.effect.as_ref());
+    ///
+    /// let provoke = Goal::new().with("enemy_alerted", Assert::eq(true));
+    /// let plan = make_plan(&start, &[attack.clone()], &provoke).unwrap();
+    /// assert_eq!(plan.steps()[1].effect(), attack.alternatives.first());
+    /// assert_eq!(plan.steps()[1].state().get("enemy_health"), Some(&Value::I64(10)));
+    /// ```
+    pub fn with_alternative(mut self, effect: Effect) -> Self {
+        self.alternatives.push(effect);
+        self
+    }
+
+    /// Returns all possible outcomes of the action.
+    ///
+    /// # Returns
+    /// An iterator over the effect, if any, followed by the alternatives.
+    pub fn effects(&self) -> impl Iterator<Item = &Effect> {
+        self.effect.iter().chain(&self.alternatives)
+    }
+
+    /// Limits how many times this action may appear in a single plan.
+    ///
+    /// This method uses the builder pattern, allowing for fluent chaining.
+    ///
+    /// # Arguments
+    /// * `max_uses` - The maximum number of uses per plan
+    ///
+    /// # Returns
+    /// The modified `Action` instance (for method chaining).
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let start = WorldState::new().set("health", 10);
+    /// let goal = Goal::new().with("health", Assert::gt_eq(70));
+    ///
+    /// // Only two potions are available, but three are needed to reach the goal
+    /// let drink_potion = Action::new("drink_potion")
+    ///     .with_effect(Effect::new().add_mutation(Mutation::increment("health", 20)))
+    ///     .with_max_uses(2);
+    /// assert!(make_plan(&start, &[drink_potion.clone()], &goal).is_none());
+    ///
+    /// // With a slower alternative, the planner routes around the limit
+    /// let rest = Action::new("rest")
+    ///     .with_effect(Effect::new().add_mutation(Mutation::increment("health", 20)).with_cost(5));
+    /// let plan = make_plan(&start, &[drink_potion, rest], &goal).unwrap();
+    /// let keys: Vec<&str> = plan.effects().map(|(key, _, _)| key).collect();
+    /// assert_eq!(keys.iter().filter(|key| **key == "drink_potion").count(), 2);
+    /// assert_eq!(keys.iter().filter(|key| **key == "rest").count(), 1);
+    /// assert_eq!(plan.cost(), 7);
+    /// ```
+    pub fn with_max_uses(mut self, max_uses: usize) -> Self {
+        self.max_uses = Some(max_uses);
+        self
+    }
+
+    /// Adds the action to a group of mutually exclusive actions.
+    ///
+    /// This method uses the builder pattern, allowing for fluent chaining.
+    /// See [`Action::group`] for how groups restrict plans.
+    ///
+    /// # Arguments
+    /// * `group` - The name of the group
+    ///
+    /// # Returns
+    /// The modified `Action` instance (for method chaining).
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let start = WorldState::new().set("near_base", false).set("base_taken", false);
+    /// let goal = Goal::new().with("base_taken", Assert::eq(true));
+    ///
+    /// // Sneaking in and storming the base are two incompatible approaches
+    /// let sneak_in = Action::new("sneak_in")
+    ///     .with_effect(Effect::new().add_mutation(Mutation::set("near_base", true)))
+    ///     .with_group("approach");
+    /// let storm = Action::new("storm")
+    ///     .with_precondition(("near_base", Assert::eq(true)))
+    ///     .with_effect(Effect::new().add_mutation(Mutation::set("base_taken", true)))
+    ///     .with_group("approach");
+    /// let sabotage = Action::new("sabotage")
+    ///     .with_precondition(("near_base", Assert::eq(true)))
+    ///     .with_effect(Effect::new().add_mutation(Mutation::set("base_taken", true)).with_cost(3));
+    ///
+    /// // Without groups, sneaking in and storming would be the cheapest plan (cost 2)
+    /// let plan = make_plan(&start, &[sneak_in, storm, sabotage], &goal).unwrap();
+    /// let keys: Vec<&str> = plan.effects().map(|(key, _, _)| key).collect();
+    /// assert_eq!(keys, ["sneak_in", "sabotage"]);
+    /// assert_eq!(plan.cost(), 4);
+    /// ```
+    pub fn with_group(mut self, group: impl Into<String>) -> Self {
+        self.group = Some(group.into());
+        self
+    }
+
     /// Checks whether all preconditions are satisfied in the given world state.
     ///
     /// This is the core validation function that determines if an action
@@ -154,9 +495,17 @@ impl Action {
     /// # Returns
     /// `true` if all preconditions are satisfied, `false` otherwise.
     ///
+    /// A precondition on a world state variable that doesn't exist doesn't
+    /// hold, like in [`Assert::check`]. Such preconditions usually point to a
+    /// misconfigured domain, which [`try_make_plan`](crate::prelude::try_make_plan)
+    /// reports as [`PlanError::MissingKey`](crate::prelude::PlanError::MissingKey).
+    ///
     /// # Panics
-    /// Panics if a precondition references a world state variable that doesn't exist.
-    /// This is a deliberate design choice to catch configuration errors early.
+    /// With the `strict` feature, panics if a precondition references a world
+    /// state variable that doesn't exist, unless the precondition is
+    /// [`Assert::Exists`], [`Assert::Absent`] or [`Assert::All`], which inspect
+    /// the presence of the key directly. This catches configuration errors
+    /// early while debugging.
     ///
     /// # Examples
     /// ```
@@ -186,12 +535,839 @@ impl Action {
     /// assert!(!action.check_preconditions(&bad_state));
     /// ```
     pub fn check_preconditions(&self, world_state: &WorldState) -> bool {
-        self.preconditions.iter().all(|(key, compare)| {
-            let state_value = world_state
-                .0
-                .get(key)
-                .unwrap_or_else(|| panic!("Couldn't find key {key:#?} in WorldState"));
-            compare_values(compare, state_value)
+        #[cfg(feature = "strict")]
+        if let Some(key) = self.missing_precondition_key(world_state) {
+            panic!("Couldn't find key {key:#?} in WorldState");
+        }
+        self.preconditions.iter().all(|(key, compare)| compare.check(world_state, key))
+    }
+
+    /// Returns the key of the first precondition that compares the value of
+    /// a variable missing from `world_state`.
+    ///
+    /// Preconditions that inspect the presence of their key ([`Assert::Exists`],
+    /// [`Assert::Absent`] and [`Assert::All`]) are meant to handle missing
+    /// keys, so they are never reported.
+    pub(crate) fn missing_precondition_key(&self, world_state: &WorldState) -> Option<&str> {
+        self.preconditions
+            .iter()
+            .find(|(key, compare)| {
+                !world_state.contains_key(key)
+                    && !matches!(compare, Assert::Exists | Assert::Absent | Assert::All(_))
+            })
+            .map(|(key, _)| key.as_str())
+    }
+
+    /// Checks whether performing the action would leave a world state
+    /// unchanged.
+    ///
+    /// Such actions are usually a configuration mistake, like an effect that
+    /// sets a key to the value it must already have. Every outcome of the
+    /// action is considered, so an action is only a no-op if none of its
+    /// effects change the state; an action without any effect always is.
+    /// Preconditions are ignored, and effects that can't be applied to the
+    /// state (see [`Effect::validate`]) don't count as no-ops.
+    ///
+    /// # Arguments
+    /// * `state` - The world state to apply the action's effects to
+    ///
+    /// # Returns
+    /// `true` if no effect of the action changes `state`, `false` otherwise.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let state = WorldState::new().set("door_open", true).set("keys", 0);
+    ///
+    /// // Opening an open door changes nothing
+    /// let open_door = Action::new("open_door")
+    ///     .with_precondition(("door_open", Assert::eq(true)))
+    ///     .with_effect(Effect::new().add_mutation(Mutation::set("door_open", true)));
+    /// assert!(open_door.is_noop_in(&state));
+    /// assert!(!open_door.is_noop_in(&state.clone().set("door_open", false)));
+    ///
+    /// // Picking up a key does
+    /// let pick_up_key = Action::new("pick_up_key")
+    ///     .with_effect(Effect::new().add_mutation(Mutation::increment("keys", 1)));
+    /// assert!(!pick_up_key.is_noop_in(&state));
+    ///
+    /// // So does an action with at least one effective outcome
+    /// let fumble = open_door.clone().with_alternative(
+    ///     Effect::new().add_mutation(Mutation::set("door_open", false)),
+    /// );
+    /// assert!(!fumble.is_noop_in(&state));
+    ///
+    /// assert!(Action::new("wait").is_noop_in(&state));
+    /// ```
+    pub fn is_noop_in(&self, state: &WorldState) -> bool {
+        self.effects().all(|effect| {
+            let mut new_state = state.clone();
+            effect.validate(state).is_ok() && {
+                effect.apply_to(&mut new_state);
+                new_state == *state
+            }
+        })
+    }
+
+    /// Checks whether the action can bring a state closer to satisfying a goal.
+    ///
+    /// An action is relevant if one of its outcomes has a mutation that moves a
+    /// key the goal constrains in a satisfying direction:
+    /// - a set (including `set_if_absent`) to a value satisfying the requirement
+    /// - a delete of a key the goal requires to be absent
+    /// - an increment, decrement or `set_min`/`set_max` in a direction that
+    ///   can satisfy the requirement, e.g. up for `gt`, either way for `eq`
+    /// - a mutation whose direction can't be known without a state, such as
+    ///   `compute` or `add_from_key`, of a constrained key
+    ///
+    /// Regression planning only considers relevant actions as the last step
+    /// toward a goal.
+    ///
+    /// # Arguments
+    /// * `goal` - The goal to check the action against
+    ///
+    /// # Returns
+    /// `true` if the action can help satisfy the goal, `false` otherwise.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let goal = Goal::new().with("gold", Assert::gt_eq(100)).with("is_cursed", Assert::eq(false));
+    ///
+    /// // Mining moves gold up
+    /// let mine = Action::new("mine")
+    ///     .with_effect(Effect::new().add_mutation(Mutation::increment("gold", 10)));
+    /// assert!(mine.is_relevant_to(&goal));
+    ///
+    /// // Chopping wood doesn't touch the goal
+    /// let chop = Action::new("chop")
+    ///     .with_effect(Effect::new().add_mutation(Mutation::increment("wood", 1)));
+    /// assert!(!chop.is_relevant_to(&goal));
+    ///
+    /// // Shopping and cursing change constrained keys in the wrong direction
+    /// let shop = Action::new("shop")
+    ///     .with_effect(Effect::new().add_mutation(Mutation::decrement("gold", 10)));
+    /// assert!(!shop.is_relevant_to(&goal));
+    /// let curse = Action::new("curse")
+    ///     .with_effect(Effect::new().add_mutation(Mutation::set("is_cursed", true)));
+    /// assert!(!curse.is_relevant_to(&goal));
+    ///
+    /// // An exact target can be reached from either side
+    /// assert!(shop.is_relevant_to(&Goal::new().with("gold", Assert::eq(50))));
+    /// ```
+    pub fn is_relevant_to(&self, goal: &Goal) -> bool {
+        self.effects()
+            .any(|effect| effect.mutations.iter().any(|mutation| advances(mutation, goal)))
+    }
+
+    /// Estimates how many times the action must be applied in a row to
+    /// satisfy the goal requirements it moves toward.
+    ///
+    /// The estimate is derived from the deltas of the action's effect: an
+    /// increment or decrement moves a key by the same amount each time, so a
+    /// requirement `d` away takes `d / amount` applications (rounded up), and
+    /// a set to a satisfying value takes one. Requirements moved by several
+    /// mutations use the fastest one, and the action needs as many
+    /// applications as its slowest requirement. Preconditions, alternative
+    /// effects and mutations whose delta depends on the state (such as
+    /// `compute`) are not considered, so this is a hint rather than a bound.
+    ///
+    /// [`HeuristicKind::EstimatedApplications`](crate::prelude::HeuristicKind::EstimatedApplications)
+    /// uses this to estimate the cost of plans that repeat an action many
+    /// times.
+    ///
+    /// # Arguments
+    /// * `state` - The world state the action would be applied to
+    /// * `goal` - The goal whose requirements the action should satisfy
+    ///
+    /// # Returns
+    /// * `Some(applications)` if the effect moves at least one unsatisfied
+    ///   requirement closer to its target
+    /// * `None` otherwise
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let state = WorldState::new().set("gold", 0).set("wood", 2).set("has_axe", false);
+    /// let rob = Action::new("rob")
+    ///     .with_effect(Effect::new().add_mutation(Mutation::increment("gold", 1)));
+    /// let mine = Action::new("mine")
+    ///     .with_effect(Effect::new().add_mutation(Mutation::increment("gold", 3)));
+    ///
+    /// let goal = Goal::new().with("gold", Assert::eq(7));
+    /// assert_eq!(rob.estimated_applications(&state, &goal), Some(7));
+    /// assert_eq!(mine.estimated_applications(&state, &goal), Some(3));
+    ///
+    /// // The slowest requirement decides
+    /// let trade = Action::new("trade").with_effect(
+    ///     Effect::new()
+    ///         .add_mutation(Mutation::increment("gold", 2))
+    ///         .add_mutation(Mutation::increment("wood", 1))
+    ///         .add_mutation(Mutation::set("has_axe", true)),
+    /// );
+    /// let goal = goal.with("wood", Assert::gt_eq(10)).with("has_axe", Assert::eq(true));
+    /// assert_eq!(trade.estimated_applications(&state, &goal), Some(8));
+    ///
+    /// // Satisfied requirements and wrong directions don't count
+    /// let satisfied = Goal::new().with("wood", Assert::gt_eq(1));
+    /// assert_eq!(trade.estimated_applications(&state, &satisfied), None);
+    /// let spend = Action::new("spend")
+    ///     .with_effect(Effect::new().add_mutation(Mutation::decrement("gold", 1)));
+    /// assert_eq!(spend.estimated_applications(&state, &Goal::new().with("gold", Assert::gt(5))), None);
+    ///
+    /// // Huge values don't overflow
+    /// let hoard = WorldState::new().set("gold", i64::MAX - 1);
+    /// let goal = Goal::new().with("gold", Assert::eq(i64::MAX));
+    /// assert_eq!(mine.estimated_applications(&hoard, &goal), Some(1));
+    /// let goal = Goal::new().with("gold", Assert::lt(0));
+    /// assert_eq!(rob.estimated_applications(&hoard, &goal), None);
+    /// ```
+    pub fn estimated_applications(&self, state: &WorldState, goal: &Goal) -> Option<u64> {
+        goal.requirements
+            .iter()
+            .filter_map(|(key, assert)| self.applications_for(key, state.get(key), assert))
+            .max()
+    }
+
+    /// Estimates how many applications of the action satisfy a single
+    /// requirement on a key holding `current`, see
+    /// [`Action::estimated_applications`].
+    pub(crate) fn applications_for(
+        &self,
+        key: &str,
+        current: Option<&Value>,
+        assert: &Assert,
+    ) -> Option<u64> {
+        if current.map_or(assert.holds_when_missing(), |value| compare_values(assert, value)) {
+            return None;
+        }
+        let effect = self.effect.as_ref()?;
+        effect
+            .mutations
+            .iter()
+            .filter_map(|mutation| applications(mutation, key, current, assert))
+            .min()
+    }
+
+    /// Returns the value `key` drifts to from `value` when the action is
+    /// applied `times` times, following the increments and decrements of its
+    /// effect.
+    pub(crate) fn drift(&self, key: &str, value: Value, times: u64) -> Value {
+        let Some(effect) = &self.effect else { return value };
+        effect.mutations.iter().fold(value, |value, mutation| {
+            let (amount, sign) = match mutation {
+                Mutation::Increment(target, amount) if target == key => (amount, 1),
+                Mutation::Decrement(target, amount) if target == key => (amount, -1),
+                _ => return value,
+            };
+            match (value, amount) {
+                (Value::I64(value), Value::I64(amount)) => {
+                    let total = amount.saturating_mul(i64::try_from(times).unwrap_or(i64::MAX));
+                    Value::I64(value.saturating_add(total.saturating_mul(sign)))
+                },
+                (Value::F64(value), Value::F64(amount)) => {
+                    Value::F64(value + amount * times as f64 * sign as f64)
+                },
+                _ => value,
+            }
         })
     }
+
+    /// Performs the action on a world state, returning the resulting state.
+    ///
+    /// Unlike [`Action::check_preconditions`], this never panics, not even
+    /// with the `strict` feature: a precondition on a missing key simply
+    /// doesn't hold, and an effect that can't be applied to the state (see
+    /// [`Effect::validate`]) is reported as an error. An action without an
+    /// effect returns an unchanged copy of the state. Alternative effects are
+    /// ignored.
+    ///
+    /// # Arguments
+    /// * `state` - The world state to perform the action on
+    ///
+    /// # Returns
+    /// * `Ok(new_state)` with the effect applied
+    /// * `Err(ActionError::PreconditionsNotMet)` if a precondition doesn't hold
+    /// * `Err(ActionError::InvalidEffect(error))` if the effect can't be applied
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let chop = Action::new("chop")
+    ///     .with_precondition(("has_axe", Assert::eq(true)))
+    ///     .with_effect(Effect::new().add_mutation(Mutation::increment("wood", 2)));
+    ///
+    /// let state = WorldState::new().set("has_axe", true).set("wood", 1);
+    /// assert_eq!(chop.apply(&state), Ok(state.clone().set("wood", 3)));
+    ///
+    /// // Preconditions that don't hold, including on missing keys
+    /// let no_axe = WorldState::new().set("has_axe", false).set("wood", 1);
+    /// assert_eq!(chop.apply(&no_axe), Err(ActionError::PreconditionsNotMet));
+    /// assert_eq!(chop.apply(&WorldState::new()), Err(ActionError::PreconditionsNotMet));
+    ///
+    /// // An effect that doesn't fit the state
+    /// let broken = state.clone().set("wood", true);
+    /// assert!(matches!(chop.apply(&broken), Err(ActionError::InvalidEffect(_))));
+    /// ```
+    ///
+    /// Actions without an effect leave the state unchanged:
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let wait = Action::new("wait").with_precondition(("is_idle", Assert::eq(true)));
+    /// let state = WorldState::new().set("is_idle", true);
+    /// assert_eq!(wait.apply(&state), Ok(state));
+    /// ```
+    pub fn apply(&self, state: &WorldState) -> Result<WorldState, ActionError> {
+        if !self.preconditions.iter().all(|(key, compare)| compare.check(state, key)) {
+            return Err(ActionError::PreconditionsNotMet);
+        }
+        let mut new_state = state.clone();
+        if let Some(effect) = &self.effect {
+            effect.validate(state).map_err(ActionError::InvalidEffect)?;
+            effect.apply_to(&mut new_state);
+        }
+        Ok(new_state)
+    }
+}
+
+/// Checks whether a mutation can move a key toward satisfying a goal's
+/// requirement on it, see [`Action::is_relevant_to`].
+fn advances(mutation: &Mutation, goal: &Goal) -> bool {
+    match mutation {
+        Mutation::Set(key, value) | Mutation::SetIfAbsent(key, value) => {
+            goal.get(key).is_some_and(|assert| compare_values(assert, value))
+        },
+        Mutation::Delete(key) => goal.get(key).is_some_and(Assert::holds_when_missing),
+        Mutation::Increment(key, amount)
+        | Mutation::Decrement(key, amount)
+        | Mutation::DecrementSaturating(key, amount) => {
+            let Some(assert) = goal.get(key) else { return false };
+            let direction = match order(amount, &Value::I64(0)) {
+                Some(Ordering::Equal) | None => return false,
+                Some(sign) if matches!(mutation, Mutation::Increment(..)) => sign,
+                Some(sign) => sign.reverse(),
+            };
+            assert.is_comparable_with(amount) && moves_toward(assert, direction)
+        },
+        Mutation::SetMin(key, bound) | Mutation::SetMax(key, bound) => {
+            let direction = match mutation {
+                Mutation::SetMin(..) => Ordering::Greater,
+                _ => Ordering::Less,
+            };
+            goal.get(key).is_some_and(|assert| {
+                assert.is_comparable_with(bound) && moves_toward(assert, direction)
+            })
+        },
+        Mutation::AddFromKey(key, _) | Mutation::SubtractFromKey(key, _) => goal.get(key).is_some(),
+        Mutation::Compute { target, .. } => goal.get(target).is_some(),
+        Mutation::Conditional { mutation, .. } => advances(mutation, goal),
+    }
+}
+
+/// Estimates how many applications of `mutation` move the value of `key` from
+/// `current` to a value satisfying `assert`.
+fn applications(
+    mutation: &Mutation,
+    key: &str,
+    current: Option<&Value>,
+    assert: &Assert,
+) -> Option<u64> {
+    match mutation {
+        Mutation::Set(target, value) if target == key => compare_values(assert, value).then_some(1),
+        Mutation::SetIfAbsent(target, value) if target == key && current.is_none() => {
+            compare_values(assert, value).then_some(1)
+        },
+        Mutation::Increment(target, amount)
+        | Mutation::Decrement(target, amount)
+        | Mutation::DecrementSaturating(target, amount)
+            if target == key =>
+        {
+            let value = current?;
+            if !value.same_type_as(amount) || matches!(value, Value::Bool(_)) {
+                return None;
+            }
+            // Value arithmetic saturates, so values near the bounds of i64 don't
+            // overflow while the heuristic runs on every expanded node
+            let moved = match mutation {
+                Mutation::Increment(..) => *value + *amount,
+                _ => *value - *amount,
+            };
+            let (before, after) = (assert.distance_to(value), assert.distance_to(&moved));
+            (after < before).then(|| before.div_ceil(before - after))
+        },
+        _ => None,
+    }
+}
+
+/// Checks whether changing a value in `direction` (`Greater` for up, `Less`
+/// for down) can help satisfy an assertion.
+fn moves_toward(assert: &Assert, direction: Ordering) -> bool {
+    match assert {
+        Assert::Equals(_) | Assert::NotEquals(_) => true,
+        Assert::GreaterThan(_) | Assert::GreaterThanEquals(_) => direction == Ordering::Greater,
+        Assert::LessThan(_) | Assert::LessThanEquals(_) => direction == Ordering::Less,
+        // Changing a value never changes whether its key is present
+        Assert::Exists | Assert::Absent => false,
+        Assert::All(asserts) => asserts.iter().any(|assert| moves_toward(assert, direction)),
+    }
+}
+
+/// A list of actions indexed by the keys their preconditions read.
+///
+/// The planner only evaluates the preconditions of an action again when the
+/// previous action of the path wrote a key they read, using the same index
+/// internally. With many actions whose preconditions read few keys, this
+/// saves most precondition evaluations (see
+/// [`PlanStats::precondition_checks`](crate::prelude::PlanStats::precondition_checks)).
+///
+/// An `ActionSet` dereferences to a slice of its actions, so it can be passed
+/// to every planning function directly.
+///
+/// # Example
+/// ```
+/// use rust_goap::prelude::*;
+///
+/// let start = WorldState::new().set("energy", 30).set("hunger", 70).set("gold", 0);
+/// let goal = Goal::new().with("gold", Assert::eq(7));
+///
+/// let mut actions = ActionSet::new([
+///     Action::new("sleep")
+///         .with_effect(Effect::new().add_mutation(Mutation::increment("energy", 10))),
+///     Action::new("eat")
+///         .with_precondition(("energy", Assert::gt_eq(26)))
+///         .with_effect(Effect::new().add_mutation(Mutation::decrement("hunger", 10))),
+///     Action::new("rob")
+///         .with_precondition(("hunger", Assert::lt_eq(50)))
+///         .with_precondition(("energy", Assert::gt_eq(50)))
+///         .with_effect(
+///             Effect::new()
+///                 .add_mutation(Mutation::increment("gold", 1))
+///                 .add_mutation(Mutation::decrement("energy", 5))
+///                 .add_mutation(Mutation::increment("hunger", 5)),
+///         ),
+/// ]);
+/// let names = |actions: Vec<&Action>| actions.iter().map(|action| action.key.clone()).collect::<Vec<_>>();
+/// assert_eq!(names(actions.reading("energy").collect()), ["eat", "rob"]);
+/// assert_eq!(names(actions.reading("gold").collect()), Vec::<String>::new());
+///
+/// // Pad the actions with many that never apply
+/// let mut padded: Vec<Action> = actions.to_vec();
+/// padded.extend((0..100).map(|i| {
+///     Action::new(format!("unlock_{i}"))
+///         .with_precondition((format!("has_key_{i}"), Assert::exists()))
+///         .with_effect(Effect::new().add_mutation(Mutation::set(format!("unlocked_{i}"), true)))
+/// }));
+/// actions = ActionSet::from(padded);
+/// assert_eq!(actions.len(), 103);
+///
+/// let (plan, stats) = make_plan_with_stats(&start, &actions, &goal);
+/// assert!(plan.is_some());
+///
+/// // Evaluating every action at every expanded state would take many more checks
+/// assert!(stats.precondition_checks * 10 < stats.nodes_expanded * actions.len());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ActionSet {
+    actions: Vec<Action>,
+    readers: BTreeMap<String, Vec<usize>>,
+}
+
+impl ActionSet {
+    /// Creates an action set, indexing the actions by the keys their
+    /// preconditions read.
+    ///
+    /// # Arguments
+    /// * `actions` - The actions of the set
+    pub fn new(actions: impl IntoIterator<Item = Action>) -> Self {
+        let actions: Vec<Action> = actions.into_iter().collect();
+        let readers = precondition_readers(&actions)
+            .into_iter()
+            .map(|(key, indices)| (key.to_string(), indices))
+            .collect();
+        Self { actions, readers }
+    }
+
+    /// Returns the actions of the set.
+    pub fn actions(&self) -> &[Action] {
+        &self.actions
+    }
+
+    /// Iterates over the actions whose preconditions read `key`, in the order
+    /// of the set.
+    ///
+    /// # Arguments
+    /// * `key` - The world state key
+    pub fn reading(&self, key: &str) -> impl Iterator<Item = &Action> {
+        self.readers.get(key).into_iter().flatten().map(|&index| &self.actions[index])
+    }
+}
+
+impl Deref for ActionSet {
+    type Target = [Action];
+
+    fn deref(&self) -> &[Action] {
+        &self.actions
+    }
+}
+
+impl From<Vec<Action>> for ActionSet {
+    fn from(actions: Vec<Action>) -> Self {
+        Self::new(actions)
+    }
+}
+
+impl FromIterator<Action> for ActionSet {
+    fn from_iter<I: IntoIterator<Item = Action>>(iter: I) -> Self {
+        Self::new(iter)
+    }
+}
+
+/// Maps every key read by the preconditions of `actions` to the indices of
+/// the actions reading it, in ascending order.
+pub(crate) fn precondition_readers(actions: &[Action]) -> BTreeMap<&str, Vec<usize>> {
+    let mut readers: BTreeMap<&str, Vec<usize>> = BTreeMap::new();
+    for (index, action) in actions.iter().enumerate() {
+        for (key, _) in &action.preconditions {
+            let indices = readers.entry(key.as_str()).or_default();
+            if indices.last() != Some(&index) {
+                indices.push(index);
+            }
+        }
+    }
+    readers
+}
+
+/// A parameterized blueprint that expands into several concrete [`Action`]s.
+///
+/// Many domains contain families of actions that only differ by a parameter,
+/// such as "move to room X" for every room. Instead of writing one `Action`
+/// per value, a template declares its parameters with [`ActionTemplate::param`]
+/// and is expanded with [`ActionTemplate::build`] into one action per
+/// combination of parameter values.
+///
+/// # Substitution Syntax
+/// A parameter named `name` is referenced as `{name}` inside any key of the
+/// template: precondition keys, the keys mutations read and write, and the
+/// `cost_per_unit` key of the effect. Each occurrence is replaced by the bound
+/// value. Placeholders that don't match a declared parameter are left as-is.
+///
+/// The key of each expanded action is the template key followed by the bound
+/// values, in parameter declaration order, joined with underscores (e.g.
+/// `move_to_kitchen`).
+///
+/// # Usage Example
+/// ```
+/// use rust_goap::prelude::*;
+///
+/// let actions = ActionTemplate::new("move_to")
+///     .param("room", ["kitchen", "hall", "bedroom"])
+///     .with_precondition(("door_{room}_open", Assert::eq(true)))
+///     .with_effect(Effect::new().add_mutation(Mutation::set("in_{room}", true)))
+///     .build();
+///
+/// assert_eq!(actions.len(), 3);
+/// assert_eq!(actions[0].key, "move_to_kitchen");
+/// assert_eq!(actions[1].key, "move_to_hall");
+/// assert_eq!(actions[2].key, "move_to_bedroom");
+/// assert_eq!(actions[1].preconditions[0].0, "door_hall_open");
+///
+/// // The expanded actions plan like hand-written ones
+/// let start = WorldState::new()
+///     .set("door_hall_open", true)
+///     .set("door_kitchen_open", false)
+///     .set("door_bedroom_open", false);
+/// let goal = Goal::new().with("in_hall", Assert::eq(true));
+/// let plan = make_plan(&start, &actions, &goal).unwrap();
+/// let keys: Vec<String> = get_effects_from_plan(&plan).map(|(key, _, _)| key).collect();
+/// assert_eq!(keys, ["move_to_hall"]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct ActionTemplate {
+    /// Base identifier of the expanded actions.
+    pub key: String,
+
+    /// The declared parameters, each with the values it is expanded over.
+    pub params: Vec<(String, Vec<String>)>,
+
+    /// Preconditions of the expanded actions; keys may contain placeholders.
+    pub preconditions: Vec<(String, Assert)>,
+
+    /// Effect of the expanded actions; keys may contain placeholders.
+    pub effect: Option<Effect>,
+}
+
+impl ActionTemplate {
+    /// Creates a new action template with the given base identifier.
+    ///
+    /// # Arguments
+    /// * `key` - The base identifier of the expanded actions
+    ///
+    /// # Returns
+    /// A new `ActionTemplate` with no parameters, no preconditions and no effect.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let template = ActionTemplate::new("move_to");
+    /// assert_eq!(template.key, "move_to");
+    /// assert!(template.params.is_empty());
+    /// ```
+    pub fn new(key: impl Into<String>) -> Self {
+        Self { key: key.into(), ..Default::default() }
+    }
+
+    /// Declares a parameter and the values it is expanded over.
+    ///
+    /// When several parameters are declared, [`ActionTemplate::build`] expands
+    /// every combination of their values.
+    ///
+    /// # Arguments
+    /// * `name` - The parameter name, referenced as `{name}` in keys
+    /// * `values` - The values to bind the parameter to
+    ///
+    /// # Returns
+    /// The modified `ActionTemplate` instance (for method chaining).
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let actions = ActionTemplate::new("carry")
+    ///     .param("item", ["wood", "stone"])
+    ///     .param("to", ["camp", "mine"])
+    ///     .with_effect(
+    ///         Effect::new().add_mutation(Mutation::increment("{item}_at_{to}", 1)),
+    ///     )
+    ///     .build();
+    ///
+    /// let keys: Vec<&str> = actions.iter().map(|action| action.key.as_str()).collect();
+    /// assert_eq!(keys, ["carry_wood_camp", "carry_wood_mine", "carry_stone_camp", "carry_stone_mine"]);
+    /// ```
+    pub fn param(
+        mut self,
+        name: impl Into<String>,
+        values: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.params.push((name.into(), values.into_iter().map(Into::into).collect()));
+        self
+    }
+
+    /// Adds a precondition to the template.
+    ///
+    /// # Arguments
+    /// * `(key, compare)` - The precondition key, which may contain
+    ///   placeholders, and the assertion that must hold for it
+    ///
+    /// # Returns
+    /// The modified `ActionTemplate` instance (for method chaining).
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let template = ActionTemplate::new("open")
+    ///     .param("door", ["front", "back"])
+    ///     .with_precondition(("has_key_{door}", Assert::eq(true)));
+    ///
+    /// assert_eq!(template.preconditions.len(), 1);
+    /// ```
+    pub fn with_precondition(mut self, (key, compare): (impl Into<String>, Assert)) -> Self {
+        self.preconditions.push((key.into(), compare));
+        self
+    }
+
+    /// Sets the effect of the template.
+    ///
+    /// # Arguments
+    /// * `effect` - The effect of the expanded actions, whose keys may contain
+    ///   placeholders
+    ///
+    /// # Returns
+    /// The modified `ActionTemplate` instance (for method chaining).
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let template = ActionTemplate::new("open")
+    ///     .param("door", ["front", "back"])
+    ///     .with_effect(Effect::new().add_mutation(Mutation::set("{door}_open", true)));
+    ///
+    /// assert!(template.effect.is_some());
+    /// ```
+    pub fn with_effect(mut self, effect: Effect) -> Self {
+        self.effect = Some(effect);
+        self
+    }
+
+    /// Expands the template into concrete actions.
+    ///
+    /// One action is produced per combination of parameter values, in the
+    /// order the values were given (the last declared parameter varies
+    /// fastest). A template without parameters produces a single action.
+    ///
+    /// # Returns
+    /// The expanded actions.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let actions = ActionTemplate::new("open")
+    ///     .param("door", ["front", "back"])
+    ///     .with_effect(Effect::new().add_mutation(Mutation::set("{door}_open", true)))
+    ///     .build();
+    ///
+    /// assert_eq!(actions.len(), 2);
+    /// let effect = actions[1].effect.as_ref().unwrap();
+    /// assert_eq!(effect.mutations[0], Mutation::set("back_open", true));
+    /// ```
+    pub fn build(&self) -> Vec<Action> {
+        let mut bindings: Vec<Vec<(&str, &str)>> = vec![vec![]];
+        for (name, values) in &self.params {
+            bindings = bindings
+                .into_iter()
+                .flat_map(|binding| {
+                    values.iter().map(move |value| {
+                        let mut binding = binding.clone();
+                        binding.push((name.as_str(), value.as_str()));
+                        binding
+                    })
+                })
+                .collect();
+        }
+
+        bindings
+            .into_iter()
+            .map(|binding| {
+                let substitute = |key: &str| {
+                    binding.iter().fold(key.to_string(), |key, (name, value)| {
+                        key.replace(&format!("{{{name}}}"), value)
+                    })
+                };
+                let mut action_key = self.key.clone();
+                for (_, value) in &binding {
+                    action_key.push('_');
+                    action_key.push_str(value);
+                }
+
+                Action {
+                    key: action_key,
+                    preconditions: self
+                        .preconditions
+                        .iter()
+                        .map(|(key, compare)| (substitute(key), compare.clone()))
+                        .collect(),
+                    effect: self.effect.clone().map(|effect| effect.map_keys(&substitute)),
+                    ..Default::default()
+                }
+            })
+            .collect()
+    }
+}
+
+/// Produces a human-readable summary of an action library.
+///
+/// For each action, the summary lists its key, its preconditions, the
+/// mutations of its effect (formatted with [`format_mutations`]), and its cost,
+/// followed by any alternative outcomes.
+/// Since it is generated from the live definitions, it can be used to document
+/// a planning domain without keeping hand-written docs in sync.
+///
+/// # Arguments
+/// * `actions` - The actions to describe
+///
+/// # Returns
+/// A formatted string with one block per action.
+///
+/// # Examples
+/// ```
+/// use rust_goap::prelude::*;
+///
+/// // The actions from the `long_plan` example
+/// let actions = [
+///     Action::new("sleep")
+///         .with_effect(Effect::new().add_mutation(Mutation::increment("energy", 10))),
+///     Action::new("eat")
+///         .with_effect(Effect::new().add_mutation(Mutation::decrement("hunger", 10)))
+///         .with_precondition(("energy", Assert::gt_eq(26_i64))),
+///     Action::new("rob")
+///         .with_effect(
+///             Effect::new()
+///                 .add_mutation(Mutation::increment("gold", 1))
+///                 .add_mutation(Mutation::decrement("energy", 5))
+///                 .add_mutation(Mutation::increment("hunger", 5))
+///                 .with_cost(3),
+///         )
+///         .with_precondition(("hunger", Assert::lt_eq(50_i64)))
+///         .with_precondition(("energy", Assert::gt_eq(50_i64))),
+/// ];
+///
+/// let summary = describe_actions(&actions);
+/// assert!(summary.contains("ACTION \"sleep\" (cost: 1)"));
+/// assert!(summary.contains("ACTION \"eat\" (cost: 1)"));
+/// assert!(summary.contains("ACTION \"rob\" (cost: 3)"));
+/// assert!(summary.contains("energy >= Value:I64(26)"));
+/// assert!(summary.contains("increment: gold + Value:I64(1)"));
+/// ```
+pub fn describe_actions(actions: &[Action]) -> String {
+    let mut output = String::new();
+
+    for action in actions {
+        match &action.effect {
+            Some(effect) => {
+                output.push_str(&format!(
+                    "= ACTION {:?} (cost: {}",
+                    action.key,
+                    effect.fixed_cost()
+                ));
+                if let Some((key, cost_per_unit)) = &effect.cost_per_unit {
+                    output.push_str(&format!(" + {cost_per_unit} per unit of {key}"));
+                }
+                output.push_str(")\n");
+            },
+            None => output.push_str(&format!("= ACTION {:?} (no effect)\n", action.key)),
+        }
+        if let Some(max_uses) = action.max_uses {
+            output.push_str(&format!("MAX USES: {max_uses}\n"));
+        }
+        if let Some(group) = &action.group {
+            output.push_str(&format!("GROUP: {group}\n"));
+        }
+
+        output.push_str("PRECONDITIONS:\n");
+        if action.preconditions.is_empty() {
+            output.push_str("none\n");
+        }
+        for (key, assert) in &action.preconditions {
+            output.push_str(&format!("{key} {assert}\n"));
+        }
+
+        output.push_str("MUTATES:\n");
+        match &action.effect {
+            Some(effect) if effect.has_mutations() => {
+                output.push_str(&format_mutations(effect.mutations.clone()))
+            },
+            _ => output.push_str("none\n"),
+        }
+
+        for alternative in &action.alternatives {
+            output.push_str(&format!("OR (cost: {}):\n", alternative.fixed_cost()));
+            if alternative.has_mutations() {
+                output.push_str(&format_mutations(alternative.mutations.clone()));
+            } else {
+                output.push_str("none\n");
+            }
+        }
+
+        output.push_str("\n---\n");
+    }
+
+    output
 }
@@ -1,5 +1,9 @@
 use crate::basic::value::Value;
-use std::hash::{Hash, Hasher};
+use crate::world_state::WorldState;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+use core::ops::{Range, RangeFrom, RangeInclusive, RangeTo, RangeToInclusive};
 
 /// Represents a comparison assertion between [`Value`] instances.
 ///
@@ -18,8 +22,11 @@ use std::hash::{Hash, Hasher};
 /// let lt_assert = Assert::lt(100);         // Value must be less than 100
 /// let gte_assert = Assert::gt_eq(50);      // Value must be greater than or equal to 50
 /// let lte_assert = Assert::lt_eq(200);     // Value must be less than or equal to 200
+/// let exists_assert = Assert::exists();    // Key must be present
+/// let absent_assert = Assert::absent();    // Key must not be present
+/// let all_assert = Assert::all([Assert::gt(0), Assert::lt(10)]); // Both must hold
 /// ```
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Assert {
     /// Value must equal the specified value
     Equals(Value),
@@ -33,6 +40,12 @@ pub enum Assert {
     LessThan(Value),
     /// Value must be less than or equal to the specified value
     LessThanEquals(Value),
+    /// Key must be present in the world state, whatever its value
+    Exists,
+    /// Key must not be present in the world state
+    Absent,
+    /// Every contained assertion must hold
+    All(Vec<Assert>),
 }
 
 impl Assert {
@@ -131,13 +144,121 @@ impl Assert {
     pub fn lt(value: impl Into<Value>) -> Self {
         Assert::LessThan(value.into())
     }
+
+    /// Creates an assertion requiring the key to be present.
+    ///
+    /// The stored value is ignored; only the presence of the key matters.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let action = Action::new("drop_item").with_precondition(("held_item", Assert::exists()));
+    ///
+    /// assert!(action.check_preconditions(&WorldState::new().set("held_item", 3)));
+    /// assert!(!action.check_preconditions(&WorldState::new()));
+    /// ```
+    pub fn exists() -> Self {
+        Assert::Exists
+    }
+
+    /// Creates an assertion requiring the key to be absent.
+    ///
+    /// The stored value is ignored; only the absence of the key matters.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let action = Action::new("pick_up_item").with_precondition(("held_item", Assert::absent()));
+    ///
+    /// assert!(action.check_preconditions(&WorldState::new()));
+    /// assert!(!action.check_preconditions(&WorldState::new().set("held_item", 3)));
+    ///
+    /// // Goals can require a key to be absent too
+    /// let goal = Goal::new().with("held_item", Assert::absent());
+    /// assert!(goal.is_satisfied_by(&WorldState::new()));
+    /// assert!(!goal.is_satisfied_by(&WorldState::new().set("held_item", 3)));
+    /// ```
+    pub fn absent() -> Self {
+        Assert::Absent
+    }
+
+    /// Creates an assertion requiring every given assertion to hold.
+    ///
+    /// This expresses ranges and other combined requirements on a single key,
+    /// which a [`Goal`](crate::prelude::Goal) can only hold one assertion for.
+    /// Nested `All` assertions are flattened into the new one.
+    ///
+    /// # Arguments
+    /// * `asserts` - The assertions that must all hold
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let in_range = Assert::all([Assert::gt_eq(20), Assert::lt(30)]);
+    ///
+    /// assert!(compare_values(&in_range, &Value::I64(25)));
+    /// assert!(!compare_values(&in_range, &Value::I64(30)));
+    ///
+    /// let goal = Goal::new().with("temperature", in_range);
+    /// assert!(goal.is_satisfied_by(&WorldState::new().set("temperature", 20)));
+    /// assert!(!goal.is_satisfied_by(&WorldState::new().set("temperature", 15)));
+    /// ```
+    pub fn all(asserts: impl IntoIterator<Item = Assert>) -> Self {
+        let mut flattened = Vec::new();
+        for assert in asserts {
+            match assert {
+                Assert::All(inner) => flattened.extend(inner),
+                assert => flattened.push(assert),
+            }
+        }
+        Assert::All(flattened)
+    }
+
+    /// Creates an assertion requiring a number to be within `tolerance` of
+    /// `value`, bounds included.
+    ///
+    /// The assertion is the range `[value - tolerance, value + tolerance]`,
+    /// made of two [`Assert::All`] members with `F64` bounds, so integers and
+    /// floats are both compared by value. Booleans and [`Value::Null`] have no
+    /// notion of closeness: for them, this is [`Assert::Equals`]. The
+    /// tolerance should be non-negative; a negative one can't be satisfied.
+    ///
+    /// # Arguments
+    /// * `value` - The target value
+    /// * `tolerance` - The largest accepted difference from `value`
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let near = Assert::approx_eq(10.0, 0.5);
+    /// assert!(compare_values(&near, &Value::F64(10.3)));
+    /// assert!(compare_values(&near, &Value::F64(9.5)));
+    /// assert!(compare_values(&near, &Value::I64(10)));
+    /// assert!(!compare_values(&near, &Value::F64(10.6)));
+    ///
+    /// assert_eq!(Assert::approx_eq(true, 0.5), Assert::eq(true));
+    /// ```
+    pub fn approx_eq(value: impl Into<Value>, tolerance: f64) -> Self {
+        let target = match value.into() {
+            Value::I64(v) => v as f64,
+            Value::F64(v) => v,
+            value @ (Value::Bool(_) | Value::Null) => return Assert::Equals(value),
+        };
+        Assert::all([Assert::gt_eq(target - tolerance), Assert::lt_eq(target + tolerance)])
+    }
 }
 
 impl Assert {
     /// Extracts the comparison value from an assertion.
     ///
     /// Returns the [`Value`] that this assertion is comparing against,
-    /// regardless of the comparison operator.
+    /// regardless of the comparison operator, or `None` for assertions that
+    /// only check for the presence of a key (`Exists` and `Absent`) and for
+    /// combined assertions (`All`).
     ///
     /// # Examples
     /// ```
@@ -145,20 +266,250 @@ impl Assert {
     ///
     /// let assert = Assert::eq(42);
     /// let value = assert.value();
-    /// assert!(matches!(value, Value::I64(42)));
+    /// assert!(matches!(value, Some(Value::I64(42))));
     ///
     /// let assert2 = Assert::gt(10.5);
     /// let value2 = assert2.value();
-    /// assert!(matches!(value2, Value::F64(10.5)));
+    /// assert!(matches!(value2, Some(Value::F64(10.5))));
+    ///
+    /// assert_eq!(Assert::exists().value(), None);
     /// ```
-    pub fn value(&self) -> Value {
+    pub fn value(&self) -> Option<Value> {
+        match self {
+            Assert::Equals(v)
+            | Assert::NotEquals(v)
+            | Assert::GreaterThan(v)
+            | Assert::LessThan(v)
+            | Assert::GreaterThanEquals(v)
+            | Assert::LessThanEquals(v) => Some(*v),
+            Assert::Exists | Assert::Absent | Assert::All(_) => None,
+        }
+    }
+
+    /// Checks this assertion against the value of a key in a world state.
+    ///
+    /// Present keys are compared with [`compare_values`]. A missing key only
+    /// satisfies `Absent` (or an `All` made of `Absent` assertions); every
+    /// other assertion fails for it.
+    ///
+    /// # Arguments
+    /// * `state` - The world state to look the key up in
+    /// * `key` - The key whose value is checked
+    ///
+    /// # Returns
+    /// `true` if the assertion holds for the key, `false` otherwise.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let state = WorldState::new().set("health", 80).set("is_armed", true);
+    ///
+    /// // Present keys
+    /// assert!(Assert::gt_eq(50).check(&state, "health"));
+    /// assert!(!Assert::lt(50).check(&state, "health"));
+    /// assert!(Assert::exists().check(&state, "health"));
+    /// assert!(!Assert::absent().check(&state, "health"));
+    ///
+    /// // Missing keys
+    /// assert!(!Assert::eq(0).check(&state, "ammo"));
+    /// assert!(!Assert::not_eq(0).check(&state, "ammo"));
+    /// assert!(!Assert::exists().check(&state, "ammo"));
+    /// assert!(Assert::absent().check(&state, "ammo"));
+    ///
+    /// // Type mismatches
+    /// assert!(!Assert::gt(0).check(&state, "is_armed"));
+    /// assert!(!Assert::eq(1).check(&state, "is_armed"));
+    /// assert!(Assert::not_eq(1).check(&state, "is_armed"));
+    /// ```
+    pub fn check(&self, state: &WorldState, key: &str) -> bool {
+        match state.get(key) {
+            Some(value) => compare_values(self, value),
+            None => self.holds_when_missing(),
+        }
+    }
+
+    /// Measures how far a value is from satisfying this assertion.
+    ///
+    /// Unlike [`Value::distance`], which always measures the gap to the
+    /// compared value, this takes the operator into account, so a value that
+    /// already satisfies the assertion contributes nothing to a heuristic.
+    ///
+    /// # Distance Calculation
+    /// - 0 if the assertion holds for `value`
+    /// - `Equals`, `GreaterThan(Equals)` and `LessThan(Equals)`: the gap to
+    ///   the compared value, at least 1
+    /// - `NotEquals` and `Absent`: 1
+    /// - `All`: the largest distance of the contained assertions
+    /// - 1 when a `Bool` is compared with a number, which can't be measured
+    ///
+    /// # Arguments
+    /// * `value` - The value to measure
+    ///
+    /// # Returns
+    /// The distance, saturating at `u64::MAX`.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// // Satisfied assertions have no distance
+    /// assert_eq!(Assert::gt_eq(80).distance_to(&Value::I64(90)), 0);
+    /// assert_eq!(Assert::lt(80).distance_to(&Value::I64(60)), 0);
+    /// assert_eq!(Assert::not_eq(0).distance_to(&Value::I64(5)), 0);
+    /// assert_eq!(Assert::exists().distance_to(&Value::Bool(false)), 0);
+    ///
+    /// // Unsatisfied ones measure the gap to the boundary
+    /// assert_eq!(Assert::gt_eq(80).distance_to(&Value::I64(60)), 20);
+    /// assert_eq!(Assert::gt(80).distance_to(&Value::I64(60)), 20);
+    /// assert_eq!(Assert::gt(80).distance_to(&Value::I64(80)), 1);
+    /// assert_eq!(Assert::lt_eq(10).distance_to(&Value::F64(12.5)), 3);
+    /// assert_eq!(Assert::lt(10).distance_to(&Value::I64(10)), 1);
+    /// assert_eq!(Assert::eq(5).distance_to(&Value::I64(2)), 3);
+    /// assert_eq!(Assert::eq(true).distance_to(&Value::Bool(false)), 1);
+    /// assert_eq!(Assert::not_eq(0).distance_to(&Value::I64(0)), 1);
+    /// assert_eq!(Assert::absent().distance_to(&Value::I64(0)), 1);
+    ///
+    /// // Ranges measure their furthest bound
+    /// let in_range = Assert::all([Assert::gt_eq(20), Assert::lt(30)]);
+    /// assert_eq!(in_range.distance_to(&Value::I64(25)), 0);
+    /// assert_eq!(in_range.distance_to(&Value::I64(12)), 8);
+    ///
+    /// // Mismatched types
+    /// assert_eq!(Assert::gt(0).distance_to(&Value::Bool(true)), 1);
+    /// ```
+    pub fn distance_to(&self, value: &Value) -> u64 {
+        if compare_values(self, value) {
+            return 0;
+        }
+        let gap = |bound: &Value| {
+            if matches!(value, Value::Bool(_)) == matches!(bound, Value::Bool(_)) {
+                value.distance(bound)
+            } else {
+                1
+            }
+        };
+        match self {
+            Assert::Equals(v)
+            | Assert::GreaterThan(v)
+            | Assert::GreaterThanEquals(v)
+            | Assert::LessThan(v)
+            | Assert::LessThanEquals(v) => gap(v).max(1),
+            Assert::NotEquals(_) | Assert::Exists | Assert::Absent => 1,
+            Assert::All(asserts) => {
+                asserts.iter().map(|assert| assert.distance_to(value)).max().unwrap_or(0)
+            },
+        }
+    }
+
+    /// Checks whether this assertion holds for a key that is missing from the
+    /// world state.
+    ///
+    /// Only `Absent` holds for a missing key, or an `All` made of `Absent`
+    /// assertions.
+    pub(crate) fn holds_when_missing(&self) -> bool {
+        match self {
+            Assert::Absent => true,
+            Assert::All(asserts) => asserts.iter().all(Assert::holds_when_missing),
+            _ => false,
+        }
+    }
+
+    /// Checks whether this assertion can be meaningfully compared with a value.
+    ///
+    /// Numbers are comparable with numbers of either type, while `Bool` and
+    /// `Null` values are only comparable with values of the same type.
+    /// `Exists` and `Absent` are comparable with any value, and `All` when
+    /// every contained assertion is.
+    pub(crate) fn is_comparable_with(&self, value: &Value) -> bool {
+        let numeric = |value: &Value| matches!(value, Value::I64(_) | Value::F64(_));
         match self {
             Assert::Equals(v)
             | Assert::NotEquals(v)
             | Assert::GreaterThan(v)
             | Assert::LessThan(v)
             | Assert::GreaterThanEquals(v)
-            | Assert::LessThanEquals(v) => *v,
+            | Assert::LessThanEquals(v) => (numeric(v) && numeric(value)) || v.same_type_as(value),
+            Assert::Exists | Assert::Absent => true,
+            Assert::All(asserts) => asserts.iter().all(|assert| assert.is_comparable_with(value)),
+        }
+    }
+
+    /// Checks whether every value satisfying this assertion also satisfies `other`.
+    ///
+    /// The check is conservative: `false` means the implication could not be
+    /// shown, not necessarily that it does not hold.
+    pub(crate) fn implies(&self, other: &Assert) -> bool {
+        match (self, other) {
+            _ if self == other => true,
+            (Assert::All(asserts), _) => asserts.iter().any(|assert| assert.implies(other)),
+            (_, Assert::All(asserts)) => asserts.iter().all(|assert| self.implies(assert)),
+            (Assert::Absent, _) | (_, Assert::Absent) => false,
+            // Every value assertion requires the key to be present
+            (_, Assert::Exists) => true,
+            (Assert::Exists, _) => false,
+            (Assert::Equals(v), _) => compare_values(other, v),
+            (_, Assert::Equals(_)) | (Assert::NotEquals(_), _) => false,
+            // A range excludes `v` if `v` itself fails the range
+            (_, Assert::NotEquals(v)) => !compare_values(self, v),
+            (
+                Assert::GreaterThan(bound) | Assert::GreaterThanEquals(bound),
+                Assert::GreaterThan(_) | Assert::GreaterThanEquals(_),
+            )
+            | (
+                Assert::LessThan(bound) | Assert::LessThanEquals(bound),
+                Assert::LessThan(_) | Assert::LessThanEquals(_),
+            ) => compare_values(other, bound),
+            _ => false,
+        }
+    }
+
+    /// Checks whether this assertion can clearly never hold.
+    ///
+    /// Only an `All` assertion can be unsatisfiable, when two of its
+    /// assertions exclude each other. The check is conservative: `false` means
+    /// no contradiction could be shown, not necessarily that a satisfying
+    /// value exists.
+    pub(crate) fn is_unsatisfiable(&self) -> bool {
+        let Assert::All(asserts) = self else {
+            return false;
+        };
+        asserts.iter().enumerate().any(|(index, assert)| {
+            assert.is_unsatisfiable()
+                || asserts[index + 1..].iter().any(|other| assert.excludes(other))
+        })
+    }
+
+    /// Checks whether no value (or absence of one) satisfies both this
+    /// assertion and `other`.
+    ///
+    /// Like [`Assert::implies`], the check is conservative.
+    fn excludes(&self, other: &Assert) -> bool {
+        match (self, other) {
+            (Assert::All(asserts), _) => asserts.iter().any(|assert| assert.excludes(other)),
+            (_, Assert::All(asserts)) => asserts.iter().any(|assert| self.excludes(assert)),
+            (Assert::Absent, Assert::Absent) => false,
+            // Every other assertion requires the key to be present
+            (Assert::Absent, _) | (_, Assert::Absent) => true,
+            (Assert::Exists, _) | (_, Assert::Exists) => false,
+            (Assert::Equals(v), other) | (other, Assert::Equals(v)) => !compare_values(other, v),
+            (
+                lower @ (Assert::GreaterThan(low) | Assert::GreaterThanEquals(low)),
+                upper @ (Assert::LessThan(high) | Assert::LessThanEquals(high)),
+            )
+            | (
+                upper @ (Assert::LessThan(high) | Assert::LessThanEquals(high)),
+                lower @ (Assert::GreaterThan(low) | Assert::GreaterThanEquals(low)),
+            ) => match order(low, high) {
+                Some(Ordering::Greater) => true,
+                // Equal bounds leave a single value, if both include it
+                Some(Ordering::Equal) => !matches!(
+                    (lower, upper),
+                    (Assert::GreaterThanEquals(_), Assert::LessThanEquals(_))
+                ),
+                _ => false,
+            },
+            _ => false,
         }
     }
 }
@@ -195,10 +546,154 @@ impl Hash for Assert {
                 5_u8.hash(state);
                 v.hash(state);
             },
+            Assert::Exists => {
+                6_u8.hash(state);
+            },
+            Assert::Absent => {
+                7_u8.hash(state);
+            },
+            Assert::All(asserts) => {
+                8_u8.hash(state);
+                asserts.len().hash(state);
+                for assert in asserts {
+                    assert.hash(state);
+                }
+            },
         }
     }
 }
 
+impl core::fmt::Display for Assert {
+    /// Formats the assertion as an operator followed by its comparison value.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// assert_eq!(format!("{}", Assert::gt_eq(80)), ">= Value:I64(80)");
+    /// assert_eq!(format!("{}", Assert::not_eq(false)), "!= Value:Bool(false)");
+    /// assert_eq!(format!("{}", Assert::exists()), "exists");
+    /// assert_eq!(
+    ///     format!("{}", Assert::all([Assert::gt(0), Assert::lt(10)])),
+    ///     "all(> Value:I64(0), < Value:I64(10))"
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Assert::Equals(v) => write!(f, "== {v}"),
+            Assert::NotEquals(v) => write!(f, "!= {v}"),
+            Assert::GreaterThan(v) => write!(f, "> {v}"),
+            Assert::GreaterThanEquals(v) => write!(f, ">= {v}"),
+            Assert::LessThan(v) => write!(f, "< {v}"),
+            Assert::LessThanEquals(v) => write!(f, "<= {v}"),
+            Assert::Exists => write!(f, "exists"),
+            Assert::Absent => write!(f, "absent"),
+            Assert::All(asserts) => {
+                write!(f, "all(")?;
+                for (index, assert) in asserts.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{assert}")?;
+                }
+                write!(f, ")")
+            },
+        }
+    }
+}
+
+impl<T: Into<Value>> From<RangeInclusive<T>> for Assert {
+    /// Converts `start..=end` to an assertion that a value lies within both
+    /// bounds: `Assert::all([Assert::gt_eq(start), Assert::lt_eq(end)])`.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let healthy = Assert::from(40..=80);
+    /// assert_eq!(healthy, Assert::all([Assert::gt_eq(40), Assert::lt_eq(80)]));
+    /// assert!(compare_values(&healthy, &Value::I64(40)));
+    /// assert!(compare_values(&healthy, &Value::I64(80)));
+    /// assert!(!compare_values(&healthy, &Value::I64(81)));
+    ///
+    /// let goal = Goal::new().with("temperature", 19.5..=22.0);
+    /// assert!(goal.is_satisfied_by(&WorldState::new().set("temperature", 21.0)));
+    /// assert!(!goal.is_satisfied_by(&WorldState::new().set("temperature", 23.5)));
+    /// ```
+    fn from(range: RangeInclusive<T>) -> Self {
+        let (start, end) = range.into_inner();
+        Assert::all([Assert::gt_eq(start), Assert::lt_eq(end)])
+    }
+}
+
+impl<T: Into<Value>> From<Range<T>> for Assert {
+    /// Converts `start..end` to an assertion that a value is at least `start`
+    /// and less than `end`: `Assert::all([Assert::gt_eq(start), Assert::lt(end)])`.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let slot = Assert::from(0..10);
+    /// assert_eq!(slot, Assert::all([Assert::gt_eq(0), Assert::lt(10)]));
+    /// assert!(compare_values(&slot, &Value::I64(0)));
+    /// assert!(!compare_values(&slot, &Value::I64(10)));
+    /// ```
+    fn from(range: Range<T>) -> Self {
+        Assert::all([Assert::gt_eq(range.start), Assert::lt(range.end)])
+    }
+}
+
+impl<T: Into<Value>> From<RangeFrom<T>> for Assert {
+    /// Converts `start..` to `Assert::gt_eq(start)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// assert_eq!(Assert::from(50..), Assert::gt_eq(50));
+    /// assert_eq!(Assert::from(0.5..), Assert::gt_eq(0.5));
+    /// assert!(compare_values(&Assert::from(50..), &Value::I64(50)));
+    /// assert!(!compare_values(&Assert::from(50..), &Value::I64(49)));
+    /// ```
+    fn from(range: RangeFrom<T>) -> Self {
+        Assert::gt_eq(range.start)
+    }
+}
+
+impl<T: Into<Value>> From<RangeTo<T>> for Assert {
+    /// Converts `..end` to `Assert::lt(end)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// assert_eq!(Assert::from(..100), Assert::lt(100));
+    /// assert_eq!(Assert::from(..1.5), Assert::lt(1.5));
+    /// assert!(compare_values(&Assert::from(..100), &Value::I64(99)));
+    /// assert!(!compare_values(&Assert::from(..100), &Value::I64(100)));
+    /// ```
+    fn from(range: RangeTo<T>) -> Self {
+        Assert::lt(range.end)
+    }
+}
+
+impl<T: Into<Value>> From<RangeToInclusive<T>> for Assert {
+    /// Converts `..=end` to `Assert::lt_eq(end)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// assert_eq!(Assert::from(..=100), Assert::lt_eq(100));
+    /// assert!(compare_values(&Assert::from(..=100), &Value::I64(100)));
+    /// assert!(!compare_values(&Assert::from(..=100), &Value::F64(100.5)));
+    /// ```
+    fn from(range: RangeToInclusive<T>) -> Self {
+        Assert::lt_eq(range.end)
+    }
+}
+
 /// Compares a value against an assertion.
 ///
 /// This is the core evaluation function that checks whether a given [`Value`]
@@ -211,6 +706,21 @@ impl Hash for Assert {
 /// # Returns
 /// `true` if the value satisfies the assertion, `false` otherwise.
 ///
+/// Since a value is given, the key it belongs to is present: `Exists` always
+/// evaluates to `true` and `Absent` always evaluates to `false`. `All`
+/// evaluates to `true` if every contained assertion does.
+///
+/// # Mixed Types
+/// The result is well-defined for every combination of value types:
+/// - Ordering comparisons (`>`, `>=`, `<`, `<=`) between `I64` and `F64`
+///   promote the integer to `F64`, so `Assert::gt_eq(80.0)` holds for
+///   `Value::I64(100)`.
+/// - Equality follows [`Value`]'s `PartialEq`, which never considers different
+///   variants equal: `Assert::eq(1.0)` doesn't hold for `Value::I64(1)`, and
+///   `Assert::not_eq(1.0)` does.
+/// - Ordering comparisons between a `Bool` and a number always return `false`,
+///   as do comparisons involving a `NaN`.
+///
 /// # Examples
 /// ```
 /// use rust_goap::prelude::*;
@@ -225,13 +735,74 @@ impl Hash for Assert {
 /// let assert3 = Assert::lt(30);
 /// assert!(!compare_values(&assert3, &value));
 /// ```
+///
+/// Comparing integers and floats:
+/// ```
+/// use rust_goap::prelude::*;
+///
+/// // I64 values against F64 assertions
+/// assert!(compare_values(&Assert::gt_eq(80.0), &Value::I64(100)));
+/// assert!(compare_values(&Assert::gt_eq(80.0), &Value::I64(80)));
+/// assert!(compare_values(&Assert::gt(79.5), &Value::I64(80)));
+/// assert!(!compare_values(&Assert::lt(80.0), &Value::I64(80)));
+/// assert!(compare_values(&Assert::lt_eq(80.0), &Value::I64(80)));
+///
+/// // F64 values against I64 assertions
+/// assert!(compare_values(&Assert::gt(80), &Value::F64(80.5)));
+/// assert!(!compare_values(&Assert::gt_eq(80), &Value::F64(79.9)));
+/// assert!(compare_values(&Assert::lt(80), &Value::F64(79.9)));
+/// assert!(compare_values(&Assert::lt_eq(80), &Value::F64(80.0)));
+///
+/// // Equality stays strict between variants
+/// assert!(!compare_values(&Assert::eq(80.0), &Value::I64(80)));
+/// assert!(compare_values(&Assert::not_eq(80.0), &Value::I64(80)));
+/// ```
+///
+/// Comparing booleans and numbers:
+/// ```
+/// use rust_goap::prelude::*;
+///
+/// for assert in [Assert::gt(0), Assert::gt_eq(0), Assert::lt(1), Assert::lt_eq(1)] {
+///     assert!(!compare_values(&assert, &Value::Bool(true)));
+///     assert!(!compare_values(&assert, &Value::Bool(false)));
+/// }
+/// for assert in [Assert::gt(false), Assert::gt_eq(false), Assert::lt(true), Assert::lt_eq(true)] {
+///     assert!(!compare_values(&assert, &Value::I64(0)));
+///     assert!(!compare_values(&assert, &Value::F64(0.5)));
+/// }
+/// assert!(!compare_values(&Assert::eq(true), &Value::I64(1)));
+/// assert!(compare_values(&Assert::not_eq(true), &Value::I64(1)));
+/// ```
 pub fn compare_values(comparison: &Assert, value: &Value) -> bool {
     match comparison {
         Assert::Equals(v) => value == v,
         Assert::NotEquals(v) => value != v,
-        Assert::GreaterThanEquals(v) => value >= v,
-        Assert::LessThan(v) => value < v,
-        Assert::GreaterThan(v) => value > v,
-        Assert::LessThanEquals(v) => value <= v,
+        Assert::GreaterThanEquals(v) => {
+            matches!(order(value, v), Some(Ordering::Greater | Ordering::Equal))
+        },
+        Assert::LessThan(v) => matches!(order(value, v), Some(Ordering::Less)),
+        Assert::GreaterThan(v) => matches!(order(value, v), Some(Ordering::Greater)),
+        Assert::LessThanEquals(v) => {
+            matches!(order(value, v), Some(Ordering::Less | Ordering::Equal))
+        },
+        Assert::Exists => true,
+        Assert::Absent => false,
+        Assert::All(asserts) => asserts.iter().all(|assert| compare_values(assert, value)),
+    }
+}
+
+/// Orders two values for ordering assertions, promoting `I64` to `F64` when
+/// the types are mixed.
+///
+/// Returns `None` for values that can't be ordered: a `Bool` against a number,
+/// or any comparison involving `NaN`.
+pub(crate) fn order(lhs: &Value, rhs: &Value) -> Option<Ordering> {
+    match (lhs, rhs) {
+        (Value::Bool(lhs), Value::Bool(rhs)) => Some(lhs.cmp(rhs)),
+        (Value::I64(lhs), Value::I64(rhs)) => Some(lhs.cmp(rhs)),
+        (Value::F64(lhs), Value::F64(rhs)) => lhs.partial_cmp(rhs),
+        (Value::I64(lhs), Value::F64(rhs)) => (*lhs as f64).partial_cmp(rhs),
+        (Value::F64(lhs), Value::I64(rhs)) => lhs.partial_cmp(&(*rhs as f64)),
+        (Value::Bool(_) | Value::Null, _) | (_, Value::Bool(_) | Value::Null) => None,
     }
 }
@@ -0,0 +1,222 @@
+use alloc::{boxed::Box, string::String, vec, vec::Vec};
+use core::fmt::Display;
+use core::ops::{Add, Mul, Sub};
+
+use crate::basic::value::Value;
+use crate::world_state::WorldState;
+
+/// Represents an arithmetic expression over world state values.
+///
+/// `Expr` is a small expression tree used by [`Mutation::Compute`](crate::prelude::Mutation::Compute)
+/// to derive a value from other state keys at the moment an effect is applied,
+/// e.g. `total = gold + silver`. Expressions are built from keys and constants
+/// combined with the `+`, `-` and `*` operators.
+///
+/// # Examples
+/// ```
+/// use rust_goap::prelude::*;
+///
+/// // total = gold + silver * 2
+/// let expr = Expr::key("gold") + Expr::key("silver") * Expr::constant(2);
+///
+/// let state = WorldState::new().set("gold", 10).set("silver", 4);
+/// assert_eq!(expr.eval(&state), Some(Value::I64(18)));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Expr {
+    /// The current value of a world state key
+    Key(String),
+    /// A constant value
+    Const(Value),
+    /// The sum of two expressions
+    Add(Box<Expr>, Box<Expr>),
+    /// The difference of two expressions
+    Sub(Box<Expr>, Box<Expr>),
+    /// The product of two expressions
+    Mul(Box<Expr>, Box<Expr>),
+}
+
+impl Expr {
+    /// Creates an expression reading the current value of a key.
+    ///
+    /// # Arguments
+    /// * `key` - The state key to read
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let expr = Expr::key("gold");
+    /// assert!(matches!(expr, Expr::Key(key) if key == "gold"));
+    /// ```
+    pub fn key(key: impl Into<String>) -> Self {
+        Expr::Key(key.into())
+    }
+
+    /// Creates a constant expression.
+    ///
+    /// # Arguments
+    /// * `value` - The constant value
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let expr = Expr::constant(2);
+    /// assert!(matches!(expr, Expr::Const(Value::I64(2))));
+    /// ```
+    pub fn constant(value: impl Into<Value>) -> Self {
+        Expr::Const(value.into())
+    }
+
+    /// Evaluates the expression against a world state.
+    ///
+    /// # Arguments
+    /// * `world_state` - The state to read keys from
+    ///
+    /// # Returns
+    /// The computed value, or `None` if any key referenced by the expression
+    /// is missing from the state, or if integer arithmetic overflows.
+    ///
+    /// Operands of different variants, and booleans, are handled the same way
+    /// as in [`Value`] arithmetic: the left-hand operand is kept unchanged.
+    ///
+    /// # Panics
+    /// With the `strict` feature, panics on such operands like [`Value`]
+    /// arithmetic does.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let expr = Expr::key("gold") - Expr::key("debt");
+    ///
+    /// let state = WorldState::new().set("gold", 10).set("debt", 3);
+    /// assert_eq!(expr.eval(&state), Some(Value::I64(7)));
+    ///
+    /// let missing = WorldState::new().set("gold", 10);
+    /// assert_eq!(expr.eval(&missing), None);
+    ///
+    /// let tripled = Expr::key("gold") * Expr::constant(3);
+    /// let hoard = WorldState::new().set("gold", i64::MAX);
+    /// assert_eq!(tripled.eval(&hoard), None);
+    /// ```
+    pub fn eval(&self, world_state: &WorldState) -> Option<Value> {
+        match self {
+            Expr::Key(key) => world_state.get(key).copied(),
+            Expr::Const(value) => Some(*value),
+            Expr::Add(lhs, rhs) => {
+                combine(lhs.eval(world_state)?, rhs.eval(world_state)?, i64::checked_add, Add::add)
+            },
+            Expr::Sub(lhs, rhs) => {
+                combine(lhs.eval(world_state)?, rhs.eval(world_state)?, i64::checked_sub, Sub::sub)
+            },
+            Expr::Mul(lhs, rhs) => {
+                combine(lhs.eval(world_state)?, rhs.eval(world_state)?, i64::checked_mul, Mul::mul)
+            },
+        }
+    }
+
+    /// Returns every state key the expression reads, in order of appearance.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let expr = Expr::key("gold") + Expr::key("silver") * Expr::constant(2);
+    /// assert_eq!(expr.keys(), ["gold", "silver"]);
+    /// ```
+    pub fn keys(&self) -> Vec<&str> {
+        match self {
+            Expr::Key(key) => vec![key],
+            Expr::Const(_) => vec![],
+            Expr::Add(lhs, rhs) | Expr::Sub(lhs, rhs) | Expr::Mul(lhs, rhs) => {
+                let mut keys = lhs.keys();
+                keys.extend(rhs.keys());
+                keys
+            },
+        }
+    }
+
+    /// Returns the same expression with every key replaced by `rename(key)`.
+    pub(crate) fn map_keys(self, rename: &dyn Fn(&str) -> String) -> Self {
+        match self {
+            Expr::Key(key) => Expr::Key(rename(&key)),
+            Expr::Const(value) => Expr::Const(value),
+            Expr::Add(lhs, rhs) => {
+                Expr::Add(Box::new(lhs.map_keys(rename)), Box::new(rhs.map_keys(rename)))
+            },
+            Expr::Sub(lhs, rhs) => {
+                Expr::Sub(Box::new(lhs.map_keys(rename)), Box::new(rhs.map_keys(rename)))
+            },
+            Expr::Mul(lhs, rhs) => {
+                Expr::Mul(Box::new(lhs.map_keys(rename)), Box::new(rhs.map_keys(rename)))
+            },
+        }
+    }
+}
+
+impl Add for Expr {
+    type Output = Expr;
+
+    /// Combines two expressions into their sum.
+    fn add(self, other: Expr) -> Expr {
+        Expr::Add(Box::new(self), Box::new(other))
+    }
+}
+
+impl Sub for Expr {
+    type Output = Expr;
+
+    /// Combines two expressions into their difference.
+    fn sub(self, other: Expr) -> Expr {
+        Expr::Sub(Box::new(self), Box::new(other))
+    }
+}
+
+impl Mul for Expr {
+    type Output = Expr;
+
+    /// Combines two expressions into their product.
+    fn mul(self, other: Expr) -> Expr {
+        Expr::Mul(Box::new(self), Box::new(other))
+    }
+}
+
+impl Display for Expr {
+    /// Formats the expression with explicit parentheses around every operation.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let expr = Expr::key("gold") + Expr::key("silver") * Expr::constant(2);
+    /// assert_eq!(format!("{}", expr), "(gold + (silver * Value:I64(2)))");
+    /// ```
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Expr::Key(key) => write!(f, "{key}"),
+            Expr::Const(value) => write!(f, "{value}"),
+            Expr::Add(lhs, rhs) => write!(f, "({lhs} + {rhs})"),
+            Expr::Sub(lhs, rhs) => write!(f, "({lhs} - {rhs})"),
+            Expr::Mul(lhs, rhs) => write!(f, "({lhs} * {rhs})"),
+        }
+    }
+}
+
+/// Combines two operands with `checked` if both are integers, or with `op`
+/// otherwise.
+///
+/// # Returns
+/// The result, or `None` if the integers overflow.
+fn combine(
+    lhs: Value,
+    rhs: Value,
+    checked: fn(i64, i64) -> Option<i64>,
+    op: fn(Value, Value) -> Value,
+) -> Option<Value> {
+    match (lhs, rhs) {
+        (Value::I64(lhs), Value::I64(rhs)) => checked(lhs, rhs).map(Value::I64),
+        _ => Some(op(lhs, rhs)),
+    }
+}
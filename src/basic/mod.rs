@@ -1,3 +1,4 @@
 pub(crate) mod assert;
+pub(crate) mod expr;
 pub(crate) mod mutation;
 pub(crate) mod value;
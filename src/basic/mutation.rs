@@ -1,3 +1,8 @@
+use alloc::{boxed::Box, format, string::String, vec, vec::Vec};
+use core::cmp::Ordering;
+
+use crate::basic::assert::{Assert, order};
+use crate::basic::expr::Expr;
 use crate::basic::value::Value;
 use crate::world_state::WorldState;
 
@@ -27,6 +32,34 @@ pub enum Mutation {
     Increment(String, Value),
     /// Decrement a value for a key by a given amount
     Decrement(String, Value),
+    /// Decrement a value for a key by a given amount, stopping at zero
+    DecrementSaturating(String, Value),
+    /// Increment a value for a key (first) by the current value of another key (second)
+    AddFromKey(String, String),
+    /// Decrement a value for a key (first) by the current value of another key (second)
+    SubtractFromKey(String, String),
+    /// Raise a value for a key to at least a given floor, keeping larger values
+    SetMin(String, Value),
+    /// Lower a value for a key to at most a given ceiling, keeping smaller values
+    SetMax(String, Value),
+    /// Set a value for a key only if the key is missing, keeping any existing value
+    SetIfAbsent(String, Value),
+    /// Set a key to the result of an expression evaluated against the current state
+    Compute {
+        /// The state key receiving the computed value
+        target: String,
+        /// The expression to evaluate
+        expr: Expr,
+    },
+    /// Apply a mutation only if a condition holds against the current state
+    Conditional {
+        /// The state key the condition is evaluated against
+        on_key: String,
+        /// The condition that must hold for `on_key`
+        condition: Assert,
+        /// The mutation to apply when the condition holds
+        mutation: Box<Mutation>,
+    },
 }
 
 impl Mutation {
@@ -96,13 +129,400 @@ impl Mutation {
     pub fn decrement(key: impl Into<String>, value: impl Into<Value>) -> Self {
         Mutation::Decrement(key.into(), value.into())
     }
+
+    /// Creates a mutation that decrements a key's value by a specified
+    /// amount, without going below zero.
+    ///
+    /// This suits resource counters that can't be negative: the value is
+    /// decremented like with [`Mutation::decrement`], and a result below zero
+    /// is replaced by zero of the same type. Values that are already negative
+    /// are raised to zero too. Like a decrement, a missing key is left
+    /// unchanged, and the amount must be a number of the same type as the
+    /// value (see [`Effect::validate`](crate::prelude::Effect::validate)).
+    ///
+    /// # Arguments
+    /// * `key` - The state key to decrement
+    /// * `value` - The amount to subtract
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let spend = Mutation::decrement_saturating("arrows", 2);
+    ///
+    /// let mut state = WorldState::new().set("arrows", 3);
+    /// apply_mutator(&mut state, &spend);
+    /// assert_eq!(state.get("arrows"), Some(&Value::I64(1)));
+    ///
+    /// // The counter stops at zero instead of going negative
+    /// apply_mutator(&mut state, &spend);
+    /// assert_eq!(state.get("arrows"), Some(&Value::I64(0)));
+    /// apply_mutator(&mut state, &spend);
+    /// assert_eq!(state.get("arrows"), Some(&Value::I64(0)));
+    ///
+    /// // Floats stop at zero as well
+    /// let mut state = WorldState::new().set("fuel", 0.5);
+    /// apply_mutator(&mut state, &Mutation::decrement_saturating("fuel", 1.5));
+    /// assert_eq!(state.get("fuel"), Some(&Value::F64(0.0)));
+    ///
+    /// // An empty quiver doesn't satisfy a goal requiring a debt
+    /// let goal = Goal::new().with("arrows", Assert::lt(0));
+    /// let shoot = Action::new("shoot").with_effect(Effect::new().add_mutation(spend));
+    /// assert!(make_plan(&WorldState::new().set("arrows", 3), &[shoot], &goal).is_none());
+    /// ```
+    pub fn decrement_saturating(key: impl Into<String>, value: impl Into<Value>) -> Self {
+        Mutation::DecrementSaturating(key.into(), value.into())
+    }
+
+    /// Creates a mutation that increments a key's value by the value of another key.
+    ///
+    /// The amount is read from `source` at the moment the mutation is applied.
+    /// If either `target` or `source` is missing from the state, the mutation
+    /// does nothing.
+    ///
+    /// # Arguments
+    /// * `target` - The state key to increment
+    /// * `source` - The state key holding the amount to add
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// // Transferring gold from the player to the bank keeps the total unchanged
+    /// let deposit = Effect::new()
+    ///     .add_mutation(Mutation::add_from_key("bank", "amount"))
+    ///     .add_mutation(Mutation::subtract_from_key("player", "amount"));
+    ///
+    /// let mut state = WorldState::new().set("player", 50).set("bank", 20).set("amount", 15);
+    /// deposit.apply_to(&mut state);
+    /// assert_eq!(state.get("player"), Some(&Value::I64(35)));
+    /// assert_eq!(state.get("bank"), Some(&Value::I64(35)));
+    ///
+    /// let total = |state: &WorldState| {
+    ///     i64::try_from(*state.get("player").unwrap()).unwrap()
+    ///         + i64::try_from(*state.get("bank").unwrap()).unwrap()
+    /// };
+    /// assert_eq!(total(&state), 70);
+    ///
+    /// // Without a source value there is nothing to transfer
+    /// let mut state = WorldState::new().set("player", 50).set("bank", 20);
+    /// deposit.apply_to(&mut state);
+    /// assert_eq!(total(&state), 70);
+    /// assert_eq!(state.get("bank"), Some(&Value::I64(20)));
+    /// ```
+    pub fn add_from_key(target: impl Into<String>, source: impl Into<String>) -> Self {
+        Mutation::AddFromKey(target.into(), source.into())
+    }
+
+    /// Creates a mutation that decrements a key's value by the value of another key.
+    ///
+    /// The amount is read from `source` at the moment the mutation is applied.
+    /// If either `target` or `source` is missing from the state, the mutation
+    /// does nothing.
+    ///
+    /// # Arguments
+    /// * `target` - The state key to decrement
+    /// * `source` - The state key holding the amount to subtract
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let mutation = Mutation::subtract_from_key("player", "amount");
+    /// assert!(matches!(
+    ///     mutation,
+    ///     Mutation::SubtractFromKey(target, source) if target == "player" && source == "amount"
+    /// ));
+    /// ```
+    pub fn subtract_from_key(target: impl Into<String>, source: impl Into<String>) -> Self {
+        Mutation::SubtractFromKey(target.into(), source.into())
+    }
+
+    /// Creates a mutation that raises a key's value to at least `floor`.
+    ///
+    /// The value becomes the larger of its current value and `floor`. Numbers
+    /// of different types are compared by value. A missing key, or a current
+    /// value that can't be ordered against `floor`, is left unchanged.
+    ///
+    /// # Arguments
+    /// * `key` - The state key to raise
+    /// * `floor` - The smallest value the key may have afterwards
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let mutation = Mutation::set_min("morale", 20);
+    ///
+    /// // Values below the floor are raised to it
+    /// let mut state = WorldState::new().set("morale", 5);
+    /// apply_mutator(&mut state, &mutation);
+    /// assert_eq!(state.get("morale"), Some(&Value::I64(20)));
+    ///
+    /// // Values already beyond the floor are kept
+    /// let mut state = WorldState::new().set("morale", 35);
+    /// apply_mutator(&mut state, &mutation);
+    /// assert_eq!(state.get("morale"), Some(&Value::I64(35)));
+    /// ```
+    pub fn set_min(key: impl Into<String>, floor: impl Into<Value>) -> Self {
+        Mutation::SetMin(key.into(), floor.into())
+    }
+
+    /// Creates a mutation that lowers a key's value to at most `ceiling`.
+    ///
+    /// The value becomes the smaller of its current value and `ceiling`.
+    /// Numbers of different types are compared by value. A missing key, or a
+    /// current value that can't be ordered against `ceiling`, is left unchanged.
+    ///
+    /// # Arguments
+    /// * `key` - The state key to lower
+    /// * `ceiling` - The largest value the key may have afterwards
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// // Heal by 30, but to at most the maximum health of 100
+    /// let heal = Effect::new()
+    ///     .add_mutation(Mutation::increment("health", 30))
+    ///     .add_mutation(Mutation::set_max("health", 100));
+    ///
+    /// let mut state = WorldState::new().set("health", 50);
+    /// heal.apply_to(&mut state);
+    /// assert_eq!(state.get("health"), Some(&Value::I64(80)));
+    ///
+    /// // Values that would exceed the ceiling are capped
+    /// heal.apply_to(&mut state);
+    /// assert_eq!(state.get("health"), Some(&Value::I64(100)));
+    ///
+    /// // Values already beyond the ceiling are lowered to it
+    /// let mut state = WorldState::new().set("health", 150);
+    /// apply_mutator(&mut state, &Mutation::set_max("health", 100));
+    /// assert_eq!(state.get("health"), Some(&Value::I64(100)));
+    /// ```
+    pub fn set_max(key: impl Into<String>, ceiling: impl Into<Value>) -> Self {
+        Mutation::SetMax(key.into(), ceiling.into())
+    }
+
+    /// Creates a mutation that sets a key only if it isn't in the world state.
+    ///
+    /// This initializes a default without overwriting a value that is
+    /// already there, whatever its type. A key holding [`Value::Null`] is
+    /// present, so it is left unchanged too.
+    ///
+    /// # Arguments
+    /// * `key` - The state key to initialize
+    /// * `value` - The value to set if the key is missing
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let mutation = Mutation::set_if_absent("arrows", 20);
+    ///
+    /// // Missing keys are set
+    /// let mut state = WorldState::new();
+    /// apply_mutator(&mut state, &mutation);
+    /// assert_eq!(state.get("arrows"), Some(&Value::I64(20)));
+    ///
+    /// // Existing values are kept
+    /// let mut state = WorldState::new().set("arrows", 3);
+    /// apply_mutator(&mut state, &mutation);
+    /// assert_eq!(state.get("arrows"), Some(&Value::I64(3)));
+    ///
+    /// assert_eq!(format_mutations(vec![mutation]), "set if absent: arrows = Value:I64(20)\n");
+    /// ```
+    pub fn set_if_absent(key: impl Into<String>, value: impl Into<Value>) -> Self {
+        Mutation::SetIfAbsent(key.into(), value.into())
+    }
+
+    /// Creates a mutation that sets a key to the result of an expression.
+    ///
+    /// The expression is evaluated against the world state at the moment the
+    /// mutation is applied, so the result depends on the state it is applied to.
+    /// If it can't be evaluated (see [`Expr::eval`]), the target is left
+    /// unchanged, and the planner skips the effect when integers overflow.
+    ///
+    /// # Arguments
+    /// * `target` - The state key to set
+    /// * `expr` - The expression computing the new value
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let mutation = Mutation::compute("total", Expr::key("gold") + Expr::key("silver"));
+    /// assert!(matches!(&mutation, Mutation::Compute { target, .. } if target == "total"));
+    ///
+    /// // The planner evaluates the expression against each state it explores
+    /// let start = WorldState::new().set("gold", 30).set("silver", 12).set("total", 0);
+    /// let goal = Goal::new().with("total", Assert::eq(42));
+    /// let count_coins = Action::new("count_coins")
+    ///     .with_effect(Effect { mutations: vec![mutation], cost: 1, ..Default::default() });
+    ///
+    /// let plan = make_plan(&start, &[count_coins], &goal).unwrap();
+    /// assert_eq!(plan.cost(), 1);
+    /// assert_eq!(plan.steps().last().unwrap().state().get("total"), Some(&Value::I64(42)));
+    ///
+    /// // Overflowing values end the search instead of wrapping around
+    /// let start = WorldState::new().set("gold", 1);
+    /// let goal = Goal::new().with("gold", Assert::eq(2));
+    /// let triple = Action::new("triple").with_effect(Effect {
+    ///     mutations: vec![Mutation::compute("gold", Expr::key("gold") * Expr::constant(3))],
+    ///     cost: 1,
+    ///     ..Default::default()
+    /// });
+    /// assert!(make_plan(&start, &[triple.clone()], &goal).is_none());
+    ///
+    /// let hoard = WorldState::new().set("gold", i64::MAX);
+    /// let error = triple.effect.unwrap().validate(&hoard).unwrap_err();
+    /// assert_eq!(error, EffectError::Overflow { key: "gold".to_string() });
+    /// ```
+    pub fn compute(target: impl Into<String>, expr: Expr) -> Self {
+        Mutation::Compute { target: target.into(), expr }
+    }
+
+    /// Creates a mutation that only applies when a condition holds.
+    ///
+    /// The condition is evaluated against the world state at the moment the
+    /// mutation is applied, following the same rules as goal requirements: a
+    /// missing `on_key` only satisfies [`Assert::Absent`].
+    ///
+    /// # Arguments
+    /// * `on_key` - The state key the condition is evaluated against
+    /// * `condition` - The condition that must hold
+    /// * `mutation` - The mutation to apply when the condition holds
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// // Opening the chest only grants gold if it isn't locked
+    /// let open_chest = Action::new("open_chest").with_effect(Effect::new().add_mutation(
+    ///     Mutation::conditional("chest_locked", Assert::eq(false), Mutation::increment("gold", 10)),
+    /// ));
+    /// let unlock_chest = Action::new("unlock_chest")
+    ///     .with_effect(Effect::new().add_mutation(Mutation::set("chest_locked", false)));
+    ///
+    /// // The same effect yields different states depending on the starting state
+    /// let effect = open_chest.effect.as_ref().unwrap();
+    /// let mut unlocked = WorldState::new().set("gold", 0).set("chest_locked", false);
+    /// let mut locked = WorldState::new().set("gold", 0).set("chest_locked", true);
+    /// effect.apply_to(&mut unlocked);
+    /// effect.apply_to(&mut locked);
+    /// assert_eq!(unlocked.get("gold"), Some(&Value::I64(10)));
+    /// assert_eq!(locked.get("gold"), Some(&Value::I64(0)));
+    ///
+    /// // The planner evaluates the condition for every state it explores
+    /// let start = WorldState::new().set("gold", 0).set("chest_locked", true);
+    /// let goal = Goal::new().with("gold", Assert::gt_eq(10));
+    /// let plan = make_plan(&start, &[open_chest, unlock_chest], &goal).unwrap();
+    /// let keys: Vec<String> = get_effects_from_plan(&plan).map(|(key, _, _)| key).collect();
+    /// assert_eq!(keys, ["unlock_chest", "open_chest"]);
+    /// ```
+    pub fn conditional(on_key: impl Into<String>, condition: Assert, mutation: Mutation) -> Self {
+        Mutation::Conditional { on_key: on_key.into(), condition, mutation: Box::new(mutation) }
+    }
+
+    /// Returns the key the mutation writes.
+    ///
+    /// For conditional mutations this is the key of the inner mutation, not
+    /// the key the condition is evaluated against.
+    ///
+    /// # Example
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// assert_eq!(Mutation::increment("gold", 5).key(), "gold");
+    /// assert_eq!(Mutation::add_from_key("gold", "loot").key(), "gold");
+    /// let refill = Mutation::conditional("ammo", Assert::lt(5), Mutation::set("needs_ammo", true));
+    /// assert_eq!(refill.key(), "needs_ammo");
+    /// ```
+    pub fn key(&self) -> &str {
+        match self {
+            Mutation::Set(key, _)
+            | Mutation::Delete(key)
+            | Mutation::Increment(key, _)
+            | Mutation::Decrement(key, _)
+            | Mutation::DecrementSaturating(key, _)
+            | Mutation::AddFromKey(key, _)
+            | Mutation::SubtractFromKey(key, _)
+            | Mutation::SetMin(key, _)
+            | Mutation::SetMax(key, _)
+            | Mutation::SetIfAbsent(key, _)
+            | Mutation::Compute { target: key, .. } => key,
+            Mutation::Conditional { mutation, .. } => mutation.key(),
+        }
+    }
+
+    /// Returns the same mutation targeting a different key.
+    ///
+    /// For conditional mutations the inner mutation is retargeted, while the
+    /// key the condition is evaluated against is left unchanged.
+    pub(crate) fn rekeyed(self, key: String) -> Self {
+        match self {
+            Mutation::Set(_, value) => Mutation::Set(key, value),
+            Mutation::Delete(_) => Mutation::Delete(key),
+            Mutation::Increment(_, value) => Mutation::Increment(key, value),
+            Mutation::Decrement(_, value) => Mutation::Decrement(key, value),
+            Mutation::DecrementSaturating(_, value) => Mutation::DecrementSaturating(key, value),
+            Mutation::AddFromKey(_, source) => Mutation::AddFromKey(key, source),
+            Mutation::SubtractFromKey(_, source) => Mutation::SubtractFromKey(key, source),
+            Mutation::SetMin(_, value) => Mutation::SetMin(key, value),
+            Mutation::SetMax(_, value) => Mutation::SetMax(key, value),
+            Mutation::SetIfAbsent(_, value) => Mutation::SetIfAbsent(key, value),
+            Mutation::Compute { expr, .. } => Mutation::Compute { target: key, expr },
+            Mutation::Conditional { on_key, condition, mutation } => Mutation::Conditional {
+                on_key,
+                condition,
+                mutation: Box::new(mutation.rekeyed(key)),
+            },
+        }
+    }
+
+    /// Returns the same mutation with every key it reads or writes replaced by
+    /// `rename(key)`.
+    pub(crate) fn map_keys(self, rename: &dyn Fn(&str) -> String) -> Self {
+        match self {
+            Mutation::Set(key, value) => Mutation::Set(rename(&key), value),
+            Mutation::Delete(key) => Mutation::Delete(rename(&key)),
+            Mutation::Increment(key, value) => Mutation::Increment(rename(&key), value),
+            Mutation::Decrement(key, value) => Mutation::Decrement(rename(&key), value),
+            Mutation::DecrementSaturating(key, value) => {
+                Mutation::DecrementSaturating(rename(&key), value)
+            },
+            Mutation::AddFromKey(target, source) => {
+                Mutation::AddFromKey(rename(&target), rename(&source))
+            },
+            Mutation::SubtractFromKey(target, source) => {
+                Mutation::SubtractFromKey(rename(&target), rename(&source))
+            },
+            Mutation::SetMin(key, value) => Mutation::SetMin(rename(&key), value),
+            Mutation::SetMax(key, value) => Mutation::SetMax(rename(&key), value),
+            Mutation::SetIfAbsent(key, value) => Mutation::SetIfAbsent(rename(&key), value),
+            Mutation::Compute { target, expr } => {
+                Mutation::Compute { target: rename(&target), expr: expr.map_keys(rename) }
+            },
+            Mutation::Conditional { on_key, condition, mutation } => Mutation::Conditional {
+                on_key: rename(&on_key),
+                condition,
+                mutation: Box::new(mutation.map_keys(rename)),
+            },
+        }
+    }
 }
 
 /// Applies a mutation to a world state.
 ///
 /// This function modifies the given [`WorldState`] according to the specified
-/// [`Mutation`]. It handles all four mutation types: Set, Delete, Increment,
-/// and Decrement.
+/// [`Mutation`]. It handles all mutation types: Set, Delete, Increment,
+/// Decrement, DecrementSaturating, AddFromKey, SubtractFromKey, SetMin,
+/// SetMax, SetIfAbsent, Compute, and Conditional. An AddFromKey or
+/// SubtractFromKey mutation whose source key is missing leaves
+/// the state unchanged, as does a Compute mutation whose expression references
+/// a missing key or a Conditional mutation whose condition doesn't hold.
+/// Integer arithmetic saturates at the bounds of `i64` instead of overflowing
+/// (see [`Effect::validate`](crate::prelude::Effect::validate) to detect it).
 ///
 /// # Arguments
 /// * `world_state` - The world state to modify
@@ -116,27 +536,82 @@ impl Mutation {
 /// let mutation = Mutation::increment("health", 25);
 ///
 /// apply_mutator(&mut world_state, &mutation);
-///
-/// // Verify the mutation was applied by checking the internal state
-/// // Note: WorldState doesn't have a public get() method, but we can
-/// // verify through other means in real usage
+/// assert_eq!(world_state.get("health"), Some(&Value::I64(75)));
 /// ```
 pub fn apply_mutator(world_state: &mut WorldState, mutator: &Mutation) {
     match mutator {
         Mutation::Set(key, value) => {
-            world_state.0.insert(key.into(), *value);
+            world_state.put(key, *value);
         },
         Mutation::Delete(key) => {
-            world_state.0.remove(key);
+            world_state.remove(key);
         },
         Mutation::Increment(key, value) => {
-            if let Some(current_value) = world_state.0.get_mut(key) {
-                *current_value += *value;
+            if let Some(mut current_value) = world_state.0.get(key).copied() {
+                current_value += *value;
+                world_state.put(key, current_value);
             }
         },
         Mutation::Decrement(key, value) => {
-            if let Some(current_value) = world_state.0.get_mut(key) {
-                *current_value -= *value;
+            if let Some(mut current_value) = world_state.0.get(key).copied() {
+                current_value -= *value;
+                world_state.put(key, current_value);
+            }
+        },
+        Mutation::DecrementSaturating(key, value) => {
+            if let Some(mut current_value) = world_state.0.get(key).copied() {
+                current_value -= *value;
+                match current_value {
+                    Value::I64(v) if v < 0 => current_value = Value::I64(0),
+                    Value::F64(v) if v < 0.0 => current_value = Value::F64(0.0),
+                    _ => {},
+                }
+                world_state.put(key, current_value);
+            }
+        },
+        Mutation::AddFromKey(key, source) => {
+            if let Some(mut current_value) = world_state.0.get(key).copied()
+                && let Some(amount) = world_state.0.get(source).copied()
+            {
+                current_value += amount;
+                world_state.put(key, current_value);
+            }
+        },
+        Mutation::SubtractFromKey(key, source) => {
+            if let Some(mut current_value) = world_state.0.get(key).copied()
+                && let Some(amount) = world_state.0.get(source).copied()
+            {
+                current_value -= amount;
+                world_state.put(key, current_value);
+            }
+        },
+        Mutation::SetMin(key, floor) => {
+            if let Some(current_value) = world_state.0.get(key)
+                && order(floor, current_value) == Some(Ordering::Greater)
+            {
+                world_state.put(key, *floor);
+            }
+        },
+        Mutation::SetMax(key, ceiling) => {
+            if let Some(current_value) = world_state.0.get(key)
+                && order(ceiling, current_value) == Some(Ordering::Less)
+            {
+                world_state.put(key, *ceiling);
+            }
+        },
+        Mutation::SetIfAbsent(key, value) => {
+            if !world_state.contains_key(key) {
+                world_state.put(key, *value);
+            }
+        },
+        Mutation::Compute { target, expr } => {
+            if let Some(value) = expr.eval(world_state) {
+                world_state.put(target, value);
+            }
+        },
+        Mutation::Conditional { on_key, condition, mutation } => {
+            if condition.check(world_state, on_key) {
+                apply_mutator(world_state, mutation);
             }
         },
     }
@@ -162,12 +637,14 @@ pub fn apply_mutator(world_state: &mut WorldState, mutator: &Mutation) {
 ///     Mutation::set("health", 100),
 ///     Mutation::increment("ammo", 10),
 ///     Mutation::decrement("hunger", 5),
+///     Mutation::set_max("health", 120),
 /// ];
 ///
 /// let formatted = format_mutations(mutations);
 /// assert!(formatted.contains("set: health = Value:I64(100)"));
 /// assert!(formatted.contains("increment: ammo + Value:I64(10)"));
 /// assert!(formatted.contains("decrement: hunger - Value:I64(5)"));
+/// assert!(formatted.contains("set max: health <= Value:I64(120)"));
 /// ```
 pub fn format_mutations(mutations: Vec<Mutation>) -> String {
     let mut output = String::new();
@@ -177,7 +654,125 @@ pub fn format_mutations(mutations: Vec<Mutation>) -> String {
             Mutation::Delete(k) => output.push_str(&format!("delete: {k}\n")),
             Mutation::Increment(k, v) => output.push_str(&format!("increment: {k} + {v}\n")),
             Mutation::Decrement(k, v) => output.push_str(&format!("decrement: {k} - {v}\n")),
+            Mutation::DecrementSaturating(k, v) => {
+                output.push_str(&format!("decrement (saturating): {k} - {v}\n"))
+            },
+            Mutation::AddFromKey(k, source) => {
+                output.push_str(&format!("increment: {k} + {source}\n"))
+            },
+            Mutation::SubtractFromKey(k, source) => {
+                output.push_str(&format!("decrement: {k} - {source}\n"))
+            },
+            Mutation::SetMin(k, v) => output.push_str(&format!("set min: {k} >= {v}\n")),
+            Mutation::SetMax(k, v) => output.push_str(&format!("set max: {k} <= {v}\n")),
+            Mutation::SetIfAbsent(k, v) => output.push_str(&format!("set if absent: {k} = {v}\n")),
+            Mutation::Compute { target, expr } => {
+                output.push_str(&format!("compute: {target} = {expr}\n"))
+            },
+            Mutation::Conditional { on_key, condition, mutation } => {
+                let inner = format_mutations(vec![*mutation]);
+                output.push_str(&format!("if {on_key} {condition} then {inner}"));
+            },
         }
     }
     output
 }
+
+/// Builds a `Vec` of [`Mutation`]s with a short syntax.
+///
+/// Each comma-separated item becomes one mutation that keeps its own key:
+/// - `"key" = value` sets the key, like [`Mutation::set`]
+/// - `"key" += amount` increments it, like [`Mutation::increment`]
+/// - `"key" -= amount` decrements it, like [`Mutation::decrement`]
+/// - `delete "key"` removes it, like [`Mutation::delete`]
+/// - any other expression of type `Mutation` is used as-is, e.g.
+///   `Mutation::set_min("health", 0)`
+///
+/// Keys are string literals, or any expression in parentheses, e.g.
+/// `(format!("ammo_{slot}")) -= 1`.
+///
+/// Together with [`Effect::with_mutations`](crate::prelude::Effect::with_mutations)
+/// this is the recommended way to build effects with several mutations,
+/// since it doesn't need the placeholder keys of
+/// [`Effect::with_mutation`](crate::prelude::Effect::with_mutation).
+///
+/// # Examples
+/// ```
+/// use rust_goap::prelude::*;
+///
+/// let mutations = mutations! {
+///     "gold" += 1,
+///     "energy" -= 5,
+///     "is_rested" = false,
+///     delete "target",
+///     Mutation::set_max("hunger", 100),
+/// };
+/// assert_eq!(
+///     mutations,
+///     vec![
+///         Mutation::increment("gold", 1),
+///         Mutation::decrement("energy", 5),
+///         Mutation::set("is_rested", false),
+///         Mutation::delete("target"),
+///         Mutation::set_max("hunger", 100),
+///     ]
+/// );
+///
+/// // The same effect as the builder
+/// let effect = Effect::new().with_mutations(mutations! { "gold" += 1, "energy" -= 5 }).with_cost(2);
+/// let built = Effect::new()
+///     .add_mutation(Mutation::increment("gold", 1))
+///     .add_mutation(Mutation::decrement("energy", 5))
+///     .with_cost(2);
+/// assert_eq!(effect, built);
+/// ```
+///
+/// Computed keys go in parentheses:
+/// ```
+/// use rust_goap::prelude::*;
+///
+/// let slot = 2;
+/// let effect = Effect::from(mutations! { (format!("ammo_{slot}")) -= 1 });
+/// assert_eq!(effect, Effect::new().add_mutation(Mutation::decrement("ammo_2", 1)));
+/// assert!(mutations! {}.is_empty());
+/// ```
+#[macro_export]
+macro_rules! mutations {
+    (@acc [$($done:expr,)*]) => {{
+        let mutations: $crate::__vec::Vec<$crate::prelude::Mutation> = $crate::__vec![$($done),*];
+        mutations
+    }};
+    (@acc [$($done:expr,)*] , $($rest:tt)*) => {
+        $crate::mutations!(@acc [$($done,)*] $($rest)*)
+    };
+    (@acc [$($done:expr,)*] $key:literal += $amount:expr $(, $($rest:tt)*)?) => {
+        $crate::mutations!(@acc [$($done,)* $crate::prelude::Mutation::increment($key, $amount),] $($($rest)*)?)
+    };
+    (@acc [$($done:expr,)*] ($key:expr) += $amount:expr $(, $($rest:tt)*)?) => {
+        $crate::mutations!(@acc [$($done,)* $crate::prelude::Mutation::increment($key, $amount),] $($($rest)*)?)
+    };
+    (@acc [$($done:expr,)*] $key:literal -= $amount:expr $(, $($rest:tt)*)?) => {
+        $crate::mutations!(@acc [$($done,)* $crate::prelude::Mutation::decrement($key, $amount),] $($($rest)*)?)
+    };
+    (@acc [$($done:expr,)*] ($key:expr) -= $amount:expr $(, $($rest:tt)*)?) => {
+        $crate::mutations!(@acc [$($done,)* $crate::prelude::Mutation::decrement($key, $amount),] $($($rest)*)?)
+    };
+    (@acc [$($done:expr,)*] $key:literal = $value:expr $(, $($rest:tt)*)?) => {
+        $crate::mutations!(@acc [$($done,)* $crate::prelude::Mutation::set($key, $value),] $($($rest)*)?)
+    };
+    (@acc [$($done:expr,)*] ($key:expr) = $value:expr $(, $($rest:tt)*)?) => {
+        $crate::mutations!(@acc [$($done,)* $crate::prelude::Mutation::set($key, $value),] $($($rest)*)?)
+    };
+    (@acc [$($done:expr,)*] delete $key:literal $(, $($rest:tt)*)?) => {
+        $crate::mutations!(@acc [$($done,)* $crate::prelude::Mutation::delete($key),] $($($rest)*)?)
+    };
+    (@acc [$($done:expr,)*] delete ($key:expr) $(, $($rest:tt)*)?) => {
+        $crate::mutations!(@acc [$($done,)* $crate::prelude::Mutation::delete($key),] $($($rest)*)?)
+    };
+    (@acc [$($done:expr,)*] $mutation:expr $(, $($rest:tt)*)?) => {
+        $crate::mutations!(@acc [$($done,)* $mutation,] $($($rest)*)?)
+    };
+    ($($items:tt)*) => {
+        $crate::mutations!(@acc [] $($items)*)
+    };
+}
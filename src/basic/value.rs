@@ -1,6 +1,6 @@
-use std::fmt::Display;
-use std::hash::Hash;
-use std::ops::{Add, AddAssign, Sub, SubAssign};
+use core::fmt::Display;
+use core::hash::Hash;
+use core::ops::{Add, AddAssign, Mul, Sub, SubAssign};
 
 /// Represents a typed value that can be stored in a WorldState.
 ///
@@ -10,6 +10,9 @@ use std::ops::{Add, AddAssign, Sub, SubAssign};
 /// - 64-bit integers for discrete quantities (e.g., `health`, `ammo_count`)
 /// - 64-bit floating-point numbers for continuous values (e.g., `distance`, `time_remaining`)
 ///
+/// A key can also hold [`Value::Null`] to mark it as explicitly unset, which
+/// is different from the key being absent from the state.
+///
 /// # Examples
 /// ```
 /// use rust_goap::prelude::*;
@@ -18,7 +21,7 @@ use std::ops::{Add, AddAssign, Sub, SubAssign};
 /// let int_value: Value = 42.into();         // Value::I64(42)
 /// let float_value: Value = 3.14.into();     // Value::F64(3.14)
 /// ```
-#[derive(Clone, Debug, PartialOrd, Copy)]
+#[derive(Clone, Debug, Copy)]
 pub enum Value {
     /// Boolean value, typically used for logical state flags
     Bool(bool),
@@ -26,6 +29,39 @@ pub enum Value {
     I64(i64),
     /// 64-bit floating-point value, used for continuous measurements
     F64(f64),
+    /// Explicitly unset value: the key is present, but holds nothing
+    ///
+    /// A null key satisfies [`Assert::exists`](crate::prelude::Assert::exists)
+    /// and [`Assert::eq(Value::Null)`](crate::prelude::Assert::eq), but not
+    /// [`Assert::absent`](crate::prelude::Assert::absent). It never satisfies
+    /// ordering assertions, and `not_eq(Value::Null)` requires the key to hold
+    /// an actual value. Null doesn't support arithmetic.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let absent = WorldState::new();
+    /// let null = WorldState::new().unset("target");
+    /// let concrete = WorldState::new().set("target", 3);
+    ///
+    /// let is_null = Goal::new().with("target", Assert::eq(Value::Null));
+    /// let has_value = Goal::new().with("target", Assert::not_eq(Value::Null));
+    /// let is_absent = Goal::new().with("target", Assert::absent());
+    /// let exists = Goal::new().with("target", Assert::exists());
+    ///
+    /// let check = |state: &WorldState| {
+    ///     [&is_null, &has_value, &is_absent, &exists].map(|goal| goal.is_satisfied_by(state))
+    /// };
+    /// assert_eq!(check(&absent), [false, false, true, false]);
+    /// assert_eq!(check(&null), [true, false, false, true]);
+    /// assert_eq!(check(&concrete), [false, true, false, true]);
+    ///
+    /// // Null is never ordered against numbers
+    /// assert!(!compare_values(&Assert::lt(10), &Value::Null));
+    /// assert!(!compare_values(&Assert::gt_eq(0), &Value::Null));
+    /// ```
+    Null,
 }
 
 impl From<i64> for Value {
@@ -73,6 +109,186 @@ impl From<bool> for Value {
     }
 }
 
+impl From<i32> for Value {
+    /// Converts an `i32` to a `Value::I64`.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let state = WorldState::new().set("x", 5i32);
+    /// assert_eq!(state.get("x"), Some(&Value::I64(5)));
+    /// ```
+    fn from(value: i32) -> Self {
+        Value::I64(i64::from(value))
+    }
+}
+
+impl From<u32> for Value {
+    /// Converts a `u32` to a `Value::I64`.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let state = WorldState::new().set("arrows", 12u32);
+    /// assert_eq!(state.get("arrows"), Some(&Value::I64(12)));
+    /// ```
+    fn from(value: u32) -> Self {
+        Value::I64(i64::from(value))
+    }
+}
+
+impl From<usize> for Value {
+    /// Converts a `usize` to a `Value::I64`.
+    ///
+    /// Values larger than `i64::MAX` saturate at `i64::MAX` rather than
+    /// wrapping around to negative numbers.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let inventory = vec!["sword", "shield"];
+    /// let state = WorldState::new().set("item_count", inventory.len());
+    /// assert_eq!(state.get("item_count"), Some(&Value::I64(2)));
+    ///
+    /// assert_eq!(Value::from(usize::MAX), Value::I64(i64::MAX));
+    /// ```
+    fn from(value: usize) -> Self {
+        Value::I64(i64::try_from(value).unwrap_or(i64::MAX))
+    }
+}
+
+impl From<f32> for Value {
+    /// Converts an `f32` to a `Value::F64`.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let state = WorldState::new().set("y", 2.5f32);
+    /// assert_eq!(state.get("y"), Some(&Value::F64(2.5)));
+    /// ```
+    fn from(value: f32) -> Self {
+        Value::F64(f64::from(value))
+    }
+}
+
+/// Error returned when extracting a typed value from a [`Value`] of another variant.
+///
+/// # Examples
+/// ```
+/// use rust_goap::prelude::*;
+///
+/// let error = i64::try_from(Value::Bool(true)).unwrap_err();
+/// assert_eq!(error.expected, "i64");
+/// assert_eq!(error.actual, "bool");
+/// assert_eq!(error.to_string(), "expected an i64 value, found bool");
+///
+/// let error = bool::try_from(Value::Null).unwrap_err();
+/// assert_eq!(error.to_string(), "expected a bool value, found null");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ValueTypeError {
+    /// The name of the type that was expected (see [`Value::type_name`])
+    pub expected: &'static str,
+    /// The name of the type that was found
+    pub actual: &'static str,
+}
+
+impl Display for ValueTypeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let article = match self.expected {
+            "i64" | "f64" => "an",
+            _ => "a",
+        };
+        write!(f, "expected {article} {} value, found {}", self.expected, self.actual)
+    }
+}
+
+impl core::error::Error for ValueTypeError {}
+
+impl TryFrom<Value> for i64 {
+    type Error = ValueTypeError;
+
+    /// Extracts the integer from a `Value::I64`.
+    ///
+    /// # Errors
+    /// Returns a [`ValueTypeError`] if the value is not a `Value::I64`.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let health: i64 = Value::I64(80).try_into().unwrap();
+    /// assert_eq!(health, 80);
+    ///
+    /// let error = i64::try_from(Value::F64(80.0)).unwrap_err();
+    /// assert_eq!((error.expected, error.actual), ("i64", "f64"));
+    /// ```
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::I64(v) => Ok(v),
+            _ => Err(ValueTypeError { expected: "i64", actual: value.type_name() }),
+        }
+    }
+}
+
+impl TryFrom<Value> for f64 {
+    type Error = ValueTypeError;
+
+    /// Extracts the float from a `Value::F64`.
+    ///
+    /// Integers are not promoted: a `Value::I64` is a type mismatch.
+    ///
+    /// # Errors
+    /// Returns a [`ValueTypeError`] if the value is not a `Value::F64`.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let distance: f64 = Value::F64(2.5).try_into().unwrap();
+    /// assert_eq!(distance, 2.5);
+    ///
+    /// let error = f64::try_from(Value::I64(2)).unwrap_err();
+    /// assert_eq!((error.expected, error.actual), ("f64", "i64"));
+    /// ```
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::F64(v) => Ok(v),
+            _ => Err(ValueTypeError { expected: "f64", actual: value.type_name() }),
+        }
+    }
+}
+
+impl TryFrom<Value> for bool {
+    type Error = ValueTypeError;
+
+    /// Extracts the boolean from a `Value::Bool`.
+    ///
+    /// # Errors
+    /// Returns a [`ValueTypeError`] if the value is not a `Value::Bool`.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let is_hungry: bool = Value::Bool(true).try_into().unwrap();
+    /// assert!(is_hungry);
+    ///
+    /// let error = bool::try_from(Value::I64(1)).unwrap_err();
+    /// assert_eq!((error.expected, error.actual), ("bool", "i64"));
+    /// ```
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Bool(v) => Ok(v),
+            _ => Err(ValueTypeError { expected: "bool", actual: value.type_name() }),
+        }
+    }
+}
+
 impl Hash for Value {
     /// Implements hashing for `Value`.
     ///
@@ -80,12 +296,18 @@ impl Hash for Value {
     /// The hash includes the enum discriminant to ensure different variants
     /// hash to different values, even if their contained values might be equal
     /// when interpreted differently (e.g., `1` as `i64` vs `1.0` as `f64`).
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+    ///
+    /// Floats that compare equal hash equally: `0.0` and `-0.0` share a hash,
+    /// as do all `NaN`s.
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         core::mem::discriminant(self).hash(state);
         match self {
             Value::Bool(b) => b.hash(state),
             Value::I64(i) => i.hash(state),
-            Value::F64(f) => f.to_bits().hash(state),
+            Value::F64(f) if f.is_nan() => f64::NAN.to_bits().hash(state),
+            // Adding 0.0 turns -0.0 into 0.0
+            Value::F64(f) => (f + 0.0).to_bits().hash(state),
+            Value::Null => {},
         }
     }
 }
@@ -97,6 +319,9 @@ impl PartialEq for Value {
     /// and contain equal inner values. Different variants (e.g., `I64` vs `F64`)
     /// are never equal, even if their numerical values would be equal.
     ///
+    /// Equality is consistent with the [`Ord`] implementation, so unlike for
+    /// `f64`, every `NaN` is equal to every other `NaN`.
+    ///
     /// # Examples
     /// ```
     /// use rust_goap::prelude::*;
@@ -107,14 +332,10 @@ impl PartialEq for Value {
     ///
     /// assert_eq!(a, b);     // Both are I64(42)
     /// assert_ne!(a, c);     // I64(42) != F64(42.0)
+    /// assert_eq!(Value::F64(f64::NAN), Value::F64(f64::NAN));
     /// ```
     fn eq(&self, other: &Self) -> bool {
-        match (self, other) {
-            (Self::Bool(l0), Self::Bool(r0)) => l0 == r0,
-            (Self::I64(l0), Self::I64(r0)) => l0 == r0,
-            (Self::F64(l0), Self::F64(r0)) => l0 == r0,
-            _ => false,
-        }
+        self.cmp(other) == core::cmp::Ordering::Equal
     }
 }
 
@@ -124,17 +345,85 @@ impl PartialEq for Value {
 /// `Value` can safely implement `Eq`.
 impl Eq for Value {}
 
+impl PartialOrd for Value {
+    /// Compares two values; always returns `Some`, see the [`Ord`] implementation.
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Value {
+    /// Defines a total ordering over all values.
+    ///
+    /// Values of different variants are ordered by variant, with
+    /// `Null < Bool < I64 < F64`, regardless of the numbers they hold. This ordering
+    /// is meant for sorting and ordered collections; numeric comparisons that
+    /// mix `I64` and `F64` are done by [`compare_values`](crate::prelude::compare_values).
+    ///
+    /// Within a variant, values are ordered naturally (`false < true`). For
+    /// `F64`, `NaN` is greater than every other float, including infinity, and
+    /// all `NaN`s are equal to each other, so a `NaN` entering the world state
+    /// can't corrupt the ordering. `-0.0` and `0.0` are equal.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// // Cross-variant ordering
+    /// assert!(Value::Bool(true) < Value::I64(i64::MIN));
+    /// assert!(Value::I64(i64::MAX) < Value::F64(f64::NEG_INFINITY));
+    ///
+    /// // NaN is greater than every real number and equal to itself
+    /// let nan = Value::F64(f64::NAN);
+    /// assert!(nan > Value::F64(f64::INFINITY));
+    /// assert!(Value::F64(-1.0) < nan);
+    /// assert_eq!(nan.cmp(&nan), std::cmp::Ordering::Equal);
+    ///
+    /// let mut values = vec![nan, Value::F64(1.5), Value::I64(3), Value::Bool(false), Value::F64(-2.0)];
+    /// values.sort();
+    /// assert_eq!(values[..4], [Value::Bool(false), Value::I64(3), Value::F64(-2.0), Value::F64(1.5)]);
+    /// assert!(matches!(values[4], Value::F64(f) if f.is_nan()));
+    /// ```
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        use core::cmp::Ordering;
+
+        match (self, other) {
+            (Value::Bool(lhs), Value::Bool(rhs)) => lhs.cmp(rhs),
+            (Value::I64(lhs), Value::I64(rhs)) => lhs.cmp(rhs),
+            (Value::F64(lhs), Value::F64(rhs)) => match (lhs.is_nan(), rhs.is_nan()) {
+                (true, true) => Ordering::Equal,
+                (true, false) => Ordering::Greater,
+                (false, true) => Ordering::Less,
+                (false, false) => lhs.partial_cmp(rhs).unwrap_or(Ordering::Equal),
+            },
+            _ => self.variant_rank().cmp(&other.variant_rank()),
+        }
+    }
+}
+
 impl Value {
-    /// Calculates the distance between two values of the same type.
+    /// Calculates the distance between two values.
     ///
     /// This method is used by the GOAP planner to estimate how far a current
     /// state is from a desired goal state. The distance metric varies by type:
     /// - For `Bool`: 0 if equal, 1 if different
     /// - For `I64`: absolute difference as unsigned 64-bit integer
-    /// - For `F64`: absolute difference converted to unsigned 64-bit integer
+    /// - For `F64`: absolute difference rounded up to the next integer, so that
+    ///   values that differ by less than one unit still have a distance of 1
+    /// - For `Null`: 0 from another null, 1 from any other value, since setting
+    ///   or unsetting a key is a single change
+    ///
+    /// `I64` and `F64` values can be mixed, in which case the integer is
+    /// promoted to `F64`. Distances that don't fit in a `u64` (including
+    /// infinite and `NaN` differences) saturate at `u64::MAX`.
+    ///
+    /// A `Bool` and a number have a distance of 1, like other values that
+    /// can't be measured against each other.
     ///
     /// # Panics
-    /// Panics if the two values are of different variants (e.g., comparing `Bool` with `I64`).
+    /// With the `strict` feature, panics if a `Bool` is compared with a
+    /// number, with a message naming both types (e.g. "expected bool, got
+    /// i64"), to catch misconfigured domains while debugging.
     ///
     /// # Examples
     /// ```
@@ -147,20 +436,179 @@ impl Value {
     /// assert_eq!(a.distance(&b), 5);     // |10 - 15| = 5
     /// assert_eq!(c.distance(&c), 0);     // true == true
     /// ```
+    ///
+    /// Float distances:
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// // Fractional differences are rounded up
+    /// assert_eq!(Value::F64(1.0).distance(&Value::F64(1.4)), 1);
+    /// assert_eq!(Value::F64(1.0).distance(&Value::F64(3.5)), 3);
+    /// assert_eq!(Value::F64(2.5).distance(&Value::F64(2.5)), 0);
+    ///
+    /// // Integers and floats can be mixed
+    /// assert_eq!(Value::I64(10).distance(&Value::F64(10.5)), 1);
+    ///
+    /// // Huge differences saturate instead of wrapping or panicking
+    /// assert_eq!(Value::F64(-1e300).distance(&Value::F64(1e300)), u64::MAX);
+    /// assert_eq!(Value::F64(0.0).distance(&Value::F64(f64::INFINITY)), u64::MAX);
+    /// assert_eq!(Value::F64(0.0).distance(&Value::F64(f64::NAN)), u64::MAX);
+    /// assert_eq!(Value::I64(i64::MIN).distance(&Value::I64(i64::MAX)), u64::MAX);
+    /// ```
+    ///
+    /// Null distances:
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// assert_eq!(Value::Null.distance(&Value::Null), 0);
+    /// assert_eq!(Value::Null.distance(&Value::I64(100)), 1);
+    /// assert_eq!(Value::Bool(true).distance(&Value::Null), 1);
+    /// ```
     pub fn distance(&self, other: &Value) -> u64 {
         match (self, other) {
-            (Value::Bool(lhs), Value::Bool(rhs)) => {
-                if lhs == rhs {
-                    0
-                } else {
-                    1
-                }
-            },
-            (Value::I64(lhs), Value::I64(rhs)) => (lhs - rhs).unsigned_abs(),
-            (Value::F64(lhs), Value::F64(rhs)) => (lhs - rhs).abs() as u64,
-            _ => panic!("Cannot calculate distance between different Value types"),
+            (Value::Bool(lhs), Value::Bool(rhs)) => u64::from(lhs != rhs),
+            (Value::I64(lhs), Value::I64(rhs)) => lhs.abs_diff(*rhs),
+            (Value::F64(lhs), Value::F64(rhs)) => float_distance(*lhs, *rhs),
+            (Value::I64(lhs), Value::F64(rhs)) => float_distance(*lhs as f64, *rhs),
+            (Value::F64(lhs), Value::I64(rhs)) => float_distance(*lhs, *rhs as f64),
+            (Value::Null, Value::Null) => 0,
+            (Value::Null, _) | (_, Value::Null) => 1,
+            #[cfg(feature = "strict")]
+            _ => panic!(
+                "cannot calculate the distance between values: expected {}, got {}",
+                self.type_name(),
+                other.type_name()
+            ),
+            #[cfg(not(feature = "strict"))]
+            _ => 1,
+        }
+    }
+
+    /// Returns the name of the value's type: `"bool"`, `"i64"`, `"f64"`, or
+    /// `"null"`.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// assert_eq!(Value::Bool(true).type_name(), "bool");
+    /// assert_eq!(Value::I64(42).type_name(), "i64");
+    /// assert_eq!(Value::F64(0.5).type_name(), "f64");
+    /// assert_eq!(Value::Null.type_name(), "null");
+    /// ```
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Value::Bool(_) => "bool",
+            Value::I64(_) => "i64",
+            Value::F64(_) => "f64",
+            Value::Null => "null",
         }
     }
+
+    /// Converts the value to its JSON representation.
+    ///
+    /// Non-finite floats have no JSON representation and become `null`.
+    #[cfg(feature = "serde_json")]
+    pub(crate) fn to_json_value(self) -> serde_json::Value {
+        match self {
+            Value::Bool(b) => serde_json::Value::from(b),
+            Value::I64(i) => serde_json::Value::from(i),
+            Value::F64(f) => serde_json::Value::from(f),
+            Value::Null => serde_json::Value::Null,
+        }
+    }
+
+    /// Checks whether two values have the same type, whatever they hold.
+    ///
+    /// # Arguments
+    /// * `other` - The value to compare types with
+    ///
+    /// # Returns
+    /// `true` if both values are of the same variant, `false` otherwise.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// assert!(Value::I64(1).same_type_as(&Value::I64(-7)));
+    /// assert!(Value::Bool(true).same_type_as(&Value::Bool(false)));
+    ///
+    /// // Integers and floats are different types
+    /// assert!(!Value::I64(1).same_type_as(&Value::F64(1.0)));
+    /// assert!(!Value::Bool(true).same_type_as(&Value::I64(1)));
+    /// ```
+    pub fn same_type_as(&self, other: &Value) -> bool {
+        core::mem::discriminant(self) == core::mem::discriminant(other)
+    }
+
+    /// Linearly interpolates between two numeric values.
+    ///
+    /// Computes `self + (other - self) * t` as a float, so integers are
+    /// promoted to `F64` and the result is always a `Value::F64`, even when
+    /// both operands are integers. `t` isn't clamped: values outside
+    /// `0.0..=1.0` extrapolate.
+    ///
+    /// # Arguments
+    /// * `other` - The value reached at `t = 1.0`
+    /// * `t` - The interpolation factor
+    ///
+    /// # Returns
+    /// * `Some(Value::F64(_))` if both values are numbers
+    /// * `None` if either value is a `Bool` or `Null`
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let (low, high) = (Value::I64(10), Value::I64(20));
+    /// assert_eq!(low.lerp(&high, 0.0), Some(Value::F64(10.0)));
+    /// assert_eq!(low.lerp(&high, 1.0), Some(Value::F64(20.0)));
+    /// assert_eq!(low.lerp(&high, 0.5), Some(Value::F64(15.0)));
+    ///
+    /// let (low, high) = (Value::F64(-1.0), Value::F64(2.0));
+    /// assert_eq!(low.lerp(&high, 0.0), Some(Value::F64(-1.0)));
+    /// assert_eq!(low.lerp(&high, 1.0), Some(Value::F64(2.0)));
+    /// assert_eq!(low.lerp(&high, 0.5), Some(Value::F64(0.5)));
+    ///
+    /// // Mixed operands are promoted
+    /// assert_eq!(Value::I64(1).lerp(&Value::F64(2.0), 0.5), Some(Value::F64(1.5)));
+    /// assert_eq!(Value::F64(0.0).lerp(&Value::I64(4), 0.5), Some(Value::F64(2.0)));
+    ///
+    /// // Booleans and nulls can't be interpolated
+    /// assert_eq!(Value::Bool(false).lerp(&Value::Bool(true), 0.5), None);
+    /// assert_eq!(Value::Null.lerp(&Value::I64(1), 0.5), None);
+    /// ```
+    pub fn lerp(&self, other: &Value, t: f64) -> Option<Value> {
+        let as_f64 = |value: &Value| match value {
+            Value::I64(v) => Some(*v as f64),
+            Value::F64(v) => Some(*v),
+            Value::Bool(_) | Value::Null => None,
+        };
+        let (from, to) = (as_f64(self)?, as_f64(other)?);
+        Some(Value::F64(from + (to - from) * t))
+    }
+
+    /// Returns the position of the variant in the cross-variant ordering.
+    fn variant_rank(&self) -> u8 {
+        match self {
+            Value::Null => 0,
+            Value::Bool(_) => 1,
+            Value::I64(_) => 2,
+            Value::F64(_) => 3,
+        }
+    }
+}
+
+/// Calculates the distance between two floats, rounded up and saturating at
+/// `u64::MAX` (which `NaN` differences also map to).
+fn float_distance(lhs: f64, rhs: f64) -> u64 {
+    let difference = (lhs - rhs).abs();
+    if difference.is_nan() || difference >= u64::MAX as f64 {
+        return u64::MAX;
+    }
+    // Round up by hand, since `f64::ceil` isn't available without `std`
+    let truncated = difference as u64;
+    if (truncated as f64) < difference { truncated + 1 } else { truncated }
 }
 
 impl Display for Value {
@@ -181,7 +629,7 @@ impl Display for Value {
     /// assert_eq!(format!("{}", int_val), "Value:I64(42)");
     /// assert_eq!(format!("{}", float_val), "Value:F64(3.14)");
     /// ```
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Self::Bool(v) => {
                 write!(f, "Value:Bool({v})")
@@ -192,6 +640,7 @@ impl Display for Value {
             Self::F64(v) => {
                 write!(f, "Value:F64({v})")
             },
+            Self::Null => write!(f, "Value:Null"),
         }
     }
 }
@@ -205,12 +654,14 @@ impl Add for Value {
     /// - `I64 + I64` → `I64`
     /// - `F64 + F64` → `F64`
     ///
-    /// Boolean values do not support addition.
+    /// Boolean values do not support addition. Values that can't be added,
+    /// including values of different variants, leave the left-hand side
+    /// unchanged. `I64` sums saturate at the bounds of `i64` instead of
+    /// overflowing.
     ///
     /// # Panics
-    /// Panics if:
-    /// - The values are of different variants
-    /// - Either value is `Bool` (booleans don't support arithmetic)
+    /// With the `strict` feature, panics on values that can't be added instead,
+    /// with a message naming both types (e.g. "expected i64, got bool").
     ///
     /// # Examples
     /// ```
@@ -221,12 +672,24 @@ impl Add for Value {
     /// let result = a + b;
     ///
     /// assert!(matches!(result, Value::I64(15)));
+    ///
+    /// assert_eq!(Value::I64(i64::MAX) + Value::I64(1), Value::I64(i64::MAX));
     /// ```
     fn add(self, other: Value) -> Value {
+        #[cfg(feature = "strict")]
+        assert!(
+            self.same_type_as(&other),
+            "cannot add values: expected {}, got {}",
+            self.type_name(),
+            other.type_name()
+        );
         match (self, other) {
-            (Value::I64(a), Value::I64(b)) => Value::I64(a + b),
+            (Value::I64(a), Value::I64(b)) => Value::I64(a.saturating_add(b)),
             (Value::F64(a), Value::F64(b)) => Value::F64(a + b),
-            _ => panic!("Unsupported addition between Datum variants, {self:?} - {other:?}"),
+            #[cfg(feature = "strict")]
+            _ => panic!("cannot add {} values", self.type_name()),
+            #[cfg(not(feature = "strict"))]
+            _ => self,
         }
     }
 }
@@ -240,12 +703,14 @@ impl Sub for Value {
     /// - `I64 - I64` → `I64`
     /// - `F64 - F64` → `F64`
     ///
-    /// Boolean values do not support subtraction.
+    /// Boolean values do not support subtraction. Values that can't be
+    /// subtracted, including values of different variants, leave the left-hand
+    /// side unchanged. `I64` differences saturate at the bounds of `i64`
+    /// instead of overflowing.
     ///
     /// # Panics
-    /// Panics if:
-    /// - The values are of different variants
-    /// - Either value is `Bool` (booleans don't support arithmetic)
+    /// With the `strict` feature, panics on values that can't be subtracted
+    /// instead, with a message naming both types (e.g. "expected f64, got i64").
     ///
     /// # Examples
     /// ```
@@ -256,12 +721,74 @@ impl Sub for Value {
     /// let result = a - b;
     ///
     /// assert!(matches!(result, Value::I64(5)));
+    ///
+    /// assert_eq!(Value::I64(i64::MIN) - Value::I64(1), Value::I64(i64::MIN));
     /// ```
     fn sub(self, other: Value) -> Value {
+        #[cfg(feature = "strict")]
+        assert!(
+            self.same_type_as(&other),
+            "cannot subtract values: expected {}, got {}",
+            self.type_name(),
+            other.type_name()
+        );
         match (self, other) {
-            (Value::I64(a), Value::I64(b)) => Value::I64(a - b),
+            (Value::I64(a), Value::I64(b)) => Value::I64(a.saturating_sub(b)),
             (Value::F64(a), Value::F64(b)) => Value::F64(a - b),
-            _ => panic!("Unsupported negation between Datum variants, {self:?} - {other:?}"),
+            #[cfg(feature = "strict")]
+            _ => panic!("cannot subtract {} values", self.type_name()),
+            #[cfg(not(feature = "strict"))]
+            _ => self,
+        }
+    }
+}
+
+impl Mul for Value {
+    type Output = Value;
+
+    /// Multiplies two `Value` instances together.
+    ///
+    /// Supports multiplication between values of the same numeric type:
+    /// - `I64 * I64` → `I64`
+    /// - `F64 * F64` → `F64`
+    ///
+    /// Boolean values do not support multiplication. Values that can't be
+    /// multiplied, including values of different variants, leave the left-hand
+    /// side unchanged. `I64` products saturate at the bounds of `i64` instead
+    /// of overflowing.
+    ///
+    /// # Panics
+    /// With the `strict` feature, panics on values that can't be multiplied
+    /// instead, with a message naming both types (e.g. "expected i64, got f64").
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let a: Value = 10.into();
+    /// let b: Value = 5.into();
+    /// let result = a * b;
+    ///
+    /// assert!(matches!(result, Value::I64(50)));
+    ///
+    /// assert_eq!(Value::I64(i64::MAX) * Value::I64(3), Value::I64(i64::MAX));
+    /// assert_eq!(Value::I64(i64::MIN) * Value::I64(3), Value::I64(i64::MIN));
+    /// ```
+    fn mul(self, other: Value) -> Value {
+        #[cfg(feature = "strict")]
+        assert!(
+            self.same_type_as(&other),
+            "cannot multiply values: expected {}, got {}",
+            self.type_name(),
+            other.type_name()
+        );
+        match (self, other) {
+            (Value::I64(a), Value::I64(b)) => Value::I64(a.saturating_mul(b)),
+            (Value::F64(a), Value::F64(b)) => Value::F64(a * b),
+            #[cfg(feature = "strict")]
+            _ => panic!("cannot multiply {} values", self.type_name()),
+            #[cfg(not(feature = "strict"))]
+            _ => self,
         }
     }
 }
@@ -272,25 +799,15 @@ impl AddAssign for Value {
     /// This is the in-place version of the `Add` trait, allowing `+=` syntax.
     /// Only supports same-type numeric operations.
     ///
+    /// Values that can't be added and overflows are handled like in
+    /// [`Add`](#impl-Add-for-Value). The planner skips effects that would add
+    /// such values or overflow (see
+    /// [`Effect::validate`](crate::prelude::Effect::validate)).
+    ///
     /// # Panics
-    /// Currently panics with "unimplemented" for unsupported operations.
-    /// In the future, this should be changed to panic with a clearer error message.
+    /// With the `strict` feature, panics like [`Add`](#impl-Add-for-Value).
     fn add_assign(&mut self, rhs: Self) {
-        match self {
-            Self::I64(v1) => match rhs {
-                Self::I64(v2) => {
-                    *v1 += v2;
-                },
-                _ => unimplemented!("Unimplemented! Tried to add {self:?} to {rhs:?}"),
-            },
-            Self::F64(v1) => match rhs {
-                Self::F64(v2) => {
-                    *v1 += v2;
-                },
-                _ => unimplemented!("Unimplemented! Tried to add {self:?} to {rhs:?}"),
-            },
-            _ => unimplemented!("Unimplemented! Tried to add {self:?} to {rhs:?}"),
-        }
+        *self = *self + rhs;
     }
 }
 
@@ -300,24 +817,14 @@ impl SubAssign for Value {
     /// This is the in-place version of the `Sub` trait, allowing `-=` syntax.
     /// Only supports same-type numeric operations.
     ///
+    /// Values that can't be subtracted and overflows are handled like in
+    /// [`Sub`](#impl-Sub-for-Value). The planner skips effects that would
+    /// subtract such values or overflow (see
+    /// [`Effect::validate`](crate::prelude::Effect::validate)).
+    ///
     /// # Panics
-    /// Currently panics with "unimplemented" for unsupported operations.
-    /// In the future, this should be changed to panic with a clearer error message.
+    /// With the `strict` feature, panics like [`Sub`](#impl-Sub-for-Value).
     fn sub_assign(&mut self, rhs: Self) {
-        match self {
-            Self::I64(v1) => match rhs {
-                Self::I64(v2) => {
-                    *v1 -= v2;
-                },
-                _ => unimplemented!("Unimplemented! Tried to subtract {rhs:?} from {self:?}"),
-            },
-            Self::F64(v1) => match rhs {
-                Self::F64(v2) => {
-                    *v1 -= v2;
-                },
-                _ => unimplemented!("Unimplemented! Tried to subtract {rhs:?} from {self:?}"),
-            },
-            _ => unimplemented!("Unimplemented! Tried to subtract {rhs:?} from {self:?}"),
-        }
+        *self = *self - rhs;
     }
 }
@@ -1,5 +1,10 @@
-use crate::basic::mutation::Mutation;
-use std::hash::{Hash, Hasher};
+use crate::basic::expr::Expr;
+use crate::basic::mutation::{Mutation, apply_mutator};
+use crate::basic::value::Value;
+use crate::error::EffectError;
+use crate::world_state::WorldState;
+use alloc::{string::String, string::ToString, vec, vec::Vec};
+use core::hash::{Hash, Hasher};
 
 /// Represents the outcome of executing an action in a Goal-Oriented Action Planning (GOAP) system.
 ///
@@ -21,20 +26,47 @@ use std::hash::{Hash, Hasher};
 ///
 /// // Create an effect for an attack action
 /// let attack_effect = Effect::new()
-///     .with_mutation("enemy_health", Mutation::decrement("", 25))
-///     .with_mutation("ammo_count", Mutation::decrement("", 1))
+///     .add_mutation(Mutation::decrement("enemy_health", 25))
+///     .add_mutation(Mutation::decrement("ammo_count", 1))
 ///     .with_cost(2); // Attacking has a higher cost than other actions
 ///
 /// // Create an effect for a healing action
 /// let heal_effect = Effect::new()
-///     .with_mutation("player_health", Mutation::increment("", 50))
-///     .with_mutation("medical_supplies", Mutation::decrement("", 1));
+///     .add_mutation(Mutation::increment("player_health", 50))
+///     .add_mutation(Mutation::decrement("medical_supplies", 1));
 ///     // Uses default cost of 1
 ///
 /// // Create an effect for a resource gathering action
 /// let gather_effect = Effect::new()
-///     .with_mutation("wood_count", Mutation::increment("", 5))
-///     .with_mutation("stamina", Mutation::decrement("", 10));
+///     .add_mutation(Mutation::increment("wood_count", 5))
+///     .add_mutation(Mutation::decrement("stamina", 10));
+/// ```
+///
+/// # Building Effects
+/// [`Effect::with_mutation`] retargets the mutation it is given to its `key`
+/// argument, which is why the examples above pass `""` as the mutation's own
+/// key. [`Effect::add_mutation`] and [`Effect::with_mutations`] keep the keys
+/// of the mutations instead, and combined with the
+/// [`mutations!`](crate::mutations) macro are the recommended way to build
+/// an effect:
+/// ```
+/// use rust_goap::prelude::*;
+///
+/// let attack_effect = Effect::new()
+///     .with_mutations(mutations! { "enemy_health" -= 25, "ammo_count" -= 1 })
+///     .with_cost(2);
+///
+/// let same_effect = Effect::new()
+///     .add_mutation(Mutation::decrement("enemy_health", 25))
+///     .add_mutation(Mutation::decrement("ammo_count", 1))
+///     .with_cost(2);
+/// assert_eq!(attack_effect, same_effect);
+///
+/// let retargeted = Effect::new()
+///     .add_mutation(Mutation::decrement("enemy_health", 25))
+///     .add_mutation(Mutation::decrement("ammo_count", 1))
+///     .with_cost(2);
+/// assert_eq!(attack_effect, retargeted);
 /// ```
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Effect {
@@ -48,8 +80,8 @@ pub struct Effect {
     /// use rust_goap::prelude::*;
     ///
     /// let effect = Effect::new()
-    ///     .with_mutation("health", Mutation::increment("", 20))
-    ///     .with_mutation("hunger", Mutation::decrement("", 5));
+    ///     .add_mutation(Mutation::increment("health", 20))
+    ///     .add_mutation(Mutation::decrement("hunger", 5));
     ///
     /// assert_eq!(effect.mutations.len(), 2);
     /// ```
@@ -61,7 +93,8 @@ pub struct Effect {
     /// Higher costs make actions less desirable to the planner. The default cost is 1.
     ///
     /// Costs are used by the planner to find the most efficient (lowest total cost)
-    /// sequence of actions to achieve a goal.
+    /// sequence of actions to achieve a goal. They should be positive (see
+    /// [`Effect::with_cost`]).
     ///
     /// # Example
     /// ```
@@ -74,6 +107,52 @@ pub struct Effect {
     /// assert_eq!(expensive_effect.cost, 5);
     /// ```
     pub cost: usize,
+
+    /// An optional cost that scales with how much the effect changes one key.
+    ///
+    /// When set to `(key, cost_per_unit)`, applying the effect costs
+    /// `cost + cost_per_unit * delta`, where `delta` is the distance (see
+    /// [`Value::distance`](crate::prelude::Value::distance)) between the value
+    /// of `key` before and after the effect is applied. Since the delta is
+    /// resolved at apply time, state-dependent mutations are priced by what
+    /// they actually changed. This makes, for example, moving 10 units more
+    /// expensive than moving 1 unit. `None` (the default) keeps the flat `cost`.
+    ///
+    /// # Example
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let effect = Effect::new()
+    ///     .add_mutation(Mutation::decrement("distance", 10))
+    ///     .with_cost_per_unit("distance", 2);
+    ///
+    /// assert_eq!(effect.cost_per_unit, Some(("distance".to_string(), 2)));
+    /// ```
+    pub cost_per_unit: Option<(String, usize)>,
+
+    /// Extra costs of individual mutations, added to the flat `cost`.
+    ///
+    /// The cost at an index belongs to the mutation at the same index of
+    /// [`Effect::mutations`]; mutations without an entry (including all of
+    /// them when this is empty, the default) add nothing. This lets one
+    /// effect price its changes separately, e.g. keep discarding a junk item
+    /// free while discarding a valuable one costs extra. Use
+    /// [`Effect::with_mutation_cost`] to set the cost of the last added
+    /// mutation.
+    ///
+    /// # Example
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let effect = Effect::new()
+    ///     .add_mutation(Mutation::delete("junk"))
+    ///     .add_mutation(Mutation::delete("sword"))
+    ///     .with_mutation_cost(4);
+    ///
+    /// assert_eq!(effect.mutation_costs, vec![0, 4]);
+    /// assert_eq!(effect.fixed_cost(), 5);
+    /// ```
+    pub mutation_costs: Vec<usize>,
 }
 
 impl Default for Effect {
@@ -90,7 +169,32 @@ impl Default for Effect {
     /// assert_eq!(default_effect.cost, 1);
     /// ```
     fn default() -> Self {
-        Self { mutations: vec![], cost: 1 }
+        Self { mutations: vec![], cost: 1, cost_per_unit: None, mutation_costs: vec![] }
+    }
+}
+
+impl From<Vec<Mutation>> for Effect {
+    /// Creates an effect with the given mutations, keeping their own keys,
+    /// and the default cost of 1.
+    ///
+    /// # Example
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let effect = Effect::from(mutations! { "wood" += 5, "has_axe" = true });
+    /// assert_eq!(effect.mutations, vec![Mutation::increment("wood", 5), Mutation::set("has_axe", true)]);
+    /// assert_eq!(effect.cost, 1);
+    /// ```
+    fn from(mutations: Vec<Mutation>) -> Self {
+        Self { mutations, ..Self::default() }
+    }
+}
+
+impl FromIterator<Mutation> for Effect {
+    /// Collects mutations into an effect with the default cost of 1, keeping
+    /// their own keys.
+    fn from_iter<I: IntoIterator<Item = Mutation>>(mutations: I) -> Self {
+        Self::from(mutations.into_iter().collect::<Vec<_>>())
     }
 }
 
@@ -112,13 +216,16 @@ impl Effect {
     /// assert_eq!(effect.cost, 1);
     /// ```
     pub fn new() -> Self {
-        Self { mutations: vec![], cost: 1 }
+        Self { mutations: vec![], cost: 1, cost_per_unit: None, mutation_costs: vec![] }
     }
 
     /// Adds a mutation to the effect using the builder pattern.
     ///
     /// This method allows fluent chaining to build complex effects with multiple
-    /// state changes.
+    /// state changes. The mutation is retargeted to `key`, so it should be
+    /// created with an empty key (or `key` itself). Prefer
+    /// [`Effect::add_mutation`] or [`Effect::with_mutations`], which keep the
+    /// mutation's own key.
     ///
     /// # Arguments
     /// * `key` - The world state variable to modify
@@ -127,26 +234,95 @@ impl Effect {
     /// # Returns
     /// The modified `Effect` instance (for method chaining).
     ///
+    /// # Panics
+    /// In debug builds, panics if the mutation already targets a different,
+    /// non-empty key, which would otherwise be silently replaced.
+    ///
     /// # Example
     /// ```
     /// use rust_goap::prelude::*;
     ///
     /// let effect = Effect::new()
     ///     .with_mutation("health", Mutation::increment("", 50))
-    ///     .with_mutation("stamina", Mutation::decrement("", 20));
+    ///     .with_mutation("stamina", Mutation::decrement("stamina", 20));
     ///
-    /// assert_eq!(effect.mutations.len(), 2);
+    /// assert_eq!(
+    ///     effect.mutations,
+    ///     vec![Mutation::increment("health", 50), Mutation::decrement("stamina", 20)]
+    /// );
+    /// ```
+    ///
+    /// A mutation targeting another key is a mistake:
+    /// ```should_panic
+    /// use rust_goap::prelude::*;
+    ///
+    /// // Panics in debug builds: is it "mana" or "health" that should go up?
+    /// let effect = Effect::new().with_mutation("mana", Mutation::increment("health", 50));
+    /// # if !cfg!(debug_assertions) { panic!("release builds don't check keys") }
     /// ```
     pub fn with_mutation(mut self, key: impl Into<String>, mutation: Mutation) -> Self {
+        let key = key.into();
+        debug_assert!(
+            mutation.key().is_empty() || mutation.key() == key,
+            "mutation of {:?} added to an effect under key {:?}; use Effect::add_mutation to keep its own key",
+            mutation.key(),
+            key
+        );
         // Convert the mutation to ensure it has the correct key
-        let final_mutation = match mutation {
-            Mutation::Set(_, value) => Mutation::Set(key.into(), value),
-            Mutation::Delete(_) => Mutation::Delete(key.into()),
-            Mutation::Increment(_, value) => Mutation::Increment(key.into(), value),
-            Mutation::Decrement(_, value) => Mutation::Decrement(key.into(), value),
-        };
+        self.mutations.push(mutation.rekeyed(key));
+        self
+    }
+
+    /// Adds a mutation to the effect, keeping the key it was created with.
+    ///
+    /// # Arguments
+    /// * `mutation` - The mutation operation to apply, targeting its own key
+    ///
+    /// # Returns
+    /// The modified `Effect` instance (for method chaining).
+    ///
+    /// # Example
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let effect = Effect::new()
+    ///     .add_mutation(Mutation::increment("health", 50))
+    ///     .add_mutation(Mutation::decrement("stamina", 20));
+    ///
+    /// assert_eq!(
+    ///     effect,
+    ///     Effect::new()
+    ///         .with_mutation("health", Mutation::increment("", 50))
+    ///         .with_mutation("stamina", Mutation::decrement("", 20))
+    /// );
+    /// ```
+    pub fn add_mutation(mut self, mutation: Mutation) -> Self {
+        self.mutations.push(mutation);
+        self
+    }
 
-        self.mutations.push(final_mutation);
+    /// Adds several mutations to the effect, keeping their own keys.
+    ///
+    /// # Arguments
+    /// * `mutations` - The mutations to add, e.g. built with
+    ///   [`mutations!`](crate::mutations)
+    ///
+    /// # Returns
+    /// The modified `Effect` instance (for method chaining).
+    ///
+    /// # Example
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let effect = Effect::new()
+    ///     .with_mutations([Mutation::increment("health", 50), Mutation::decrement("stamina", 20)]);
+    ///
+    /// let mut state = WorldState::new().set("health", 10).set("stamina", 30);
+    /// effect.apply_to(&mut state);
+    /// assert_eq!(state, WorldState::new().set("health", 60).set("stamina", 10));
+    /// ```
+    pub fn with_mutations(mut self, mutations: impl IntoIterator<Item = Mutation>) -> Self {
+        self.mutations.extend(mutations);
         self
     }
 
@@ -155,8 +331,13 @@ impl Effect {
     /// Cost influences the planner's decision-making. Actions with lower cost
     /// effects are preferred when multiple action sequences can achieve the same goal.
     ///
+    /// Costs must be positive for correct planning: free actions make
+    /// arbitrarily long plans look as cheap as short ones, so the search may
+    /// wander through them instead of heading for the goal. A cost of `0` is
+    /// therefore raised to `1` (logging a warning with the `log` feature).
+    ///
     /// # Arguments
-    /// * `cost` - The cost value (must be > 0 for meaningful planning)
+    /// * `cost` - The cost value, at least 1
     ///
     /// # Returns
     /// The modified `Effect` instance (for method chaining).
@@ -166,16 +347,158 @@ impl Effect {
     /// use rust_goap::prelude::*;
     ///
     /// let effect = Effect::new()
-    ///     .with_mutation("distance", Mutation::decrement("", 10))
+    ///     .add_mutation(Mutation::decrement("distance", 10))
     ///     .with_cost(3); // Moving has a cost of 3
     ///
     /// assert_eq!(effect.cost, 3);
+    ///
+    /// // Zero costs are clamped
+    /// assert_eq!(Effect::new().with_cost(0).cost, 1);
     /// ```
     pub fn with_cost(mut self, cost: usize) -> Self {
-        self.cost = cost;
+        #[cfg(feature = "log")]
+        if cost == 0 {
+            log::warn!("effect cost must be positive, using 1 instead of 0");
+        }
+        self.cost = cost.max(1);
+        self
+    }
+
+    /// Sets a cost that scales with how much the effect changes a key.
+    ///
+    /// See [`Effect::cost_per_unit`] for how the scaled cost is computed.
+    ///
+    /// # Arguments
+    /// * `key` - The world state variable whose change is priced
+    /// * `cost_per_unit` - The cost added per unit of change
+    ///
+    /// # Returns
+    /// The modified `Effect` instance (for method chaining).
+    ///
+    /// # Example
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let start = WorldState::new().set("distance", 10);
+    /// let goal = Goal::new().with("distance", Assert::eq(0));
+    ///
+    /// // Sprinting covers more ground per action, but each unit is pricier
+    /// let sprint = Action::new("sprint").with_effect(
+    ///     Effect::new()
+    ///         .add_mutation(Mutation::decrement("distance", 10))
+    ///         .with_cost_per_unit("distance", 3),
+    /// );
+    /// let walk = Action::new("walk").with_effect(
+    ///     Effect::new()
+    ///         .add_mutation(Mutation::decrement("distance", 1))
+    ///         .with_cost_per_unit("distance", 1),
+    /// );
+    ///
+    /// // A single sprint costs 1 + 3 * 10 = 31
+    /// let sprint_effect = sprint.effect.as_ref().unwrap();
+    /// let mut after = start.clone();
+    /// sprint_effect.apply_to(&mut after);
+    /// assert_eq!(sprint_effect.transition_cost(&start, &after), 31);
+    ///
+    /// // Ten walks cost 10 * (1 + 1) = 20, so the planner prefers the smaller steps
+    /// let plan = make_plan(&start, &[sprint, walk], &goal).unwrap();
+    /// assert_eq!(plan.cost(), 20);
+    /// assert!(plan.effects().all(|(key, _, _)| key == "walk"));
+    /// ```
+    pub fn with_cost_per_unit(mut self, key: impl Into<String>, cost_per_unit: usize) -> Self {
+        self.cost_per_unit = Some((key.into(), cost_per_unit));
+        self
+    }
+
+    /// Sets the extra cost of the most recently added mutation.
+    ///
+    /// The cost is added to the flat [`Effect::cost`] whenever the effect is
+    /// applied, see [`Effect::mutation_costs`]. Unlike the flat cost, it may
+    /// be 0. Does nothing if the effect has no mutations yet.
+    ///
+    /// # Arguments
+    /// * `cost` - The extra cost of the last mutation
+    ///
+    /// # Returns
+    /// The modified `Effect` instance (for method chaining).
+    ///
+    /// # Example
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let start = WorldState::new().set("bag_full", true).set("sword", 1);
+    /// let goal = Goal::new().with("bag_full", Assert::eq(false));
+    ///
+    /// // Discarding the sword frees the bag in one cheap step...
+    /// let discard = Effect::new()
+    ///     .add_mutation(Mutation::set("bag_full", false))
+    ///     .add_mutation(Mutation::delete("sword"));
+    /// let reorganize =
+    ///     Effect::new().add_mutation(Mutation::set("bag_full", false)).with_cost(3);
+    /// let actions = [
+    ///     Action::new("discard").with_effect(discard.clone()),
+    ///     Action::new("reorganize").with_effect(reorganize.clone()),
+    /// ];
+    /// let plan = make_plan(&start, &actions, &goal).unwrap();
+    /// assert_eq!((plan.effects().next().unwrap().0, plan.cost()), ("discard", 1));
+    ///
+    /// // ...unless losing the sword is priced in
+    /// let actions = [
+    ///     Action::new("discard").with_effect(discard.with_mutation_cost(5)),
+    ///     Action::new("reorganize").with_effect(reorganize),
+    /// ];
+    /// let plan = make_plan(&start, &actions, &goal).unwrap();
+    /// assert_eq!((plan.effects().next().unwrap().0, plan.cost()), ("reorganize", 3));
+    /// ```
+    pub fn with_mutation_cost(mut self, cost: usize) -> Self {
+        if let Some(index) = self.mutations.len().checked_sub(1) {
+            self.mutation_costs.resize(self.mutations.len(), 0);
+            self.mutation_costs[index] = cost;
+        }
         self
     }
 
+    /// Returns the cost of this effect that doesn't depend on the state: the
+    /// flat [`Effect::cost`] plus the [`Effect::mutation_costs`].
+    ///
+    /// # Returns
+    /// The fixed cost, saturating at `usize::MAX`.
+    pub fn fixed_cost(&self) -> usize {
+        self.mutation_costs
+            .iter()
+            .take(self.mutations.len())
+            .fold(self.cost, |total, cost| total.saturating_add(*cost))
+    }
+
+    /// Computes the cost of a transition caused by this effect.
+    ///
+    /// This is the [`Effect::fixed_cost`], plus the scaled cost of
+    /// [`Effect::cost_per_unit`] if one is set. A key missing on either side of
+    /// the transition contributes no scaled cost.
+    ///
+    /// # Arguments
+    /// * `before` - The world state before the effect was applied
+    /// * `after` - The world state after the effect was applied
+    ///
+    /// # Returns
+    /// The cost of moving from `before` to `after` with this effect,
+    /// saturating at `usize::MAX`.
+    pub fn transition_cost(
+        &self,
+        before: &crate::world_state::WorldState,
+        after: &crate::world_state::WorldState,
+    ) -> usize {
+        let Some((key, cost_per_unit)) = &self.cost_per_unit else {
+            return self.fixed_cost();
+        };
+
+        let delta = match (before.get(key), after.get(key)) {
+            (Some(old), Some(new)) => old.distance(new) as usize,
+            _ => 0,
+        };
+        self.fixed_cost().saturating_add(cost_per_unit.saturating_mul(delta))
+    }
+
     /// Applies all mutations in this effect to a world state.
     ///
     /// This is a convenience method that applies each mutation in sequence,
@@ -193,8 +516,8 @@ impl Effect {
     ///     .set("ammo", 10);
     ///
     /// let effect = Effect::new()
-    ///     .with_mutation("health", Mutation::increment("", 25))
-    ///     .with_mutation("ammo", Mutation::decrement("", 2));
+    ///     .add_mutation(Mutation::increment("health", 25))
+    ///     .add_mutation(Mutation::decrement("ammo", 2));
     ///
     /// effect.apply_to(&mut world_state);
     /// // world_state now has health = 75 and ammo = 8
@@ -217,9 +540,9 @@ impl Effect {
     /// use rust_goap::prelude::*;
     ///
     /// let effect = Effect::new()
-    ///     .with_mutation("a", Mutation::set("", 1))
-    ///     .with_mutation("b", Mutation::set("", 2))
-    ///     .with_mutation("c", Mutation::set("", 3));
+    ///     .add_mutation(Mutation::set("a", 1))
+    ///     .add_mutation(Mutation::set("b", 2))
+    ///     .add_mutation(Mutation::set("c", 3));
     ///
     /// assert_eq!(effect.mutation_count(), 3);
     /// ```
@@ -237,7 +560,7 @@ impl Effect {
     /// use rust_goap::prelude::*;
     ///
     /// let empty_effect = Effect::new();
-    /// let populated_effect = Effect::new().with_mutation("key", Mutation::set("", 42));
+    /// let populated_effect = Effect::new().add_mutation(Mutation::set("key", 42));
     ///
     /// assert!(!empty_effect.has_mutations());
     /// assert!(populated_effect.has_mutations());
@@ -245,12 +568,302 @@ impl Effect {
     pub fn has_mutations(&self) -> bool {
         !self.mutations.is_empty()
     }
+
+    /// Computes the effect that undoes this one.
+    ///
+    /// Increments and decrements are inverted into each other (including their
+    /// key-relative variants) and applied in reverse order, so that applying the
+    /// inverse right after this effect restores the previous state. Float values
+    /// are restored up to rounding. The cost and any cost per unit are preserved.
+    ///
+    /// # Returns
+    /// * `Some(effect)` if every mutation can be inverted
+    /// * `None` if any mutation needs the prior value to be undone, such as
+    ///   set, delete, compute and conditional mutations, or a key-relative
+    ///   mutation reading the key it writes
+    ///
+    /// # Example
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let start = WorldState::new().set("gold", 100).set("wood", 4).set("price", 15);
+    /// let buy_wood = Effect::new()
+    ///     .add_mutation(Mutation::subtract_from_key("gold", "price"))
+    ///     .add_mutation(Mutation::increment("wood", 1))
+    ///     .with_cost(3);
+    ///
+    /// let sell_wood = buy_wood.inverse().unwrap();
+    /// assert_eq!(sell_wood.cost, 3);
+    ///
+    /// let mut state = start.clone();
+    /// buy_wood.apply_to(&mut state);
+    /// assert_eq!(state.get("gold"), Some(&Value::I64(85)));
+    /// sell_wood.apply_to(&mut state);
+    /// assert_eq!(state, start);
+    ///
+    /// // The previous value of a set key is unknown
+    /// let reset = Effect::new().add_mutation(Mutation::set("wood", 0));
+    /// assert!(reset.inverse().is_none());
+    /// ```
+    pub fn inverse(&self) -> Option<Effect> {
+        let mutations = self
+            .mutations
+            .iter()
+            .rev()
+            .map(|mutation| match mutation {
+                Mutation::Increment(key, value) => Some(Mutation::Decrement(key.clone(), *value)),
+                Mutation::Decrement(key, value) => Some(Mutation::Increment(key.clone(), *value)),
+                Mutation::AddFromKey(key, source) if key != source => {
+                    Some(Mutation::SubtractFromKey(key.clone(), source.clone()))
+                },
+                Mutation::SubtractFromKey(key, source) if key != source => {
+                    Some(Mutation::AddFromKey(key.clone(), source.clone()))
+                },
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        let mut mutation_costs = self.mutation_costs.clone();
+        if !mutation_costs.is_empty() {
+            mutation_costs.resize(self.mutations.len(), 0);
+            mutation_costs.reverse();
+        }
+        Some(Effect {
+            mutations,
+            cost: self.cost,
+            cost_per_unit: self.cost_per_unit.clone(),
+            mutation_costs,
+        })
+    }
+
+    /// Combines this effect with another into a single effect.
+    ///
+    /// The mutations of `other` are appended to those of this effect, so the
+    /// merged effect is the same as applying this effect, then `other`. For a
+    /// key written by both, the mutations therefore compose in order: a later
+    /// set wins over an earlier one, and increments and decrements accumulate.
+    ///
+    /// The costs are added, saturating at `usize::MAX`. Note that both effects
+    /// count at least 1, so merging two effects with the default cost gives a
+    /// cost of 2. The cost per unit of this effect is kept if it has one, and
+    /// the one of `other` is used otherwise. Per-mutation costs (see
+    /// [`Effect::mutation_costs`]) stay with their mutations.
+    ///
+    /// # Arguments
+    /// * `other` - The effect to apply after this one
+    ///
+    /// # Returns
+    /// The combined effect.
+    ///
+    /// # Example
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let gain_xp = Effect::new().add_mutation(Mutation::increment("xp", 50)).with_cost(2);
+    /// let take_damage = Effect::new().add_mutation(Mutation::decrement("health", 10));
+    ///
+    /// // Disjoint keys: both changes happen
+    /// let fight = gain_xp.clone().merge(&take_damage);
+    /// assert_eq!(fight.mutation_count(), 2);
+    /// assert_eq!(fight.cost, 3);
+    /// let mut state = WorldState::new().set("xp", 0).set("health", 100);
+    /// fight.apply_to(&mut state);
+    /// assert_eq!(state, WorldState::new().set("xp", 50).set("health", 90));
+    ///
+    /// // The same key: deltas accumulate, and the later set wins
+    /// let double_xp = gain_xp.clone().merge(&gain_xp).merge(&Effect::new().add_mutation(Mutation::decrement("xp", 5)));
+    /// let mut state = WorldState::new().set("xp", 0);
+    /// double_xp.apply_to(&mut state);
+    /// assert_eq!(state.get("xp"), Some(&Value::I64(95)));
+    ///
+    /// let reset = Effect::new().add_mutation(Mutation::set("xp", 0));
+    /// let mut state = WorldState::new().set("xp", 10);
+    /// gain_xp.clone().merge(&reset).apply_to(&mut state);
+    /// assert_eq!(state.get("xp"), Some(&Value::I64(0)));
+    /// ```
+    pub fn merge(mut self, other: &Effect) -> Effect {
+        if !self.mutation_costs.is_empty() || !other.mutation_costs.is_empty() {
+            self.mutation_costs.resize(self.mutations.len(), 0);
+            self.mutation_costs.extend(other.mutation_costs.iter().take(other.mutations.len()));
+        }
+        self.mutations.extend(other.mutations.iter().cloned());
+        self.cost = self.cost.saturating_add(other.cost);
+        if self.cost_per_unit.is_none() {
+            self.cost_per_unit = other.cost_per_unit.clone();
+        }
+        self
+    }
+
+    /// Checks that every mutation of this effect can be applied to a world state
+    /// without mixing value types or overflowing.
+    ///
+    /// Increments and decrements, including their key-relative variants and
+    /// the arithmetic of computed values, need numbers of the same type on
+    /// both sides: applying them to a `Bool` or a `Null`, or adding an `F64`
+    /// to an `I64`, leaves the value unchanged, or panics with the `strict`
+    /// feature. Increments, decrements and computed values of an `I64` must
+    /// also stay within the range of `i64`, except for saturating decrements
+    /// going below zero.
+    /// Mutations are checked in order, each against the state left by
+    /// the previous ones, and mutations that would leave the state unchanged
+    /// (such as an increment of a missing key) always pass. Set, delete,
+    /// set min, set max and set if absent mutations can't fail.
+    ///
+    /// # Arguments
+    /// * `state` - The world state the effect would be applied to
+    ///
+    /// # Returns
+    /// * `Ok(())` if the effect can be applied
+    /// * `Err(error)` naming the key of the first mutation that can't
+    ///
+    /// # Example
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let state = WorldState::new().set("door_open", false).set("keys", 2);
+    ///
+    /// let use_key = Effect::new()
+    ///     .add_mutation(Mutation::decrement("keys", 1))
+    ///     .add_mutation(Mutation::set("door_open", true));
+    /// assert_eq!(use_key.validate(&state), Ok(()));
+    ///
+    /// // Booleans can't be incremented
+    /// let broken = Effect::new().add_mutation(Mutation::increment("door_open", 1));
+    /// let error = broken.validate(&state).unwrap_err();
+    /// assert_eq!(error.key(), "door_open");
+    /// assert_eq!(error.to_string(), "cannot apply mutation to \"door_open\": expected i64, got bool");
+    ///
+    /// // Integers can't overflow
+    /// let state = state.set("keys", i64::MIN);
+    /// let error = use_key.validate(&state).unwrap_err();
+    /// assert_eq!(error, EffectError::Overflow { key: "keys".to_string() });
+    /// ```
+    pub fn validate(&self, state: &WorldState) -> Result<(), EffectError> {
+        self.try_apply(state).map(|_| ())
+    }
+
+    /// Applies this effect to a copy of `state`, checking every mutation like
+    /// [`Effect::validate`] before applying it.
+    pub(crate) fn try_apply(&self, state: &WorldState) -> Result<WorldState, EffectError> {
+        let mut state = state.clone();
+        for mutation in &self.mutations {
+            check_mutation(mutation, &state)?;
+            apply_mutator(&mut state, mutation);
+        }
+        Ok(state)
+    }
+
+    /// Returns the same effect with every key it reads or writes replaced by
+    /// `rename(key)`.
+    pub(crate) fn map_keys(self, rename: &dyn Fn(&str) -> String) -> Self {
+        Self {
+            mutations: self.mutations.into_iter().map(|m| m.map_keys(rename)).collect(),
+            cost: self.cost,
+            cost_per_unit: self.cost_per_unit.map(|(key, cost)| (rename(&key), cost)),
+            mutation_costs: self.mutation_costs,
+        }
+    }
+}
+
+/// Checks a single mutation against the state it would be applied to.
+fn check_mutation(mutation: &Mutation, state: &WorldState) -> Result<(), EffectError> {
+    match mutation {
+        Mutation::Increment(key, amount) => check_step(key, state, Some(amount), i64::checked_add),
+        Mutation::Decrement(key, amount) => check_step(key, state, Some(amount), i64::checked_sub),
+        Mutation::DecrementSaturating(key, amount) => {
+            // Going below zero saturates instead of overflowing
+            check_step(key, state, Some(amount), |current, amount| {
+                current.checked_sub(amount).or((amount > 0).then_some(0))
+            })
+        },
+        Mutation::AddFromKey(key, source) => {
+            check_step(key, state, state.get(source), i64::checked_add)
+        },
+        Mutation::SubtractFromKey(key, source) => {
+            check_step(key, state, state.get(source), i64::checked_sub)
+        },
+        Mutation::Compute { target, expr } => check_expr(expr, state, target).map(|_| ()),
+        Mutation::Conditional { on_key, condition, mutation } => {
+            if condition.check(state, on_key) {
+                check_mutation(mutation, state)
+            } else {
+                Ok(())
+            }
+        },
+        Mutation::Set(..)
+        | Mutation::Delete(_)
+        | Mutation::SetMin(..)
+        | Mutation::SetMax(..)
+        | Mutation::SetIfAbsent(..) => Ok(()),
+    }
+}
+
+/// Checks that `amount` can be added to or subtracted from the value of `key`
+/// with `op`, if both are present.
+fn check_step(
+    key: &str,
+    state: &WorldState,
+    amount: Option<&Value>,
+    op: fn(i64, i64) -> Option<i64>,
+) -> Result<(), EffectError> {
+    let (Some(current), Some(amount)) = (state.get(key), amount) else {
+        return Ok(());
+    };
+    check_arithmetic(current, amount).map_err(|(expected, actual)| EffectError::TypeMismatch {
+        key: key.to_string(),
+        expected,
+        actual,
+    })?;
+    match (current, amount) {
+        (Value::I64(current), Value::I64(amount)) if op(*current, *amount).is_none() => {
+            Err(EffectError::Overflow { key: key.to_string() })
+        },
+        _ => Ok(()),
+    }
+}
+
+/// Checks that `amount` can be added to or subtracted from `current`.
+///
+/// # Returns
+/// The expected and actual type names if it can't.
+fn check_arithmetic(current: &Value, amount: &Value) -> Result<(), (&'static str, &'static str)> {
+    if matches!(amount, Value::Bool(_) | Value::Null) {
+        Err(("a number", amount.type_name()))
+    } else if !current.same_type_as(amount) {
+        Err((amount.type_name(), current.type_name()))
+    } else {
+        Ok(())
+    }
+}
+
+/// Evaluates an expression like [`Expr::eval`], checking the types of every
+/// arithmetic operation first and rejecting integer overflows.
+fn check_expr(expr: &Expr, state: &WorldState, target: &str) -> Result<Option<Value>, EffectError> {
+    match expr {
+        Expr::Key(_) | Expr::Const(_) => Ok(expr.eval(state)),
+        Expr::Add(lhs, rhs) | Expr::Sub(lhs, rhs) | Expr::Mul(lhs, rhs) => {
+            let (Some(lhs), Some(rhs)) =
+                (check_expr(lhs, state, target)?, check_expr(rhs, state, target)?)
+            else {
+                return Ok(None);
+            };
+            // The left-hand side must be a number, and the right-hand side of its type
+            check_arithmetic(&rhs, &lhs).map_err(|(expected, actual)| {
+                EffectError::TypeMismatch { key: target.to_string(), expected, actual }
+            })?;
+            // Both operands are known, so only an overflow leaves no result
+            match expr.eval(state) {
+                Some(value) => Ok(Some(value)),
+                None => Err(EffectError::Overflow { key: target.to_string() }),
+            }
+        },
+    }
 }
 
 impl Hash for Effect {
     /// Computes a hash value for the effect.
     ///
-    /// The hash includes all mutations and the cost, ensuring that effects with
+    /// The hash includes all mutations and the costs, ensuring that effects with
     /// different contents or costs hash to different values.
     ///
     /// This enables using `Effect` instances as keys in hash-based collections,
@@ -262,9 +875,9 @@ impl Hash for Effect {
     /// use std::hash::{Hash, Hasher};
     /// use rust_goap::prelude::*;
     ///
-    /// let effect1 = Effect::new().with_mutation("health", Mutation::increment("", 10));
-    /// let effect2 = Effect::new().with_mutation("health", Mutation::increment("", 10));
-    /// let effect3 = Effect::new().with_mutation("health", Mutation::increment("", 20));
+    /// let effect1 = Effect::new().add_mutation(Mutation::increment("health", 10));
+    /// let effect2 = Effect::new().add_mutation(Mutation::increment("health", 10));
+    /// let effect3 = Effect::new().add_mutation(Mutation::increment("health", 20));
     ///
     /// let mut hasher1 = DefaultHasher::new();
     /// let mut hasher2 = DefaultHasher::new();
@@ -280,5 +893,7 @@ impl Hash for Effect {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.mutations.hash(state);
         self.cost.hash(state);
+        self.cost_per_unit.hash(state);
+        self.mutation_costs.hash(state);
     }
 }
@@ -0,0 +1,156 @@
+//! Errors reported for misconfigured planning domains and actions that
+//! can't be applied.
+
+use alloc::string::String;
+use core::fmt::Display;
+
+/// Error returned by [`try_make_plan`](crate::prelude::try_make_plan).
+///
+/// Besides goals that can't be reached, this reports domains that would make
+/// the other planning functions skip actions silently, or panic with the
+/// `strict` feature: preconditions on keys that don't exist and effects that
+/// mix value types. The error names the action at fault.
+///
+/// # Example
+/// ```
+/// use rust_goap::prelude::*;
+///
+/// let error = PlanError::MissingKey { action: "eat".to_string(), key: "has_food".to_string() };
+/// assert_eq!(error.to_string(), "precondition of action \"eat\" reads missing key \"has_food\"");
+/// assert_eq!(PlanError::NoPlan.to_string(), "no plan reaches the goal");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PlanError {
+    /// A precondition of an action compares the value of a key that is
+    /// missing from a state the search reached
+    MissingKey {
+        /// The key of the action
+        action: String,
+        /// The key missing from the state
+        key: String,
+    },
+    /// An effect of an action can't be applied to a state the search reached
+    InvalidEffect {
+        /// The key of the action
+        action: String,
+        /// Why the effect can't be applied
+        error: EffectError,
+    },
+    /// The goal can't be reached from the start state
+    NoPlan,
+}
+
+impl Display for PlanError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            PlanError::MissingKey { action, key } => {
+                write!(f, "precondition of action {action:?} reads missing key {key:?}")
+            },
+            PlanError::InvalidEffect { action, error } => {
+                write!(f, "invalid effect of action {action:?}: {error}")
+            },
+            PlanError::NoPlan => write!(f, "no plan reaches the goal"),
+        }
+    }
+}
+
+impl core::error::Error for PlanError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            PlanError::InvalidEffect { error, .. } => Some(error),
+            PlanError::MissingKey { .. } | PlanError::NoPlan => None,
+        }
+    }
+}
+
+/// Error returned by [`Action::apply`](crate::prelude::Action::apply).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ActionError {
+    /// At least one precondition of the action doesn't hold
+    PreconditionsNotMet,
+    /// The effect of the action can't be applied to the state
+    InvalidEffect(EffectError),
+}
+
+impl Display for ActionError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ActionError::PreconditionsNotMet => write!(f, "action preconditions are not met"),
+            ActionError::InvalidEffect(error) => write!(f, "invalid action effect: {error}"),
+        }
+    }
+}
+
+impl core::error::Error for ActionError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            ActionError::PreconditionsNotMet => None,
+            ActionError::InvalidEffect(error) => Some(error),
+        }
+    }
+}
+
+/// Error returned by [`Effect::validate`](crate::prelude::Effect::validate)
+/// for a mutation that can't be applied to a world state.
+///
+/// # Example
+/// ```
+/// use rust_goap::prelude::*;
+///
+/// let state = WorldState::new().set("speed", 1.5).set("gold", i64::MAX);
+/// let effect = Effect::new().add_mutation(Mutation::increment("speed", 1));
+///
+/// let error = effect.validate(&state).unwrap_err();
+/// assert_eq!(error.key(), "speed");
+/// assert_eq!(
+///     error,
+///     EffectError::TypeMismatch { key: "speed".to_string(), expected: "i64", actual: "f64" }
+/// );
+///
+/// let effect = Effect::new().add_mutation(Mutation::increment("gold", 1));
+/// let error = effect.validate(&state).unwrap_err();
+/// assert_eq!(error, EffectError::Overflow { key: "gold".to_string() });
+/// assert_eq!(error.to_string(), "cannot apply mutation to \"gold\": i64 overflow");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EffectError {
+    /// The mutation would mix value types
+    TypeMismatch {
+        /// The key written by the offending mutation
+        key: String,
+        /// The type the mutation needs (see
+        /// [`Value::type_name`](crate::prelude::Value::type_name)), or `"a number"`
+        expected: &'static str,
+        /// The type that was found instead
+        actual: &'static str,
+    },
+    /// The mutation would overflow the range of an `i64`
+    Overflow {
+        /// The key written by the offending mutation
+        key: String,
+    },
+}
+
+impl EffectError {
+    /// Returns the key written by the offending mutation.
+    pub fn key(&self) -> &str {
+        match self {
+            EffectError::TypeMismatch { key, .. } | EffectError::Overflow { key } => key,
+        }
+    }
+}
+
+impl Display for EffectError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            EffectError::TypeMismatch { key, expected, actual } => {
+                write!(f, "cannot apply mutation to {key:?}: expected {expected}, got {actual}")
+            },
+            EffectError::Overflow { key } => {
+                write!(f, "cannot apply mutation to {key:?}: i64 overflow")
+            },
+        }
+    }
+}
+
+impl core::error::Error for EffectError {}
@@ -1,5 +1,6 @@
-use std::collections::BTreeMap;
-use std::hash::{Hash, Hasher};
+use alloc::collections::BTreeMap;
+use alloc::{string::String, vec::Vec};
+use core::hash::{Hash, Hasher};
 
 use crate::basic::assert::Assert;
 
@@ -39,7 +40,7 @@ use crate::basic::assert::Assert;
 /// ];
 /// let from_list_goal = Goal::from_reqs(&requirements);
 /// ```
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Goal {
     /// The requirements that define this goal.
     ///
@@ -68,6 +69,25 @@ pub struct Goal {
     /// assert!(matches!(health_req.unwrap(), Assert::GreaterThanEquals(_)));
     /// ```
     pub requirements: BTreeMap<String, Assert>,
+
+    /// The priority of this goal when choosing between several goals.
+    ///
+    /// Higher values are more important. Priority is only consulted by
+    /// multi-goal planning (see [`make_plan_for_goals`](crate::prelude::make_plan_for_goals)),
+    /// where a reachable goal with a higher priority always wins over one with a
+    /// lower priority, regardless of plan cost. The default priority is 0.
+    ///
+    /// # Example
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let idle = Goal::new().with("is_resting", Assert::eq(true));
+    /// let flee = Goal::new().with("in_danger", Assert::eq(false)).with_priority(10);
+    ///
+    /// assert_eq!(idle.priority, 0);
+    /// assert_eq!(flee.priority, 10);
+    /// ```
+    pub priority: u32,
 }
 
 impl Hash for Goal {
@@ -133,6 +153,36 @@ impl Default for Goal {
     }
 }
 
+impl core::fmt::Display for Goal {
+    /// Formats the goal as its requirements, each a key followed by its
+    /// assertion, in key order.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let goal = Goal::new().with("health", Assert::gt_eq(80)).with("has_key", Assert::eq(true));
+    /// assert_eq!(
+    ///     format!("{goal}"),
+    ///     "Goal { has_key == Value:Bool(true), health >= Value:I64(80) }"
+    /// );
+    /// assert_eq!(format!("{}", Goal::new()), "Goal {}");
+    /// ```
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.requirements.is_empty() {
+            return write!(f, "Goal {{}}");
+        }
+        write!(f, "Goal {{ ")?;
+        for (index, (key, assert)) in self.requirements.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{key} {assert}")?;
+        }
+        write!(f, " }}")
+    }
+}
+
 impl Goal {
     /// Creates a new empty goal.
     ///
@@ -155,7 +205,7 @@ impl Goal {
     ///     .with("stamina", Assert::gt_eq(20));
     /// ```
     pub fn new() -> Self {
-        Self { requirements: BTreeMap::new() }
+        Self { requirements: BTreeMap::new(), priority: 0 }
     }
 
     /// Adds a requirement to the goal using the builder pattern.
@@ -191,6 +241,141 @@ impl Goal {
         self
     }
 
+    /// Sets the priority of the goal using the builder pattern.
+    ///
+    /// Priority is used to rank goals when planning for several goals at once;
+    /// see [`make_plan_for_goals`](crate::prelude::make_plan_for_goals) for the ranking rule.
+    ///
+    /// # Arguments
+    /// * `priority` - The priority of the goal (higher is more important)
+    ///
+    /// # Returns
+    /// The modified `Goal` instance (for method chaining).
+    ///
+    /// # Example
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let goal = Goal::new()
+    ///     .with("enemy_count", Assert::eq(0))
+    ///     .with_priority(5);
+    ///
+    /// assert_eq!(goal.priority, 5);
+    /// ```
+    pub fn with_priority(mut self, priority: u32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Merges the requirements of another goal into this one.
+    ///
+    /// This is useful for composing a goal from several modular "desires".
+    /// Requirements on keys that only one of the goals constrains are copied
+    /// as-is. When both goals constrain the same key, the stricter assertion is
+    /// kept, i.e. the one whose satisfaction implies the other (for example
+    /// `gt_eq(80)` over `gt_eq(50)`, or `eq(5)` over `lt(10)`).
+    ///
+    /// If neither assertion implies the other, both are kept in an
+    /// [`Assert::All`] (such as `gt(0)` with `lt(10)`), unless they are
+    /// contradictory (such as `eq(true)` with `eq(false)`, or `gt_eq(80)` with
+    /// `lt(50)`), in which case the merge fails. See [`Goal::is_contradictory`].
+    ///
+    /// The merged goal has the higher priority of the two goals.
+    ///
+    /// # Arguments
+    /// * `other` - The goal whose requirements are merged into this one
+    ///
+    /// # Returns
+    /// The merged goal, or `None` if both goals constrain the same key with
+    /// assertions that contradict each other.
+    ///
+    /// # Example
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let fed = Goal::new().with("is_hungry", Assert::eq(false));
+    /// let healthy = Goal::new().with("health", Assert::gt_eq(50)).with_priority(2);
+    /// let very_healthy = Goal::new().with("health", Assert::gt_eq(80));
+    ///
+    /// // Disjoint keys are combined
+    /// let merged = fed.clone().merge(&healthy).unwrap();
+    /// assert_eq!(merged.requirement_count(), 2);
+    /// assert_eq!(merged.priority, 2);
+    ///
+    /// // The stricter assertion wins on a shared key
+    /// let merged = healthy.clone().merge(&very_healthy).unwrap();
+    /// assert_eq!(merged.requirements["health"], Assert::gt_eq(80));
+    ///
+    /// // Requirements that don't imply each other are combined
+    /// let not_full = Goal::new().with("health", Assert::lt(100));
+    /// let merged = healthy.clone().merge(&not_full).unwrap();
+    /// assert_eq!(merged.requirements["health"], Assert::all([Assert::gt_eq(50), Assert::lt(100)]));
+    ///
+    /// // Contradictory requirements cannot be merged
+    /// let starving = Goal::new().with("is_hungry", Assert::eq(true));
+    /// assert!(fed.merge(&starving).is_none());
+    /// ```
+    pub fn merge(mut self, other: &Goal) -> Option<Goal> {
+        for (key, assertion) in &other.requirements {
+            match self.requirements.get(key) {
+                Some(existing) if existing.implies(assertion) => {},
+                Some(existing) if !assertion.implies(existing) => {
+                    let combined = Assert::all([existing.clone(), assertion.clone()]);
+                    if combined.is_unsatisfiable() {
+                        return None;
+                    }
+                    self.requirements.insert(key.clone(), combined);
+                },
+                _ => {
+                    self.requirements.insert(key.clone(), assertion.clone());
+                },
+            }
+        }
+        self.priority = self.priority.max(other.priority);
+        Some(self)
+    }
+
+    /// Checks whether some requirement of this goal can clearly never be satisfied.
+    ///
+    /// Since each key holds a single assertion, contradictions come from
+    /// [`Assert::All`] requirements whose assertions exclude each other, such
+    /// as `gt_eq(80)` with `lt(50)`, `eq(true)` with `eq(false)`, or `absent()`
+    /// with any assertion on the value. Checking for them before planning
+    /// avoids a search that explores every reachable state in vain.
+    ///
+    /// The check is conservative: a goal that isn't reported as contradictory
+    /// may still be unsatisfiable, for example `gt(1)` with `lt(2)` on a key
+    /// that only ever holds integers.
+    ///
+    /// # Returns
+    /// `true` if some requirement is contradictory, `false` otherwise.
+    ///
+    /// # Example
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let contradictory =
+    ///     Goal::new().with("health", Assert::all([Assert::gt_eq(80), Assert::lt(50)]));
+    /// assert!(contradictory.is_contradictory());
+    ///
+    /// // Merging keeps both bounds when they are compatible
+    /// let healthy = Goal::new().with("health", Assert::gt_eq(50));
+    /// let wounded = Goal::new().with("health", Assert::lt(80));
+    /// let merged = healthy.merge(&wounded).unwrap();
+    /// assert!(!merged.is_contradictory());
+    /// assert!(merged.is_satisfied_by(&WorldState::new().set("health", 60)));
+    ///
+    /// // Merging contradictory requirements fails instead
+    /// let critical = Goal::new().with("health", Assert::lt(20));
+    /// assert!(merged.merge(&critical.clone()).is_none());
+    /// assert!(Goal::new()
+    ///     .with("health", Assert::all([Assert::gt_eq(50), Assert::lt(80), Assert::lt(20)]))
+    ///     .is_contradictory());
+    /// ```
+    pub fn is_contradictory(&self) -> bool {
+        self.requirements.values().any(Assert::is_unsatisfiable)
+    }
+
     /// Creates a goal from a slice of requirements.
     ///
     /// This is a convenience constructor for creating goals from existing
@@ -230,10 +415,94 @@ impl Goal {
         goal
     }
 
+    /// Creates a goal that targets an exact world state.
+    ///
+    /// One [`Assert::Equals`] requirement is created per key in `state`. This is
+    /// handy for navigation-style planning, where the destination is a concrete
+    /// snapshot rather than a set of conditions. Keys that are not present in
+    /// `state` are left unconstrained.
+    ///
+    /// # Arguments
+    /// * `state` - The world state to target
+    ///
+    /// # Returns
+    /// A new `Goal` requiring every key of `state` to equal its value in `state`.
+    ///
+    /// # Example
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let destination = WorldState::new().set("room", 3).set("door_open", true);
+    /// let goal = Goal::from_world_state(&destination);
+    ///
+    /// assert_eq!(goal.requirement_count(), 2);
+    /// assert!(goal.is_satisfied_by(&destination));
+    /// assert!(!goal.is_satisfied_by(&destination.clone().set("room", 4)));
+    /// ```
+    pub fn from_world_state(state: &crate::world_state::WorldState) -> Self {
+        let mut goal = Goal::new();
+        for (key, value) in state.iter() {
+            goal = goal.with(key, Assert::Equals(*value));
+        }
+        goal
+    }
+
+    /// Creates a goal that targets a world state up to a tolerance.
+    ///
+    /// Like [`Goal::from_world_state`], with one requirement per key in
+    /// `state`, except that numeric keys only need to be within `tolerance`
+    /// of their value in `state` (see [`Assert::approx_eq`]). Booleans and
+    /// null values must still be equal. This suits navigation goals, where
+    /// exact float positions may never be reached.
+    ///
+    /// # Arguments
+    /// * `state` - The world state to target
+    /// * `tolerance` - The largest accepted difference for numeric keys
+    ///
+    /// # Returns
+    /// A new `Goal` requiring every key of `state` to be close to its value in `state`.
+    ///
+    /// # Example
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let destination = WorldState::new().set("x", 4.0).set("y", -2.5).set("floor", 1).set("door_open", true);
+    /// let goal = Goal::approaching(&destination, 0.25);
+    /// assert_eq!(goal.requirement_count(), 4);
+    ///
+    /// // Within the tolerance on every numeric key
+    /// let close = destination.clone().set("x", 4.2).set("y", -2.75).set("floor", 1);
+    /// assert!(goal.is_satisfied_by(&close));
+    ///
+    /// // Just outside the tolerance, or a different boolean
+    /// assert!(!goal.is_satisfied_by(&close.clone().set("x", 4.26)));
+    /// assert!(!goal.is_satisfied_by(&close.clone().set("floor", 2)));
+    /// assert!(!goal.is_satisfied_by(&close.clone().set("door_open", false)));
+    ///
+    /// // Planning stops as soon as the state is close enough
+    /// let start = WorldState::new().set("x", 0.0).set("y", -2.5).set("floor", 1).set("door_open", true);
+    /// let step = Action::new("step")
+    ///     .with_effect(Effect::new().add_mutation(Mutation::increment("x", 0.3)));
+    /// let plan = make_plan(&start, &[step], &goal).unwrap();
+    /// assert_eq!(plan.len(), 13);
+    /// ```
+    pub fn approaching(state: &crate::world_state::WorldState, tolerance: f64) -> Self {
+        let mut goal = Goal::new();
+        for (key, value) in state.iter() {
+            goal = goal.with(key, Assert::approx_eq(*value, tolerance));
+        }
+        goal
+    }
+
     /// Checks if a world state satisfies all requirements of this goal.
     ///
     /// This method evaluates whether the given world state meets all the
-    /// conditions specified in the goal's requirements.
+    /// conditions specified in the goal's requirements, each checked with
+    /// [`Assert::check`]: a missing key only satisfies [`Assert::Absent`] (or
+    /// an [`Assert::All`] made of absent assertions). It is the check the
+    /// planner uses to recognize goal states, so a state satisfies a goal
+    /// exactly when planning from it yields a plan without actions. A goal
+    /// without requirements is satisfied by every state.
     ///
     /// # Arguments
     /// * `world_state` - The world state to check against
@@ -260,18 +529,133 @@ impl Goal {
     ///     .set("health", 30)    // Too low!
     ///     .set("has_key", true);
     ///
-    /// // Note: The is_satisfied_by method doesn't exist in the current implementation,
-    /// // but this example shows the conceptual usage. In practice, you would use
-    /// // the planner to check if a state satisfies a goal.
+    /// assert!(goal.is_satisfied_by(&good_state));
+    /// assert!(!goal.is_satisfied_by(&bad_state));
+    ///
+    /// // Presence and absence requirements
+    /// let goal = Goal::new()
+    ///     .with("target", Assert::exists())
+    ///     .with("alarm", Assert::absent())
+    ///     .with("ammo", Assert::All(vec![Assert::gt(0), Assert::lt_eq(30)]));
+    /// let state = WorldState::new().set("target", 3).set("ammo", 12);
+    /// assert!(goal.is_satisfied_by(&state));
+    /// assert!(!goal.is_satisfied_by(&state.clone().set("alarm", true)));
+    /// assert!(!goal.is_satisfied_by(&state.clone().set("ammo", 31)));
+    /// assert!(!goal.is_satisfied_by(&WorldState::new().set("ammo", 12)));
+    /// ```
+    ///
+    /// The planner agrees, whatever the assertions:
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let goals = [
+    ///     Goal::new(),
+    ///     Goal::new().with("door", Assert::eq(true)),
+    ///     Goal::new().with("door", Assert::not_eq(true)),
+    ///     Goal::new().with("door", Assert::exists()),
+    ///     Goal::new().with("door", Assert::absent()),
+    ///     Goal::new().with("keys", Assert::All(vec![Assert::gt_eq(1), Assert::lt(3)])),
+    ///     Goal::new().with("keys", Assert::All(vec![Assert::absent()])),
+    ///     Goal::new().with("keys", Assert::gt(0.5)).with("door", Assert::eq(Value::Null)),
+    /// ];
+    /// let states = [
+    ///     WorldState::new(),
+    ///     WorldState::new().set("door", true),
+    ///     WorldState::new().set("door", false).set("keys", 2),
+    ///     WorldState::new().unset("door").set("keys", 1),
+    ///     WorldState::new().set("keys", 3.0),
+    /// ];
+    ///
+    /// // Searching from a state stops right away if it is a goal state
+    /// let config = PlanningConfig::new().with_max_expansions(1);
+    /// for goal in &goals {
+    ///     for state in &states {
+    ///         let plan = make_plan_with_config(&config, state, &[], goal);
+    ///         let is_goal = plan.is_some_and(|plan| plan.is_empty());
+    ///         assert_eq!(goal.is_satisfied_by(state), is_goal, "{goal:?} on {state:?}");
+    ///     }
+    /// }
     /// ```
     pub fn is_satisfied_by(&self, world_state: &crate::world_state::WorldState) -> bool {
-        self.requirements.iter().all(|(key, assertion)| {
-            world_state
-                .0
-                .get(key)
-                .map(|value| crate::basic::assert::compare_values(assertion, value))
-                .unwrap_or(false) // If key doesn't exist, requirement is not satisfied
-        })
+        self.requirements.iter().all(|(key, assertion)| assertion.check(world_state, key))
+    }
+
+    /// Returns the fraction of this goal's requirements that a world state satisfies.
+    ///
+    /// Unlike [`WorldState::distance_to_goal`](crate::prelude::WorldState::distance_to_goal),
+    /// which is an unbounded heuristic, the ratio is normalized to `0.0..=1.0`,
+    /// which makes it suitable for reporting partial progress (e.g. in a
+    /// behavior-tree fallback). Each requirement counts equally, regardless of
+    /// how far its value is from being satisfied.
+    ///
+    /// # Arguments
+    /// * `world_state` - The world state to check against
+    ///
+    /// # Returns
+    /// The number of satisfied requirements divided by the total number of
+    /// requirements. A goal without requirements returns `1.0`.
+    ///
+    /// # Example
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let goal = Goal::new()
+    ///     .with("health", Assert::gt_eq(50))
+    ///     .with("has_key", Assert::eq(true));
+    ///
+    /// let satisfied = WorldState::new().set("health", 75).set("has_key", true);
+    /// let half = WorldState::new().set("health", 75).set("has_key", false);
+    /// let unsatisfied = WorldState::new().set("health", 30).set("has_key", false);
+    ///
+    /// assert_eq!(goal.satisfaction_ratio(&satisfied), 1.0);
+    /// assert_eq!(goal.satisfaction_ratio(&half), 0.5);
+    /// assert_eq!(goal.satisfaction_ratio(&unsatisfied), 0.0);
+    ///
+    /// // An empty goal is always fully satisfied
+    /// assert_eq!(Goal::new().satisfaction_ratio(&unsatisfied), 1.0);
+    /// ```
+    pub fn satisfaction_ratio(&self, world_state: &crate::world_state::WorldState) -> f64 {
+        if self.requirements.is_empty() {
+            return 1.0;
+        }
+        let satisfied = self
+            .requirements
+            .iter()
+            .filter(|(key, assertion)| assertion.check(world_state, key))
+            .count();
+        satisfied as f64 / self.requirements.len() as f64
+    }
+
+    /// Returns the world states that satisfy all requirements of this goal.
+    ///
+    /// This is a convenience for batch evaluation, such as scoring a population
+    /// of simulated states. Each state is checked with [`Goal::is_satisfied_by`],
+    /// and the satisfying states are returned in their original order.
+    ///
+    /// # Arguments
+    /// * `states` - The world states to check
+    ///
+    /// # Returns
+    /// References to every state in `states` that satisfies the goal.
+    ///
+    /// # Example
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let goal = Goal::new().with("health", Assert::gt_eq(50));
+    ///
+    /// let states: Vec<WorldState> =
+    ///     [10, 50, 75, 30, 100].iter().map(|&hp| WorldState::new().set("health", hp)).collect();
+    ///
+    /// let satisfying = goal.filter_satisfying(&states);
+    /// assert_eq!(satisfying.len(), 3);
+    /// assert_eq!(satisfying[0].get("health"), Some(&Value::I64(50)));
+    /// ```
+    pub fn filter_satisfying<'a>(
+        &self,
+        states: &'a [crate::world_state::WorldState],
+    ) -> Vec<&'a crate::world_state::WorldState> {
+        states.iter().filter(|state| self.is_satisfied_by(state)).collect()
     }
 
     /// Returns the number of requirements in this goal.
@@ -312,4 +696,46 @@ impl Goal {
     pub fn has_requirements(&self) -> bool {
         !self.requirements.is_empty()
     }
+
+    /// Iterates over the keys this goal has requirements for, in sorted order.
+    ///
+    /// # Example
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let goal = Goal::new().with("health", Assert::gt_eq(50)).with("ammo", Assert::gt(0));
+    ///
+    /// let keys: Vec<&str> = goal.keys().collect();
+    /// assert_eq!(keys, ["ammo", "health"]);
+    /// assert_eq!(Goal::new().keys().count(), 0);
+    /// ```
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.requirements.keys().map(String::as_str)
+    }
+
+    /// Returns the requirement for a key.
+    ///
+    /// # Arguments
+    /// * `key` - The key to look up
+    ///
+    /// # Returns
+    /// `Some(&Assert)` if the goal has a requirement for `key`, `None` otherwise.
+    ///
+    /// # Example
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let goal = Goal::new().with("health", Assert::gt_eq(50));
+    ///
+    /// assert_eq!(goal.get("health"), Some(&Assert::gt_eq(50)));
+    /// assert_eq!(goal.get("ammo"), None);
+    ///
+    /// // Describe every requirement, e.g. for a planner UI
+    /// let described: Vec<String> =
+    ///     goal.keys().map(|key| format!("{key} {}", goal.get(key).unwrap())).collect();
+    /// assert_eq!(described, ["health >= Value:I64(50)"]);
+    /// ```
+    pub fn get(&self, key: &str) -> Option<&Assert> {
+        self.requirements.get(key)
+    }
 }
@@ -1,8 +1,17 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
 mod action;
 mod basic;
 mod effect;
+mod error;
 mod goal;
 mod plan;
 mod world_state;
 
 pub mod prelude;
+
+// Used by the `mutations!` macro, which may expand in crates without `alloc`
+#[doc(hidden)]
+pub use alloc::vec as __vec;
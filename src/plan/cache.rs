@@ -0,0 +1,158 @@
+//! Plan caching for GOAP (Goal-Oriented Action Planning) system.
+//!
+//! Many agents often share the same start state, goal, and action list. This
+//! module provides a memoizing wrapper around [`make_plan`] so that the A*
+//! search only runs once per distinct planning problem.
+
+use core::hash::{Hash, Hasher};
+use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+
+use crate::plan::Plan;
+use crate::plan::planner::make_plan;
+use crate::{action::Action, goal::Goal, world_state::WorldState};
+
+/// Memoizes planning results keyed by the start state, goal, and action list.
+///
+/// Entries are looked up by the combined hash of the [`WorldState`], the
+/// [`Goal`], and every [`Action`] in the list, so two calls with equal inputs
+/// share a single search. Each entry keeps a copy of its planning problem,
+/// which is compared with the inputs on a lookup, so problems whose hashes
+/// collide never share a plan. Failed searches (`None`) are cached as well,
+/// which avoids repeatedly exploring the state space for an unreachable goal.
+///
+/// The cache never evicts entries on its own; call [`PlanCache::clear`] when the
+/// action library changes or memory needs to be reclaimed.
+///
+/// Only available with the `std` feature (enabled by default).
+///
+/// # Example
+/// ```
+/// use rust_goap::prelude::*;
+///
+/// let start = WorldState::new().set("has_food", false).set("is_hungry", true);
+/// let goal = Goal::new().with("is_hungry", Assert::eq(false));
+/// let actions = vec![
+///     Action::new("buy_food")
+///         .with_effect(Effect::new().add_mutation(Mutation::set("has_food", true))),
+///     Action::new("eat")
+///         .with_precondition(("has_food", Assert::eq(true)))
+///         .with_effect(Effect::new().add_mutation(Mutation::set("is_hungry", false))),
+/// ];
+///
+/// let mut cache = PlanCache::new();
+/// let first = cache.plan(&start, &actions, &goal);
+/// let second = cache.plan(&start, &actions, &goal);
+///
+/// // The second call is served from the cache without searching again
+/// assert_eq!(first, second);
+/// assert_eq!(cache.misses(), 1);
+/// assert_eq!(cache.hits(), 1);
+///
+/// // A different problem is planned separately
+/// let full = WorldState::new().set("has_food", true).set("is_hungry", true);
+/// let third = cache.plan(&full, &actions, &goal).unwrap();
+/// assert_eq!(third.len(), 1);
+/// assert_eq!((cache.misses(), cache.len()), (2, 2));
+/// ```
+#[derive(Debug, Default)]
+pub struct PlanCache {
+    plans: HashMap<u64, Vec<Entry>>,
+    hits: usize,
+    misses: usize,
+}
+
+/// A cached planning result, along with the problem it solves.
+#[derive(Debug)]
+struct Entry {
+    start: WorldState,
+    goal: Goal,
+    actions: Vec<Action>,
+    plan: Option<Plan>,
+}
+
+impl Entry {
+    /// Checks whether this entry was planned for the given problem.
+    fn solves(&self, start: &WorldState, actions: &[Action], goal: &Goal) -> bool {
+        self.start == *start && self.goal == *goal && self.actions == actions
+    }
+}
+
+impl PlanCache {
+    /// Creates a new empty plan cache.
+    ///
+    /// # Example
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let cache = PlanCache::new();
+    /// assert!(cache.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a plan from start state to goal, running the planner only on a cache miss.
+    ///
+    /// # Arguments
+    /// * `start` - Initial world state
+    /// * `actions` - Available actions that can be performed
+    /// * `goal` - Desired goal state with requirements
+    ///
+    /// # Returns
+    /// The same result [`make_plan`] would return for these inputs.
+    pub fn plan(&mut self, start: &WorldState, actions: &[Action], goal: &Goal) -> Option<Plan> {
+        let entries = self.plans.entry(Self::key(start, actions, goal)).or_default();
+
+        if let Some(entry) = entries.iter().find(|entry| entry.solves(start, actions, goal)) {
+            self.hits += 1;
+            return entry.plan.clone();
+        }
+
+        self.misses += 1;
+        let plan = make_plan(start, actions, goal);
+        entries.push(Entry {
+            start: start.clone(),
+            goal: goal.clone(),
+            actions: actions.to_vec(),
+            plan: plan.clone(),
+        });
+        plan
+    }
+
+    /// Returns the number of calls to [`PlanCache::plan`] served from the cache.
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    /// Returns the number of calls to [`PlanCache::plan`] that had to run the planner.
+    pub fn misses(&self) -> usize {
+        self.misses
+    }
+
+    /// Returns the number of cached planning results.
+    pub fn len(&self) -> usize {
+        self.plans.values().map(Vec::len).sum()
+    }
+
+    /// Checks if the cache holds no planning results.
+    pub fn is_empty(&self) -> bool {
+        self.plans.is_empty()
+    }
+
+    /// Removes all cached planning results and resets the hit/miss counters.
+    pub fn clear(&mut self) {
+        self.plans.clear();
+        self.hits = 0;
+        self.misses = 0;
+    }
+
+    /// Computes the combined hash of a planning problem.
+    fn key(start: &WorldState, actions: &[Action], goal: &Goal) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        start.hash(&mut hasher);
+        goal.hash(&mut hasher);
+        actions.hash(&mut hasher);
+        hasher.finish()
+    }
+}
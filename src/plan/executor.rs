@@ -0,0 +1,165 @@
+//! Step-by-step plan execution for GOAP (Goal-Oriented Action Planning) system.
+//!
+//! A plan is made against a snapshot of the world, which may change while the
+//! plan is carried out. This module executes a plan one action at a time
+//! against the live state, checking each action's preconditions right before
+//! performing it.
+
+use crate::action::Action;
+use crate::plan::Plan;
+use crate::plan::node::Node;
+use crate::plan::planner::replayed_effect;
+use crate::world_state::WorldState;
+
+/// The outcome of [`PlanExecutor::step`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum StepResult {
+    /// Every action of the plan was performed; the state is left unchanged.
+    Done,
+    /// The next action was performed and its effect applied to the state.
+    Advanced,
+    /// The preconditions of the next action don't hold in the state, its
+    /// effect can't be applied to the state (see
+    /// [`Effect::validate`](crate::prelude::Effect::validate)), or the action
+    /// is missing from the action list. The state is left unchanged and the
+    /// action stays next, so it can be retried or the plan replaced.
+    PreconditionFailed,
+}
+
+/// Executes a plan one action at a time against a live world state.
+///
+/// Unlike [`validate_plan`](crate::prelude::validate_plan), which simulates
+/// the whole plan up front, each call to [`PlanExecutor::step`] checks the
+/// preconditions of the next action against the state as it is *now*, so
+/// changes made to the state between steps are taken into account.
+///
+/// Plan nodes only record the effect of each action, so the executor looks the
+/// actions up by key in the action list the plan was made with. The outcome
+/// recorded in the plan is applied if the action still has it, and the
+/// action's effect otherwise. Preconditions on keys missing from the state
+/// don't hold (unless they are [`Assert::Absent`](crate::prelude::Assert::Absent))
+/// instead of panicking.
+///
+/// # Example
+/// ```
+/// use rust_goap::prelude::*;
+///
+/// let start = WorldState::new().set("gold", 10).set("has_sword", false).set("enemy_alive", true);
+/// let goal = Goal::new().with("enemy_alive", Assert::eq(false));
+///
+/// let actions = [
+///     Action::new("buy_sword")
+///         .with_precondition(("gold", Assert::gt_eq(10)))
+///         .with_effect(
+///             Effect::new()
+///                 .add_mutation(Mutation::decrement("gold", 10))
+///                 .add_mutation(Mutation::set("has_sword", true)),
+///         ),
+///     Action::new("attack")
+///         .with_precondition(("has_sword", Assert::eq(true)))
+///         .with_effect(Effect::new().add_mutation(Mutation::set("enemy_alive", false))),
+/// ];
+/// let plan = make_plan(&start, &actions, &goal).unwrap();
+///
+/// // A clean run-through
+/// let mut state = start.clone();
+/// let mut executor = PlanExecutor::new(plan.clone(), &actions);
+/// assert_eq!(executor.next_action(), Some("buy_sword"));
+/// assert_eq!(executor.step(&mut state), StepResult::Advanced);
+/// assert_eq!(executor.step(&mut state), StepResult::Advanced);
+/// assert_eq!(executor.step(&mut state), StepResult::Done);
+/// assert!(executor.is_finished());
+/// assert!(goal.is_satisfied_by(&state));
+///
+/// // The sword is stolen after buying it: attacking is no longer possible
+/// let mut state = start.clone();
+/// let mut executor = PlanExecutor::new(plan, &actions);
+/// assert_eq!(executor.step(&mut state), StepResult::Advanced);
+/// state = state.set("has_sword", false);
+/// assert_eq!(executor.step(&mut state), StepResult::PreconditionFailed);
+/// assert_eq!(executor.next_action(), Some("attack"));
+/// assert_eq!(state.get("enemy_alive"), Some(&Value::Bool(true)));
+///
+/// // Recovering the sword lets the plan continue where it stopped
+/// state = state.set("has_sword", true);
+/// assert_eq!(executor.step(&mut state), StepResult::Advanced);
+/// assert_eq!(executor.step(&mut state), StepResult::Done);
+/// ```
+#[derive(Clone, Debug)]
+pub struct PlanExecutor<'a> {
+    plan: Plan,
+    actions: &'a [Action],
+    cursor: usize,
+}
+
+impl<'a> PlanExecutor<'a> {
+    /// Creates an executor positioned at the first action of a plan.
+    ///
+    /// # Arguments
+    /// * `plan` - The plan to execute
+    /// * `actions` - The actions the plan was made with
+    pub fn new(plan: Plan, actions: &'a [Action]) -> Self {
+        let mut executor = Self { plan, actions, cursor: 0 };
+        executor.skip_states();
+        executor
+    }
+
+    /// Returns the plan being executed.
+    pub fn plan(&self) -> &Plan {
+        &self.plan
+    }
+
+    /// Returns the key of the next action to perform, or `None` once the
+    /// whole plan was executed.
+    pub fn next_action(&self) -> Option<&str> {
+        self.plan.steps().get(self.cursor).and_then(Node::action_key)
+    }
+
+    /// Checks whether every action of the plan was performed.
+    pub fn is_finished(&self) -> bool {
+        self.cursor >= self.plan.steps().len()
+    }
+
+    /// Performs the next action of the plan on a world state.
+    ///
+    /// The preconditions of the action are checked against `state` first;
+    /// only if they hold is the effect applied and the executor advanced.
+    ///
+    /// # Arguments
+    /// * `state` - The current world state, updated in place
+    ///
+    /// # Returns
+    /// * [`StepResult::Advanced`] if the action was performed
+    /// * [`StepResult::PreconditionFailed`] if it couldn't be performed
+    /// * [`StepResult::Done`] if there are no actions left
+    pub fn step(&mut self, state: &mut WorldState) -> StepResult {
+        let Some(Node::Effect((action_key, step_effect, _))) = self.plan.steps().get(self.cursor)
+        else {
+            return StepResult::Done;
+        };
+        let Some(action) = self.actions.iter().find(|action| &action.key == action_key) else {
+            return StepResult::PreconditionFailed;
+        };
+        let Some(effect) = replayed_effect(action, step_effect) else {
+            return StepResult::PreconditionFailed;
+        };
+        if !action.preconditions.iter().all(|(key, assert)| assert.check(state, key)) {
+            return StepResult::PreconditionFailed;
+        }
+        let Ok(new_state) = effect.try_apply(state) else {
+            return StepResult::PreconditionFailed;
+        };
+
+        *state = new_state;
+        self.cursor += 1;
+        self.skip_states();
+        StepResult::Advanced
+    }
+
+    /// Moves the cursor past state nodes, which have no action to perform.
+    fn skip_states(&mut self) {
+        while let Some(Node::State(_)) = self.plan.steps().get(self.cursor) {
+            self.cursor += 1;
+        }
+    }
+}
@@ -1,2 +1,147 @@
+#[cfg(feature = "std")]
+pub(crate) mod cache;
+pub(crate) mod executor;
 pub(crate) mod node;
 pub(crate) mod planner;
+pub(crate) mod regression;
+pub(crate) mod search;
+
+use alloc::vec::Vec;
+
+use crate::effect::Effect;
+use crate::plan::node::Node;
+use crate::world_state::WorldState;
+
+/// A plan found by [`make_plan`](crate::prelude::make_plan): the sequence of
+/// nodes from the start state to a state satisfying the goal, and its total
+/// cost.
+///
+/// The first node is always the initial state, followed by one node per
+/// action to perform.
+///
+/// Every planning function returns its plans as a `Plan`.
+///
+/// # Compatibility
+/// Code written against the older `(Vec<Node>, usize)` tuples can convert
+/// between both forms with `From`, and [`Plan::into_parts`] gives back the
+/// tuple.
+///
+/// # Example
+/// ```
+/// use rust_goap::prelude::*;
+///
+/// let start = WorldState::new().set("wood", 0);
+/// let goal = Goal::new().with("wood", Assert::gt_eq(2));
+/// let chop = Action::new("chop")
+///     .with_effect(Effect::new().add_mutation(Mutation::increment("wood", 1)).with_cost(3));
+///
+/// let plan = make_plan(&start, &[chop], &goal).unwrap();
+/// assert_eq!(plan.cost(), 6);
+/// assert_eq!(plan.len(), 2);
+/// assert!(!plan.is_empty());
+/// assert!(plan.steps()[0].is_initial());
+///
+/// let keys: Vec<&str> = plan.effects().map(|(key, _, _)| key).collect();
+/// assert_eq!(keys, ["chop", "chop"]);
+///
+/// // Convert to the tuple form expected by older APIs
+/// let (nodes, cost) = plan.into_parts();
+/// assert_eq!((nodes.len(), cost), (3, 6));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Plan {
+    nodes: Vec<Node>,
+    cost: usize,
+}
+
+impl Plan {
+    /// Creates a plan from its nodes and total cost.
+    ///
+    /// # Arguments
+    /// * `nodes` - The initial state node followed by one node per action
+    /// * `cost` - The total cost of the plan
+    pub fn new(nodes: Vec<Node>, cost: usize) -> Self {
+        Self { nodes, cost }
+    }
+
+    /// Returns every node of the plan, starting with the initial state.
+    pub fn steps(&self) -> &[Node] {
+        &self.nodes
+    }
+
+    /// Returns the total cost of the plan.
+    pub fn cost(&self) -> usize {
+        self.cost
+    }
+
+    /// Returns the number of actions in the plan, not counting the initial
+    /// state.
+    pub fn len(&self) -> usize {
+        self.nodes.iter().filter(|node| !node.is_initial()).count()
+    }
+
+    /// Checks whether the plan has no actions, which is the case when the goal
+    /// already holds in the start state.
+    ///
+    /// # Example
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let start = WorldState::new().set("door_open", true);
+    /// let goal = Goal::new().with("door_open", Assert::eq(true));
+    ///
+    /// let plan = make_plan(&start, &[], &goal).unwrap();
+    /// assert!(plan.is_empty());
+    /// assert_eq!(plan.len(), 0);
+    /// assert_eq!(plan.steps().len(), 1);
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Iterates over the actions of the plan.
+    ///
+    /// Like [`get_effects_from_plan`](crate::prelude::get_effects_from_plan),
+    /// without taking ownership of the plan.
+    ///
+    /// # Returns
+    /// Iterator over tuples of (action_key, effect, resulting_state)
+    pub fn effects(&self) -> impl Iterator<Item = (&str, &Effect, &WorldState)> {
+        crate::plan::planner::effects_ref(&self.nodes)
+    }
+
+    /// Splits the plan into its nodes and total cost.
+    pub fn into_parts(self) -> (Vec<Node>, usize) {
+        (self.nodes, self.cost)
+    }
+}
+
+impl From<(Vec<Node>, usize)> for Plan {
+    fn from((nodes, cost): (Vec<Node>, usize)) -> Self {
+        Self::new(nodes, cost)
+    }
+}
+
+impl From<Plan> for (Vec<Node>, usize) {
+    fn from(plan: Plan) -> Self {
+        plan.into_parts()
+    }
+}
+
+impl IntoIterator for Plan {
+    type Item = Node;
+    type IntoIter = alloc::vec::IntoIter<Node>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.nodes.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Plan {
+    type Item = &'a Node;
+    type IntoIter = core::slice::Iter<'a, Node>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.nodes.iter()
+    }
+}
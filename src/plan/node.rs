@@ -1,3 +1,9 @@
+use crate::action::Action;
+use crate::effect::Effect;
+use crate::world_state::WorldState;
+use alloc::{rc::Rc, string::String, vec::Vec};
+use core::hash::{Hash, Hasher};
+
 /// Represents a node in the planning graph for pathfinding algorithms.
 ///
 /// A node can be either:
@@ -5,10 +11,30 @@
 /// - A world state after applying an action's effect
 ///
 /// This enum is used by the A* pathfinding algorithm to explore possible
-/// state transitions and find optimal paths from start to goal.
-use crate::effect::Effect;
-use crate::world_state::WorldState;
-
+/// state transitions and find optimal paths from start to goal. Plans are
+/// returned as a `Vec<Node>` starting with the initial state.
+///
+/// # Example
+/// ```
+/// use rust_goap::prelude::*;
+///
+/// let start = WorldState::new().set("has_food", false);
+/// let goal = Goal::new().with("has_food", Assert::eq(true));
+/// let buy_food = Action::new("buy_food")
+///     .with_effect(Effect::new().add_mutation(Mutation::set("has_food", true)));
+///
+/// let plan = make_plan(&start, &[buy_food], &goal).unwrap();
+/// let nodes: &[Node] = plan.steps();
+/// for node in nodes {
+///     match node {
+///         Node::State(state) => assert_eq!(state, &start),
+///         Node::Effect((action_key, _, state)) => {
+///             assert_eq!(action_key, "buy_food");
+///             assert!(goal.is_satisfied_by(state));
+///         },
+///     }
+/// }
+/// ```
 #[derive(Clone, Eq, PartialEq, Hash)]
 pub enum Node {
     /// The initial world state at the start of planning.
@@ -29,13 +55,222 @@ impl Node {
             Node::Effect((_, _, state)) => state,
         }
     }
+
+    /// Returns the key of the action that produced this node.
+    ///
+    /// # Returns
+    /// The action key for `Effect` nodes, or `None` for the initial `State` node.
+    ///
+    /// # Example
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let start = WorldState::new().set("has_food", false);
+    /// let goal = Goal::new().with("has_food", Assert::eq(true));
+    /// let buy_food = Action::new("buy_food")
+    ///     .with_effect(Effect::new().add_mutation(Mutation::set("has_food", true)));
+    ///
+    /// let plan = make_plan(&start, &[buy_food], &goal).unwrap();
+    /// assert_eq!(plan.steps()[0].action_key(), None);
+    /// assert_eq!(plan.steps()[1].action_key(), Some("buy_food"));
+    /// ```
+    pub fn action_key(&self) -> Option<&str> {
+        match self {
+            Node::State(_) => None,
+            Node::Effect((action_key, _, _)) => Some(action_key),
+        }
+    }
+
+    /// Returns the effect that was applied to reach this node.
+    ///
+    /// # Returns
+    /// The applied effect for `Effect` nodes, or `None` for the initial `State` node.
+    ///
+    /// # Example
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let start = WorldState::new().set("has_food", false);
+    /// let goal = Goal::new().with("has_food", Assert::eq(true));
+    /// let buy_food = Action::new("buy_food")
+    ///     .with_effect(Effect::new().add_mutation(Mutation::set("has_food", true)).with_cost(3));
+    ///
+    /// let plan = make_plan(&start, &[buy_food], &goal).unwrap();
+    /// assert!(plan.steps()[0].effect().is_none());
+    /// assert_eq!(plan.steps()[1].effect().map(|effect| effect.cost), Some(3));
+    /// ```
+    pub fn effect(&self) -> Option<&Effect> {
+        match self {
+            Node::State(_) => None,
+            Node::Effect((_, effect, _)) => Some(effect),
+        }
+    }
+
+    /// Checks whether this node is the initial state of a plan.
+    ///
+    /// # Returns
+    /// `true` for the `State` node, `false` for `Effect` nodes.
+    ///
+    /// # Example
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let start = WorldState::new().set("has_food", false);
+    /// let goal = Goal::new().with("has_food", Assert::eq(true));
+    /// let buy_food = Action::new("buy_food")
+    ///     .with_effect(Effect::new().add_mutation(Mutation::set("has_food", true)));
+    ///
+    /// let plan = make_plan(&start, &[buy_food], &goal).unwrap();
+    /// assert!(plan.steps()[0].is_initial());
+    /// assert!(!plan.steps()[1].is_initial());
+    ///
+    /// // Walk the steps of a plan without destructuring nodes
+    /// let steps: Vec<&str> = plan.steps().iter().filter_map(|node| node.action_key()).collect();
+    /// assert_eq!(steps, ["buy_food"]);
+    /// ```
+    pub fn is_initial(&self) -> bool {
+        matches!(self, Node::State(_))
+    }
+}
+
+/// Formats a node as a concise, single line suitable for logging plans step by step.
+///
+/// The initial state is printed as `Initial: <state>` and every other node as
+/// `Action "<key>" (cost <n>) -> <state>`, where the state is the one
+/// resulting from the action and `<n>` is the flat cost of its effect.
+///
+/// # Example
+/// ```
+/// use rust_goap::prelude::*;
+///
+/// let start = WorldState::new().set("has_food", false);
+/// let goal = Goal::new().with("has_food", Assert::eq(true));
+/// let buy_food = Action::new("buy_food")
+///     .with_effect(Effect::new().add_mutation(Mutation::set("has_food", true)).with_cost(2));
+///
+/// let plan = make_plan(&start, &[buy_food], &goal).unwrap();
+/// assert_eq!(plan.steps()[0].to_string(), "Initial: WorldState { has_food: Value:Bool(false) }");
+///
+/// let step = plan.steps()[1].to_string();
+/// assert!(step.contains("\"buy_food\""));
+/// assert_eq!(step, "Action \"buy_food\" (cost 2) -> WorldState { has_food: Value:Bool(true) }");
+/// ```
+impl core::fmt::Display for Node {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Node::State(state) => write!(f, "Initial: {state}"),
+            Node::Effect((action_key, effect, state)) => {
+                write!(f, "Action {action_key:?} (cost {}) -> {state}", effect.fixed_cost())
+            },
+        }
+    }
 }
 
-impl std::fmt::Debug for Node {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Debug for Node {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Node::Effect(effect) => effect.fmt(f),
             Node::State(state) => state.fmt(f),
         }
     }
 }
+
+/// A node of the search graph: a world state plus the bookkeeping the
+/// planner needs to tell apart paths that reach the same state.
+///
+/// Search nodes refer to the action that produced them by index instead of
+/// holding a copy of its key and effect, which keeps successor generation
+/// cheap. They are converted to plan [`Node`]s once a path has been found.
+///
+/// Two search nodes are only considered duplicates if the state, the
+/// producing action and the bookkeeping are all equal, so that e.g. reaching a
+/// state with a limited action still available isn't pruned in favor of
+/// reaching it with the action used up, or with a group still open in favor
+/// of reaching it with the group committed, or in fewer steps in favor of
+/// reaching it in more steps when the plan length is limited.
+#[derive(Clone, Debug)]
+pub(crate) struct SearchNode {
+    /// The world state reached.
+    pub(crate) state: WorldState,
+    /// Index of the action that produced this state, or `None` for the start.
+    pub(crate) action: Option<usize>,
+    /// Index of the action's outcome (see [`Action::effects`]) that produced
+    /// this state.
+    pub(crate) outcome: usize,
+    /// How many times each action, by index, was used on the way to this node.
+    ///
+    /// Only actions with a usage limit are counted, and the vector stays empty
+    /// until one of them is used.
+    pub(crate) uses: Vec<usize>,
+    /// Sorted indices of the grouped actions used on the way to this node.
+    /// Each committed action blocks the other actions of its group.
+    pub(crate) committed: Vec<usize>,
+    /// The number of actions on the way to this node, only counted when the
+    /// plan length is limited (and 0 otherwise).
+    pub(crate) steps: usize,
+    /// Bitset of the actions, by index, whose preconditions hold in the state
+    /// of the parent node, or `None` for the start.
+    ///
+    /// The actions applicable in this node only differ for preconditions
+    /// reading a key written by the producing action, and every parent
+    /// producing the same state with the same action agrees on the others, so
+    /// this doesn't take part in comparisons.
+    pub(crate) parent_applicable: Option<Rc<[u64]>>,
+}
+
+impl PartialEq for SearchNode {
+    fn eq(&self, other: &Self) -> bool {
+        self.state == other.state
+            && self.action == other.action
+            && self.outcome == other.outcome
+            && self.uses == other.uses
+            && self.committed == other.committed
+            && self.steps == other.steps
+    }
+}
+
+impl Eq for SearchNode {}
+
+impl Hash for SearchNode {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.state.hash(state);
+        self.action.hash(state);
+        self.outcome.hash(state);
+        self.uses.hash(state);
+        self.committed.hash(state);
+        self.steps.hash(state);
+    }
+}
+
+impl SearchNode {
+    /// Creates the search node for a start state.
+    pub(crate) fn new(state: WorldState) -> Self {
+        Self {
+            state,
+            action: None,
+            outcome: 0,
+            uses: Vec::new(),
+            committed: Vec::new(),
+            steps: 0,
+            parent_applicable: None,
+        }
+    }
+
+    /// Returns the number of times the action at `index` was used.
+    pub(crate) fn uses_of(&self, index: usize) -> usize {
+        self.uses.get(index).copied().unwrap_or(0)
+    }
+
+    /// Converts the search node to a plan node, looking up the action that
+    /// produced it in `actions`.
+    pub(crate) fn into_node(self, actions: &[Action]) -> Node {
+        match self.action {
+            Some(index) => {
+                let action = &actions[index];
+                let effect = action.effects().nth(self.outcome).cloned().unwrap_or_default();
+                Node::Effect((action.key.clone(), effect, self.state))
+            },
+            None => Node::State(self.state),
+        }
+    }
+}
@@ -5,102 +5,531 @@
 //! It uses the A* pathfinding algorithm with custom heuristics and state
 //! transition logic.
 
-use crate::plan::node::Node;
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::rc::Rc;
+use alloc::{format, string::String, string::ToString, vec, vec::Vec};
+use core::cell::{Cell, RefCell};
+use core::time::Duration;
+
+use crate::plan::Plan;
+use crate::plan::node::{Node, SearchNode};
+use crate::plan::regression::{is_regressable, regress};
+use crate::plan::search::{astar, astar_reached, bidirectional, ida_star};
 use crate::{
-    action::Action,
-    basic::assert::compare_values,
-    basic::mutation::{apply_mutator, format_mutations},
+    action::{Action, precondition_readers},
+    basic::assert::{Assert, compare_values},
+    basic::mutation::{Mutation, format_mutations},
+    basic::value::Value,
     effect::Effect,
+    error::PlanError,
     goal::Goal,
     world_state::WorldState,
 };
 
+/// Heuristics available to the A* search.
+///
+/// A* only guarantees the lowest-cost plan when its heuristic is *admissible*,
+/// meaning it never overestimates the remaining cost to the goal. The choice of
+/// heuristic is therefore a trade-off between search speed and optimality.
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum HeuristicKind {
+    /// Always estimates a remaining cost of 0.
+    ///
+    /// This turns A* into Dijkstra's algorithm (uniform-cost search). Since 0
+    /// never overestimates, it is trivially admissible and the returned plan
+    /// is always optimal, at the price of exploring more states.
+    Zero,
+    #[default]
+    /// Sums the per-key distances between the state and the goal requirements
+    /// (see [`WorldState::distance_to_goal`]), weighted by
+    /// [`PlanningConfig::key_weights`].
+    ///
+    /// This is usually much faster than [`HeuristicKind::Zero`], but it is
+    /// **not admissible**: distances are measured in value units rather than
+    /// cost units, so an action that moves a value by 50 for a cost of 1 is
+    /// estimated as 50 cost. Plans found with this heuristic may therefore be
+    /// more expensive than the optimum.
+    SumOfDistances,
+    /// Estimates the cost of repeating the actions that move each unsatisfied
+    /// requirement, from the deltas of their effects (see
+    /// [`Action::estimated_applications`]).
+    ///
+    /// For every requirement, this picks the action that satisfies it for the
+    /// lowest cost when applied repeatedly, and adds the cost of satisfying
+    /// that action's own unmet preconditions the same way (one level deep).
+    /// Requirements no action moves count their distance, as in
+    /// [`HeuristicKind::SumOfDistances`], and the estimates are weighted by
+    /// [`PlanningConfig::key_weights`].
+    ///
+    /// Measuring in cost units rather than value units suits domains where
+    /// actions move keys by large steps, or must be set up by other actions
+    /// before they can be repeated. Computing the estimate looks at every
+    /// action for every requirement, so each expansion is slower. Like
+    /// [`HeuristicKind::SumOfDistances`], this is **not admissible**.
+    ///
+    /// # Example
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let start = WorldState::new().set("position", 0);
+    /// let goal = Goal::new().with("position", Assert::gt_eq(100));
+    /// let actions = [
+    ///     Action::new("walk")
+    ///         .with_effect(Effect::new().add_mutation(Mutation::increment("position", 10))),
+    ///     Action::new("teleport").with_effect(
+    ///         Effect::new().add_mutation(Mutation::set("position", 100)).with_cost(50),
+    ///     ),
+    /// ];
+    ///
+    /// // The distance of 100 makes teleporting look cheaper than walking
+    /// let cost = make_plan_with_config(&PlanningConfig::new(), &start, &actions, &goal).unwrap().cost();
+    /// assert_eq!(cost, 50);
+    ///
+    /// // Ten walks cost 10
+    /// let config = PlanningConfig::new().with_heuristic(HeuristicKind::EstimatedApplications);
+    /// let cost = make_plan_with_config(&config, &start, &actions, &goal).unwrap().cost();
+    /// assert_eq!(cost, 10);
+    ///
+    /// // Estimating the setup of repeated actions expands fewer nodes
+    /// let start = WorldState::new().set("logs", 0).set("planks", 0).set("tables", 0);
+    /// let goal = Goal::new().with("tables", Assert::eq(1));
+    /// let actions = [
+    ///     Action::new("chop").with_effect(Effect::new().add_mutation(Mutation::increment("logs", 1))),
+    ///     Action::new("saw")
+    ///         .with_precondition(("logs", Assert::gt_eq(1)))
+    ///         .with_effect(Effect::new().with_mutations(mutations!["logs" -= 1, "planks" += 4])),
+    ///     Action::new("build")
+    ///         .with_precondition(("planks", Assert::gt_eq(12)))
+    ///         .with_effect(Effect::new().with_mutations(mutations!["planks" -= 12, "tables" += 1])),
+    /// ];
+    ///
+    /// let distances = PlanningConfig::new().with_heuristic(HeuristicKind::SumOfDistances);
+    /// let (plan, stats) = make_plan_with_config_and_stats(&distances, &start, &actions, &goal);
+    /// let (estimated_plan, estimated_stats) =
+    ///     make_plan_with_config_and_stats(&config, &start, &actions, &goal);
+    /// assert_eq!(plan.unwrap().cost(), 7);
+    /// assert_eq!(estimated_plan.unwrap().cost(), 7);
+    /// assert!(estimated_stats.nodes_expanded < stats.nodes_expanded);
+    /// ```
+    EstimatedApplications,
+    /// Counts the goal requirements the state doesn't satisfy, however far
+    /// each is from its target.
+    ///
+    /// For a goal built with [`Goal::from_world_state`], this is the
+    /// [`WorldState::hamming_distance`] to the target state, restricted to the
+    /// target's keys. The estimate never exceeds the number of requirements
+    /// and is admissible as long as every action costs at least 1 and
+    /// satisfies at most one requirement. It ignores
+    /// [`PlanningConfig::key_weights`] and
+    /// [`PlanningConfig::missing_key_penalty`].
+    ///
+    /// # Example
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let start = WorldState::new().set("has_wood", false).set("has_axe", false);
+    /// let goal = Goal::from_world_state(&WorldState::new().set("has_wood", true).set("has_axe", true));
+    /// let actions = [
+    ///     Action::new("buy_axe")
+    ///         .with_effect(Effect::new().add_mutation(Mutation::set("has_axe", true))),
+    ///     Action::new("chop")
+    ///         .with_precondition(("has_axe", Assert::eq(true)))
+    ///         .with_effect(Effect::new().add_mutation(Mutation::set("has_wood", true))),
+    /// ];
+    ///
+    /// let config = PlanningConfig::new().with_heuristic(HeuristicKind::Hamming);
+    /// let plan = make_plan_with_config(&config, &start, &actions, &goal).unwrap();
+    /// assert_eq!(plan.cost(), 2);
+    /// assert_eq!(plan.steps()[1].action_key(), Some("buy_axe"));
+    /// ```
+    Hamming,
+}
+
 /// Heuristic function for A* pathfinding.
 ///
-/// Estimates the cost to reach the goal from the given node.
-/// Uses the `distance_to_goal` method of the world state to calculate
-/// how far the current state is from satisfying all goal requirements.
+/// Estimates the cost to reach the goal from the given state, using the
+/// selected [`HeuristicKind`].
 ///
 /// # Arguments
-/// * `node` - Current node in the search graph
+/// * `state` - Current world state in the search graph
 /// * `goal` - Target goal state
+/// * `actions` - The actions available to the search
+/// * `config` - The search options selecting the heuristic
 ///
 /// # Returns
-/// Estimated cost (as usize) to reach the goal from this node
-fn heuristic(node: &Node, goal: &Goal) -> usize {
-    node.state().distance_to_goal(goal) as usize
+/// Estimated cost (as usize) to reach the goal from this state
+fn heuristic(
+    state: &WorldState,
+    goal: &Goal,
+    actions: &[Action],
+    config: &PlanningConfig,
+) -> usize {
+    match config.heuristic {
+        HeuristicKind::Zero => 0,
+        HeuristicKind::SumOfDistances => state.weighted_distance_with_penalty(
+            goal,
+            &config.key_weights,
+            config.missing_key_penalty,
+        ) as usize,
+        HeuristicKind::EstimatedApplications => goal
+            .requirements
+            .iter()
+            .map(|(key, assert)| {
+                let weight = config.key_weights.get(key).copied().unwrap_or(1);
+                let cost = match actions_cost(state, key, assert, actions) {
+                    Some(cost) => cost,
+                    None => requirement_distance(state, key, assert, config),
+                };
+                cost.saturating_mul(weight)
+            })
+            .fold(0, u64::saturating_add) as usize,
+        HeuristicKind::Hamming => {
+            goal.requirements.iter().filter(|(key, assert)| !assert.check(state, key)).count()
+        },
+    }
+}
+
+/// Estimates the cost of satisfying a requirement by repeating a single
+/// action, including the cost of keeping that action's preconditions
+/// satisfied with other actions.
+///
+/// A precondition on a key the repeated action itself moves must still hold
+/// before its last application, so it is checked against the value the key
+/// drifts to by then, as well as against the current value. Each unmet
+/// precondition is estimated by repeating the cheapest other action, one
+/// level deep.
+///
+/// Returns `None` if the requirement is satisfied or no action moves it.
+fn actions_cost(state: &WorldState, key: &str, assert: &Assert, actions: &[Action]) -> Option<u64> {
+    let repeated_cost = |action: &Action, key: &str, current: Option<&Value>, assert: &Assert| {
+        let applications = action.applications_for(key, current, assert)?;
+        let cost = action.effect.as_ref().map_or(0, Effect::fixed_cost) as u64;
+        Some((applications, applications.saturating_mul(cost)))
+    };
+    let setup_cost = |key: &str, current: Option<&Value>, assert: &Assert| {
+        let holds =
+            current.map_or(assert.holds_when_missing(), |value| compare_values(assert, value));
+        if holds {
+            return 0;
+        }
+        actions
+            .iter()
+            .filter_map(|setup| repeated_cost(setup, key, current, assert))
+            .map(|(_, cost)| cost)
+            .min()
+            .unwrap_or(1)
+    };
+    actions
+        .iter()
+        .filter_map(|action| {
+            let (applications, cost) = repeated_cost(action, key, state.get(key), assert)?;
+            let setup = action
+                .preconditions
+                .iter()
+                .map(|(key, assert)| {
+                    let current = state.get(key);
+                    let last = current.map(|&value| action.drift(key, value, applications - 1));
+                    setup_cost(key, current, assert).max(setup_cost(key, last.as_ref(), assert))
+                })
+                .fold(0, u64::saturating_add);
+            Some(cost.saturating_add(setup))
+        })
+        .min()
+}
+
+/// Measures how far the value of `key` is from satisfying a requirement,
+/// like [`WorldState::distance_to_goal_with_penalty`] does for every key.
+fn requirement_distance(
+    state: &WorldState,
+    key: &str,
+    assert: &Assert,
+    config: &PlanningConfig,
+) -> u64 {
+    match state.get(key) {
+        Some(value) => assert.distance_to(value),
+        None if assert.holds_when_missing() => 0,
+        None => config.missing_key_penalty,
+    }
+}
+
+/// Evaluates the preconditions of the actions available to a search.
+///
+/// Preconditions only change when a key they read changes, so instead of
+/// evaluating every action at every node, the actions applicable in a node are
+/// derived from those applicable in its parent: only the actions whose
+/// preconditions read a key written by the effect leading to the node are
+/// evaluated again.
+struct Preconditions<'a> {
+    /// The actions available to the search.
+    actions: &'a [Action],
+    /// The indices of the actions reading each key in their preconditions.
+    readers: BTreeMap<&'a str, Vec<usize>>,
+    /// Whether an action may be used at all; other actions are never evaluated.
+    allow: &'a dyn Fn(&Action) -> bool,
+    /// Whether each action, by index, can help reach the goal, when
+    /// irrelevant actions are pruned; other actions are never evaluated.
+    relevant: Option<Vec<bool>>,
+    /// The number of action preconditions evaluated so far.
+    checks: Cell<usize>,
+    /// Whether misconfigured actions are recorded in `error`, rather than
+    /// skipped silently.
+    report: bool,
+    /// The first misconfigured action found, when reporting.
+    error: RefCell<Option<PlanError>>,
+}
+
+impl<'a> Preconditions<'a> {
+    fn new(actions: &'a [Action], allow: &'a dyn Fn(&Action) -> bool) -> Self {
+        Self {
+            actions,
+            readers: precondition_readers(actions),
+            allow,
+            relevant: None,
+            checks: Cell::new(0),
+            report: false,
+            error: RefCell::new(None),
+        }
+    }
+
+    /// Leaves out the actions that can't help reach `goal` if the
+    /// configuration asks for it (see
+    /// [`PlanningConfig::prune_irrelevant_actions`]).
+    fn pruned(mut self, config: &PlanningConfig, goal: &Goal) -> Self {
+        if config.prune_irrelevant_actions {
+            self.relevant = Some(relevant_actions(self.actions, goal));
+        }
+        self
+    }
+
+    /// Makes the search record the first misconfigured action it finds (see
+    /// [`try_make_plan`]).
+    fn reporting(mut self) -> Self {
+        self.report = true;
+        self
+    }
+
+    /// Records a misconfigured action, unless one was recorded already or
+    /// misconfigurations aren't reported.
+    fn fail(&self, error: impl FnOnce() -> PlanError) {
+        if self.report && self.error.borrow().is_none() {
+            *self.error.borrow_mut() = Some(error());
+        }
+    }
+
+    /// Returns the bitset of the actions, by index, whose preconditions hold
+    /// in the state of `node`.
+    fn applicable(&self, node: &SearchNode) -> Rc<[u64]> {
+        let check = |bits: &mut [u64], index: usize| {
+            let action = &self.actions[index];
+            if self.relevant.as_ref().is_some_and(|relevant| !relevant[index])
+                || !(self.allow)(action)
+            {
+                return;
+            }
+            self.checks.set(self.checks.get() + 1);
+            let missing = self.report.then(|| action.missing_precondition_key(&node.state));
+            let holds = match missing.flatten() {
+                Some(key) => {
+                    let key = key.to_string();
+                    self.fail(|| PlanError::MissingKey { action: action.key.clone(), key });
+                    false
+                },
+                None => action.check_preconditions(&node.state),
+            };
+            if holds {
+                bits[index / 64] |= 1 << (index % 64);
+            } else {
+                bits[index / 64] &= !(1 << (index % 64));
+            }
+        };
+
+        let (Some(parent), Some(action)) = (&node.parent_applicable, node.action) else {
+            let mut bits = vec![0; self.actions.len().div_ceil(64)];
+            for index in 0..self.actions.len() {
+                check(&mut bits, index);
+            }
+            return bits.into();
+        };
+
+        let effect = self.actions[action].effects().nth(node.outcome);
+        let mut stale: Vec<usize> = effect
+            .into_iter()
+            .flat_map(|effect| &effect.mutations)
+            .filter_map(|mutation| self.readers.get(mutation_keys(mutation).1))
+            .flatten()
+            .copied()
+            .collect();
+        if stale.is_empty() {
+            return parent.clone();
+        }
+        stale.sort_unstable();
+        stale.dedup();
+
+        let mut bits = parent.to_vec();
+        for index in stale {
+            check(&mut bits, index);
+        }
+        bits.into()
+    }
+}
+
+/// Checks whether the action at `index` is in a bitset of actions.
+fn contains(bits: &[u64], index: usize) -> bool {
+    bits[index / 64] & (1 << (index % 64)) != 0
 }
 
 /// Generates successor nodes for the A* pathfinding algorithm.
 ///
 /// For a given node, returns all possible next nodes by applying
-/// valid actions from the available action list. Each successor
-/// includes the cost of applying the action's effect.
+/// valid actions from the available action list. An action with
+/// alternative outcomes yields one successor per outcome. Each successor
+/// includes the cost of applying the outcome's effect. Actions that
+/// reached their usage limit on the path to `node`, or whose group is
+/// committed to another action on that path, are skipped, as are actions
+/// rejected by `allow`. Once the path to `node` contains `max_steps` actions,
+/// there are no successors.
 ///
 /// # Arguments
 /// * `node` - Current node to expand
-/// * `actions` - List of available actions
+/// * `preconditions` - The available actions, and the actions allowed at all
+/// * `max_steps` - Maximum number of actions in a path, if limited
+/// * `validate` - Whether to skip effects that fail [`Effect::validate`]
 ///
 /// # Returns
-/// Iterator over (successor_node, transition_cost) pairs
+/// Iterator over (successor_node, transition_cost) pairs, sorted by action
+/// key (actions with the same key keep their order in `actions`)
 fn successors<'a>(
-    node: &'a Node,
-    actions: &'a [Action],
-) -> impl Iterator<Item = (Node, usize)> + 'a {
-    let state = node.state();
-    actions.iter().filter_map(move |action| {
-        // Skip actions whose preconditions aren't met or have no effect
-        if !action.check_preconditions(state) || action.effect.is_none() {
-            return None;
+    node: &'a SearchNode,
+    preconditions: &'a Preconditions<'a>,
+    max_steps: Option<usize>,
+    validate: bool,
+) -> impl Iterator<Item = (SearchNode, usize)> + 'a {
+    let state = &node.state;
+    let actions = preconditions.actions;
+    let at_step_limit = max_steps.is_some_and(|max_steps| node.steps >= max_steps);
+    let applicable = (!at_step_limit).then(|| preconditions.applicable(node));
+    let parent_applicable = applicable.clone();
+    let usable = actions.iter().enumerate().filter(move |(index, action)| {
+        // Skip actions that aren't allowed or whose preconditions aren't met;
+        // actions without any outcome yield no successors below
+        if !applicable.as_ref().is_some_and(|applicable| contains(applicable, *index)) {
+            return false;
         }
 
-        let effect = action.effect.as_ref().unwrap();
+        // Skip actions that were already used as often as allowed
+        if action.max_uses.is_some_and(|max_uses| node.uses_of(*index) >= max_uses) {
+            return false;
+        }
 
-        // Apply the effect's mutations to create the new state
-        let mut new_state = state.clone();
-        for mutator in &effect.mutations {
-            apply_mutator(&mut new_state, mutator);
+        // Skip actions whose group is already committed to another action
+        if let Some(group) = &action.group
+            && node
+                .committed
+                .iter()
+                .any(|&used| used != *index && actions[used].group.as_ref() == Some(group))
+        {
+            return false;
         }
 
-        // Create a new effect with the same properties
-        let new_effect = Effect { mutations: effect.mutations.clone(), cost: effect.cost };
+        true
+    });
 
-        // Return the successor node with its transition cost
-        Some((Node::Effect((action.key.clone(), new_effect, new_state)), effect.cost))
-    })
+    let mut successors: Vec<(SearchNode, usize)> = usable
+        .flat_map(move |(index, action)| {
+            let parent_applicable = parent_applicable.clone();
+            action.effects().enumerate().filter_map(move |(outcome, effect)| {
+                // Apply the effect's mutations to create the new state; the clone shares
+                // storage with `state` until a mutation actually changes a value. Effects
+                // that would mix value types are skipped when validating
+                let new_state = if validate || preconditions.report {
+                    match effect.try_apply(state) {
+                        Ok(new_state) => new_state,
+                        Err(error) => {
+                            preconditions.fail(|| PlanError::InvalidEffect {
+                                action: action.key.clone(),
+                                error,
+                            });
+                            return None;
+                        },
+                    }
+                } else {
+                    let mut new_state = state.clone();
+                    effect.apply_to(&mut new_state);
+                    new_state
+                };
+
+                #[cfg(feature = "log")]
+                if new_state == *state {
+                    log::debug!(
+                        "action {:?} doesn't change the state it is applied to",
+                        action.key
+                    );
+                }
+
+                // The transition cost may depend on how much the effect changed the state
+                let cost = effect.transition_cost(state, &new_state);
+
+                // Only limited actions are counted, so unlimited ones don't split identical states
+                let mut uses = node.uses.clone();
+                if action.max_uses.is_some() {
+                    uses.resize(actions.len(), 0);
+                    uses[index] += 1;
+                }
+
+                let mut committed = node.committed.clone();
+                if action.group.is_some()
+                    && let Err(position) = committed.binary_search(&index)
+                {
+                    committed.insert(position, index);
+                }
+
+                // Steps are only counted when limited, so unlimited searches don't tell
+                // apart the same state reached at different depths
+                let steps = if max_steps.is_some() { node.steps + 1 } else { 0 };
+
+                // Return the successor node with its transition cost
+                let successor = SearchNode {
+                    state: new_state,
+                    action: Some(index),
+                    outcome,
+                    uses,
+                    committed,
+                    steps,
+                    parent_applicable: parent_applicable.clone(),
+                };
+                Some((successor, cost))
+            })
+        })
+        .collect();
+
+    // Order successors by action key, so that the choice between equally good
+    // paths doesn't depend on the order of `actions`
+    successors.sort_by(|(lhs, _), (rhs, _)| {
+        let key = |node: &SearchNode| node.action.map(|index| actions[index].key.as_str());
+        key(lhs).cmp(&key(rhs))
+    });
+    successors.into_iter()
 }
 
-/// Checks if a node satisfies all goal requirements.
+/// Checks if a state satisfies all goal requirements.
 ///
-/// Compares the world state in the node against all requirements
-/// specified in the goal. Returns true only if all requirements
-/// are satisfied according to their assertion rules.
+/// This is [`Goal::is_satisfied_by`], so that the planner and users always
+/// agree on which states are goal states.
 ///
 /// # Arguments
-/// * `node` - Node to check
+/// * `state` - World state to check
 /// * `goal` - Goal containing requirements to satisfy
 ///
 /// # Returns
-/// `true` if the node's state satisfies all goal requirements, `false` otherwise
-fn is_goal(node: &Node, goal: &Goal) -> bool {
-    goal.requirements.iter().all(|(key, required_value)| {
-        let state_value = match node.state().0.get(key) {
-            Some(val) => val,
-            None => {
-                // If a goal requirement key is not in the state,
-                // the goal cannot be satisfied
-                return false;
-            },
-        };
-        compare_values(required_value, state_value)
-    })
+/// `true` if the state satisfies all goal requirements, `false` otherwise
+fn is_goal(state: &WorldState, goal: &Goal) -> bool {
+    goal.is_satisfied_by(state)
 }
 
 /// Planning strategies for finding paths from start to goal.
 ///
-/// Different strategies can be used depending on the planning requirements,
-/// though currently only `StartToGoal` is implemented.
-#[derive(Default, Copy, Clone, Debug)]
+/// Different strategies can be used depending on the planning requirements.
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
 pub enum PlanningStrategy {
     #[default]
     /// Starts from the initial state and searches forward to find the
@@ -110,6 +539,592 @@ pub enum PlanningStrategy {
     /// starting point, ensuring the lowest-cost path is found, though
     /// it may take longer than alternative approaches.
     StartToGoal,
+    /// Searches forward from the initial state and backward from the goal at
+    /// the same time, until the two searches meet in the middle.
+    ///
+    /// The backward search regresses the goal through actions: it computes
+    /// the requirements a state must meet so that an action leads to a state
+    /// satisfying the goal, and so on. For deep plans, the two searches
+    /// together usually explore fewer states than a search from one side.
+    /// Both searches expand states in order of cost, ignoring the heuristic
+    /// and its weight, so the returned plan is always the cheapest one.
+    /// However, every state reached forward is compared with every goal
+    /// reached backward, so each expansion gets slower as both searches grow:
+    /// this strategy pays off for deep plans in moderately sized state spaces.
+    ///
+    /// Goals can only be regressed through set, delete, increment and
+    /// decrement mutations with a fixed cost. If an allowed action uses any
+    /// other mutation, a cost per unit, a usage limit, or a group, or if the
+    /// number of steps or the beam width is limited, the search falls back to
+    /// [`PlanningStrategy::StartToGoal`].
+    ///
+    /// # Example
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let start = WorldState::new().set("energy", 30).set("hunger", 70).set("gold", 0);
+    /// let goal = Goal::new().with("gold", Assert::eq(7));
+    ///
+    /// let actions = [
+    ///     Action::new("sleep")
+    ///         .with_effect(Effect::new().add_mutation(Mutation::increment("energy", 10))),
+    ///     Action::new("eat")
+    ///         .with_precondition(("energy", Assert::gt_eq(26)))
+    ///         .with_effect(Effect::new().add_mutation(Mutation::decrement("hunger", 10))),
+    ///     Action::new("rob")
+    ///         .with_precondition(("hunger", Assert::lt_eq(50)))
+    ///         .with_precondition(("energy", Assert::gt_eq(50)))
+    ///         .with_effect(
+    ///             Effect::new()
+    ///                 .add_mutation(Mutation::increment("gold", 1))
+    ///                 .add_mutation(Mutation::decrement("energy", 5))
+    ///                 .add_mutation(Mutation::increment("hunger", 5)),
+    ///         ),
+    /// ];
+    ///
+    /// let forward = PlanningConfig::new().with_heuristic(HeuristicKind::Zero);
+    /// let optimal_cost = make_plan_with_config(&forward, &start, &actions, &goal).unwrap().cost();
+    ///
+    /// let config = PlanningConfig::new().with_strategy(PlanningStrategy::Bidirectional);
+    /// let plan = make_plan_with_config(&config, &start, &actions, &goal).unwrap();
+    /// assert_eq!(plan.cost(), optimal_cost);
+    /// assert_eq!(validate_plan(&start, &actions, plan.steps()).unwrap().get("gold"), Some(&Value::I64(7)));
+    /// ```
+    Bidirectional,
+    /// Searches forward from the initial state like
+    /// [`PlanningStrategy::StartToGoal`], ignoring the heuristic.
+    ///
+    /// Without a heuristic, A* becomes Dijkstra's algorithm (uniform-cost
+    /// search): states are expanded in order of cost, so the returned plan is
+    /// always the cheapest one, whichever [`HeuristicKind`] and weight are
+    /// configured. This usually explores more states than a heuristic search.
+    ///
+    /// # Example
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let start = WorldState::new().set("distance", 100);
+    /// let goal = Goal::new().with("distance", Assert::eq(0));
+    ///
+    /// // Walking covers 50 units for a cost of 1, teleporting costs 5
+    /// let walk = Action::new("walk")
+    ///     .with_precondition(("distance", Assert::gt_eq(50)))
+    ///     .with_effect(Effect::new().add_mutation(Mutation::decrement("distance", 50)));
+    /// let teleport = Action::new("teleport")
+    ///     .with_effect(Effect::new().add_mutation(Mutation::set("distance", 0)).with_cost(5));
+    /// let actions = [walk, teleport];
+    ///
+    /// // The default heuristic overestimates the cost of walking
+    /// assert_eq!(make_plan(&start, &actions, &goal).unwrap().cost(), 5);
+    ///
+    /// let config = PlanningConfig::new().with_strategy(PlanningStrategy::Dijkstra);
+    /// let plan = make_plan_with_config(&config, &start, &actions, &goal).unwrap();
+    /// assert_eq!(plan.cost(), 2);
+    /// assert_eq!(plan.steps()[1].action_key(), Some("walk"));
+    /// ```
+    Dijkstra,
+    /// Searches forward from the initial state with iterative deepening A*
+    /// (IDA*), which needs far less memory than A*.
+    ///
+    /// Each iteration is a depth-first search that abandons paths whose cost
+    /// plus heuristic estimate exceeds a threshold, which grows to the
+    /// smallest abandoned estimate after each iteration. Only the current
+    /// path and the states waiting next to it are kept, so memory grows with
+    /// the length of the plan rather than with the number of states explored.
+    /// In exchange, states are expanded again in every iteration and whenever
+    /// several paths lead to them, so this is usually slower than
+    /// [`PlanningStrategy::StartToGoal`], especially in domains where actions
+    /// can be performed in many orders. The plan has the same cost as with
+    /// [`PlanningStrategy::StartToGoal`] when the heuristic is admissible (see
+    /// [`HeuristicKind`]). The beam width is ignored.
+    ///
+    /// # Example
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let start = WorldState::new().set("x", 0).set("y", 0).set("has_map", false);
+    /// let goal = Goal::new()
+    ///     .with("x", Assert::eq(4))
+    ///     .with("y", Assert::eq(3))
+    ///     .with("has_map", Assert::eq(true));
+    /// let actions = [
+    ///     Action::new("buy_map")
+    ///         .with_effect(Effect::new().add_mutation(Mutation::set("has_map", true))),
+    ///     Action::new("east")
+    ///         .with_precondition(("x", Assert::lt(4)))
+    ///         .with_effect(Effect::new().add_mutation(Mutation::increment("x", 1))),
+    ///     Action::new("north")
+    ///         .with_precondition(("y", Assert::lt(3)))
+    ///         .with_effect(Effect::new().add_mutation(Mutation::increment("y", 1))),
+    ///     Action::new("west")
+    ///         .with_precondition(("x", Assert::gt(0)))
+    ///         .with_effect(Effect::new().add_mutation(Mutation::decrement("x", 1))),
+    /// ];
+    ///
+    /// let astar = PlanningConfig::new();
+    /// let (astar_plan, astar_stats) = make_plan_with_config_and_stats(&astar, &start, &actions, &goal);
+    /// let ida = PlanningConfig::new().with_strategy(PlanningStrategy::IterativeDeepening);
+    /// let (ida_plan, ida_stats) = make_plan_with_config_and_stats(&ida, &start, &actions, &goal);
+    ///
+    /// let plan = ida_plan.unwrap();
+    /// assert_eq!(plan.cost(), astar_plan.unwrap().cost());
+    /// assert_eq!(validate_plan(&start, &actions, plan.steps()).unwrap().get("x"), Some(&Value::I64(4)));
+    /// assert!(ida_stats.peak_frontier < astar_stats.peak_frontier);
+    /// ```
+    IterativeDeepening,
+}
+
+/// Options controlling how the planner searches for a plan.
+///
+/// The default configuration is the one used by [`make_plan`]: forward A*
+/// search with the [`HeuristicKind::SumOfDistances`] heuristic, a heuristic
+/// weight of 1.0, no expansion budget, and no limit on the number of steps.
+///
+/// # Example
+/// ```
+/// use rust_goap::prelude::*;
+///
+/// let config = PlanningConfig::new()
+///     .with_heuristic(HeuristicKind::SumOfDistances)
+///     .with_weight(1.5)
+///     .with_max_expansions(10_000);
+///
+/// assert_eq!(config.weight, 1.5);
+/// assert_eq!(config.max_expansions, Some(10_000));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlanningConfig {
+    /// The planning strategy to use.
+    pub strategy: PlanningStrategy,
+
+    /// The heuristic guiding the search.
+    pub heuristic: HeuristicKind,
+
+    /// The weight applied to the heuristic (weighted A*).
+    ///
+    /// The search orders nodes by `g + weight * h`, where `g` is the cost so
+    /// far and `h` the heuristic estimate. A weight of 1.0 is regular A*.
+    /// Weights above 1.0 make the search greedier: it usually expands far fewer
+    /// nodes, and with an admissible heuristic the returned plan costs at most
+    /// `weight` times the optimum. Weights must not be negative.
+    pub weight: f64,
+
+    /// The maximum number of nodes the search may expand.
+    ///
+    /// When the budget runs out before a plan is found, planning fails as if
+    /// the goal were unreachable. `None` means the search is unbounded.
+    pub max_expansions: Option<usize>,
+
+    /// The maximum number of actions a plan may contain.
+    ///
+    /// Paths longer than this are pruned regardless of their cost, so a more
+    /// expensive but shorter plan may be returned instead of the optimal one.
+    /// When no plan fits within the limit, planning fails. `None` means plans
+    /// can be of any length.
+    pub max_steps: Option<usize>,
+
+    /// Whether effects are checked with [`Effect::validate`] before being applied.
+    ///
+    /// An effect that would mix value types, such as incrementing a boolean,
+    /// or overflow an integer can't be applied. With validation, the search
+    /// skips such effects, as if their action weren't applicable in that
+    /// state. Each mutation is checked as it is applied, so this is on by
+    /// default. Without validation, mutations mixing types leave their value
+    /// unchanged, or panic with the `strict` feature, and overflowing
+    /// integers saturate.
+    pub validate_effects: bool,
+
+    /// The distance [`HeuristicKind::SumOfDistances`] adds for each required
+    /// key missing from a state.
+    ///
+    /// The default of 1 makes states without a key look close to the goal,
+    /// even if the key must reach a large value. A penalty near the magnitude
+    /// of the required values steers the search toward creating the key
+    /// sooner, at the risk of overestimating (see
+    /// [`WorldState::distance_to_goal_with_penalty`]).
+    pub missing_key_penalty: u64,
+
+    /// The maximum number of states waiting to be expanded (beam search).
+    ///
+    /// Whenever more states are waiting, the ones with the largest estimated
+    /// total cost are dropped, which bounds the memory of the frontier. This
+    /// makes the search incomplete and no longer optimal: a dropped state may
+    /// have been the only way to the goal, or part of the cheapest plan, so
+    /// planning can fail even though a plan exists. Narrower beams need less
+    /// memory but fail more often, and a width of 0 only finds plans when the
+    /// initial state already satisfies the goal. `None` (the default) keeps
+    /// every state.
+    ///
+    /// [`PlanningStrategy::Bidirectional`] falls back to
+    /// [`PlanningStrategy::StartToGoal`] when a beam width is set.
+    pub beam_width: Option<usize>,
+
+    /// The weight of each key's distance in [`HeuristicKind::SumOfDistances`].
+    ///
+    /// Keys without a weight count once (see
+    /// [`WorldState::weighted_distance_to_goal`]). Weighting a key makes the
+    /// search prefer states that are closer to satisfying it. Like a missing-key
+    /// penalty, weights above 1 make overestimating the remaining cost more
+    /// likely.
+    pub key_weights: BTreeMap<String, u64>,
+
+    /// Whether actions that can't help reach the goal are left out of the
+    /// search.
+    ///
+    /// An action is relevant if one of its outcomes writes a key the goal
+    /// constrains, or a key read by another relevant action, through its
+    /// preconditions or its mutations. The relevant actions are found once
+    /// per search by working backward from the goal, and the others are
+    /// never evaluated, which shrinks the branching factor of domains with
+    /// many unrelated actions. Since a pruned action can't change anything the
+    /// goal depends on, the same plans are found. Off by default.
+    pub prune_irrelevant_actions: bool,
+}
+
+impl Default for PlanningConfig {
+    /// Creates the configuration used by [`make_plan`].
+    fn default() -> Self {
+        Self {
+            strategy: PlanningStrategy::default(),
+            heuristic: HeuristicKind::default(),
+            weight: 1.0,
+            max_expansions: None,
+            max_steps: None,
+            validate_effects: true,
+            missing_key_penalty: 1,
+            beam_width: None,
+            key_weights: BTreeMap::new(),
+            prune_irrelevant_actions: false,
+        }
+    }
+}
+
+impl PlanningConfig {
+    /// Creates the default planning configuration.
+    ///
+    /// # Example
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let config = PlanningConfig::new();
+    /// assert_eq!(config.heuristic, HeuristicKind::SumOfDistances);
+    /// assert_eq!(config.weight, 1.0);
+    /// assert_eq!(config.max_expansions, None);
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the planning strategy.
+    pub fn with_strategy(mut self, strategy: PlanningStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Sets the heuristic guiding the search.
+    pub fn with_heuristic(mut self, heuristic: HeuristicKind) -> Self {
+        self.heuristic = heuristic;
+        self
+    }
+
+    /// Sets the heuristic weight (see [`PlanningConfig::weight`]).
+    pub fn with_weight(mut self, weight: f64) -> Self {
+        self.weight = weight;
+        self
+    }
+
+    /// Sets the maximum number of node expansions (see [`PlanningConfig::max_expansions`]).
+    pub fn with_max_expansions(mut self, max_expansions: usize) -> Self {
+        self.max_expansions = Some(max_expansions);
+        self
+    }
+
+    /// Sets the maximum number of actions in a plan (see [`PlanningConfig::max_steps`]).
+    ///
+    /// # Example
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let start = WorldState::new().set("position", 0);
+    /// let goal = Goal::new().with("position", Assert::gt_eq(100));
+    /// let actions = [
+    ///     Action::new("walk")
+    ///         .with_effect(Effect::new().add_mutation(Mutation::increment("position", 10))),
+    ///     Action::new("teleport").with_effect(
+    ///         Effect::new().add_mutation(Mutation::set("position", 100)).with_cost(50),
+    ///     ),
+    /// ];
+    ///
+    /// // The optimal plan walks 10 times
+    /// let config = PlanningConfig::new().with_heuristic(HeuristicKind::Zero);
+    /// let plan = make_plan_with_config(&config, &start, &actions, &goal).unwrap();
+    /// assert_eq!((plan.len(), plan.cost()), (10, 10));
+    ///
+    /// // With at most 5 actions, the pricier teleport is the only option
+    /// let limited = config.clone().with_max_steps(5);
+    /// let plan = make_plan_with_config(&limited, &start, &actions, &goal).unwrap();
+    /// assert_eq!(plan.steps()[1].action_key(), Some("teleport"));
+    /// assert_eq!(plan.cost(), 50);
+    ///
+    /// // Without the teleport, the goal can't be reached in 5 actions
+    /// let limited = config.with_max_steps(5);
+    /// assert!(make_plan_with_config(&limited, &start, &actions[..1], &goal).is_none());
+    /// ```
+    pub fn with_max_steps(mut self, max_steps: usize) -> Self {
+        self.max_steps = Some(max_steps);
+        self
+    }
+
+    /// Enables or disables effect validation (see [`PlanningConfig::validate_effects`]).
+    ///
+    /// # Example
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let start = WorldState::new().set("torch_lit", false).set("light", 0);
+    /// let goal = Goal::new().with("light", Assert::gt_eq(1));
+    ///
+    /// let actions = [
+    ///     // Misconfigured: "torch_lit" holds a boolean
+    ///     Action::new("fan_torch").with_effect(
+    ///         Effect::new()
+    ///             .add_mutation(Mutation::increment("torch_lit", 1))
+    ///             .add_mutation(Mutation::increment("light", 5)),
+    ///     ),
+    ///     Action::new("open_curtains").with_effect(
+    ///         Effect::new().add_mutation(Mutation::increment("light", 1)).with_cost(3),
+    ///     ),
+    /// ];
+    ///
+    /// // "fan_torch" can't be applied, so it is skipped
+    /// let config = PlanningConfig::new();
+    /// assert!(config.validate_effects);
+    /// let plan = make_plan_with_config(&config, &start, &actions, &goal).unwrap();
+    /// assert_eq!(plan.steps()[1].action_key(), Some("open_curtains"));
+    /// assert_eq!(plan.cost(), 3);
+    /// ```
+    pub fn with_effect_validation(mut self, validate_effects: bool) -> Self {
+        self.validate_effects = validate_effects;
+        self
+    }
+
+    /// Sets whether actions that can't help reach the goal are left out of
+    /// the search (see [`PlanningConfig::prune_irrelevant_actions`]).
+    ///
+    /// # Example
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let start = WorldState::new().set("has_axe", false).set("wood", 0).set("has_house", false);
+    /// let goal = Goal::new().with("has_house", Assert::eq(true));
+    ///
+    /// // Three actions build the house
+    /// let mut actions = vec![
+    ///     Action::new("buy_axe")
+    ///         .with_effect(Effect::new().add_mutation(Mutation::set("has_axe", true))),
+    ///     Action::new("chop")
+    ///         .with_precondition(("has_axe", Assert::eq(true)))
+    ///         .with_effect(Effect::new().add_mutation(Mutation::increment("wood", 10))),
+    ///     Action::new("build")
+    ///         .with_precondition(("wood", Assert::gt_eq(10)))
+    ///         .with_effect(Effect::new().add_mutation(Mutation::set("has_house", true))),
+    /// ];
+    /// // 27 more don't matter for it
+    /// actions.extend((0..27).map(|i| {
+    ///     let key = format!("chore_{i}");
+    ///     Action::new(format!("do_{key}"))
+    ///         .with_effect(Effect::new().add_mutation(Mutation::increment(key, 1)))
+    /// }));
+    ///
+    /// let full = PlanningConfig::new();
+    /// let (plan, full_stats) = make_plan_with_config_and_stats(&full, &start, &actions, &goal);
+    /// let pruned = PlanningConfig::new().with_pruning(true);
+    /// let (pruned_plan, pruned_stats) = make_plan_with_config_and_stats(&pruned, &start, &actions, &goal);
+    ///
+    /// assert_eq!(pruned_plan, plan);
+    /// assert_eq!(pruned_plan.unwrap().cost(), 3);
+    /// assert!(pruned_stats.nodes_expanded * 10 < full_stats.nodes_expanded);
+    /// ```
+    pub fn with_pruning(mut self, prune_irrelevant_actions: bool) -> Self {
+        self.prune_irrelevant_actions = prune_irrelevant_actions;
+        self
+    }
+
+    /// Sets the heuristic penalty for missing keys (see
+    /// [`PlanningConfig::missing_key_penalty`]).
+    ///
+    /// # Example
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// // "gold" only exists once the purse is opened
+    /// let start = WorldState::new().set("steps", 0);
+    /// let goal = Goal::new().with("gold", Assert::gt_eq(100));
+    ///
+    /// let actions = [
+    ///     Action::new("wander")
+    ///         .with_effect(Effect::new().add_mutation(Mutation::increment("steps", 1))),
+    ///     Action::new("open_purse")
+    ///         .with_precondition(("gold", Assert::absent()))
+    ///         .with_effect(Effect::new().add_mutation(Mutation::set("gold", 0))),
+    ///     Action::new("mine")
+    ///         .with_precondition(("gold", Assert::exists()))
+    ///         .with_effect(Effect::new().add_mutation(Mutation::increment("gold", 50))),
+    /// ];
+    ///
+    /// // With a penalty of 1, wandering looks closer to the goal than opening
+    /// // the purse, and the budget runs out before a plan is found
+    /// let config = PlanningConfig::new().with_max_expansions(20);
+    /// assert!(make_plan_with_config(&config, &start, &actions, &goal).is_none());
+    ///
+    /// // A penalty matching the required amount goes for the gold right away
+    /// let config = config.with_missing_key_penalty(100);
+    /// let plan = make_plan_with_config(&config, &start, &actions, &goal).unwrap();
+    /// assert_eq!(plan.cost(), 3);
+    /// assert_eq!(plan.steps()[1].action_key(), Some("open_purse"));
+    /// ```
+    pub fn with_missing_key_penalty(mut self, penalty: u64) -> Self {
+        self.missing_key_penalty = penalty;
+        self
+    }
+
+    /// Sets the maximum number of states waiting to be expanded (see
+    /// [`PlanningConfig::beam_width`]).
+    ///
+    /// # Example
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// // Walk across a grid while two switches can be flipped at any point,
+    /// // which multiplies the states the search keeps around
+    /// let start = WorldState::new().set("x", 0).set("y", 0).set("a", 0).set("b", 0);
+    /// let goal = Goal::new().with("x", Assert::eq(4)).with("y", Assert::eq(4));
+    /// let step = |key: &str, amount: i64| {
+    ///     Effect::new().add_mutation(Mutation::increment(key, amount)).with_cost(2)
+    /// };
+    /// let mut actions = vec![
+    ///     Action::from_effect("east", step("x", 1)),
+    ///     Action::from_effect("west", step("x", -1)),
+    ///     Action::from_effect("north", step("y", 1)),
+    ///     Action::from_effect("south", step("y", -1)),
+    /// ];
+    /// for switch in ["a", "b"] {
+    ///     for value in 0..4 {
+    ///         let set = Effect::new().add_mutation(Mutation::set(switch, value));
+    ///         actions.push(Action::from_effect(format!("set_{switch}_{value}"), set));
+    ///     }
+    /// }
+    ///
+    /// let (plan, stats) = make_plan_with_stats(&start, &actions, &goal);
+    /// assert_eq!(plan.unwrap().cost(), 16);
+    /// assert!(stats.peak_frontier > 1_000);
+    ///
+    /// // A narrow beam still finds a plan while keeping few states around
+    /// let config = PlanningConfig::new().with_beam_width(8);
+    /// let (plan, stats) = make_plan_with_config_and_stats(&config, &start, &actions, &goal);
+    /// let plan = plan.unwrap();
+    /// assert!(stats.peak_frontier <= 8);
+    /// assert!(goal.is_satisfied_by(plan.steps().last().unwrap().state()));
+    /// ```
+    pub fn with_beam_width(mut self, beam_width: usize) -> Self {
+        self.beam_width = Some(beam_width);
+        self
+    }
+
+    /// Sets the heuristic weight of a key (see [`PlanningConfig::key_weights`]).
+    ///
+    /// # Example
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let start = WorldState::new().set("gold", 0).set("has_key", false).set("position", 0);
+    /// let goal = Goal::new().with("gold", Assert::gt_eq(5)).with("has_key", Assert::eq(true));
+    ///
+    /// // The key lies 4 steps away, while every mine visibly gets closer to the goal
+    /// let actions = [
+    ///     Action::new("mine")
+    ///         .with_effect(Effect::new().add_mutation(Mutation::increment("gold", 1))),
+    ///     Action::new("walk")
+    ///         .with_effect(Effect::new().add_mutation(Mutation::increment("position", 1))),
+    ///     Action::new("pick_up_key")
+    ///         .with_precondition(("position", Assert::eq(4)))
+    ///         .with_effect(Effect::new().add_mutation(Mutation::set("has_key", true))),
+    /// ];
+    /// let first_action = |plan: &Plan| plan.steps()[1].action_key().unwrap().to_string();
+    ///
+    /// let config = PlanningConfig::new();
+    /// let (plan, stats) = make_plan_with_config_and_stats(&config, &start, &actions, &goal);
+    /// assert_eq!(first_action(&plan.unwrap()), "mine");
+    ///
+    /// // Making the key matter more sends the search after it first
+    /// let weighted = config.with_key_weight("has_key", 10);
+    /// let (plan, weighted_stats) =
+    ///     make_plan_with_config_and_stats(&weighted, &start, &actions, &goal);
+    /// let plan = plan.unwrap();
+    /// assert_eq!((first_action(&plan), plan.cost()), ("walk".to_string(), 10));
+    /// assert!(weighted_stats.nodes_expanded < stats.nodes_expanded);
+    /// ```
+    pub fn with_key_weight(mut self, key: impl Into<String>, weight: u64) -> Self {
+        self.key_weights.insert(key.into(), weight);
+        self
+    }
+}
+
+/// Measurements of the effort spent by a search, returned by
+/// [`make_plan_with_stats`].
+///
+/// Comparing them between heuristics or [`PlanningConfig`] options shows how
+/// much work each variant saves.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct PlanStats {
+    /// The number of states whose successors were generated.
+    pub nodes_expanded: usize,
+    /// The number of successor states generated, including states that were
+    /// already reached through a cheaper path.
+    pub nodes_generated: usize,
+    /// The largest number of states waiting to be expanded at any point.
+    ///
+    /// With [`PlanningStrategy::IterativeDeepening`], this counts the states
+    /// on the current path as well, since they are all the search holds.
+    pub peak_frontier: usize,
+    /// The number of times the preconditions of an action were evaluated.
+    ///
+    /// The preconditions of an action are only evaluated again when the
+    /// previous action changed a key they read (see
+    /// [`ActionSet`](crate::prelude::ActionSet)).
+    pub precondition_checks: usize,
+    /// The wall-clock time spent planning.
+    ///
+    /// Measuring time requires the standard library, so this is always zero
+    /// without the `std` feature.
+    pub elapsed: Duration,
+}
+
+/// The cost of a path in the search: the plan cost, then the number of steps.
+///
+/// Ordering paths by both makes the search prefer the shorter of two plans
+/// with equal cost. Costs are added with saturation; a path whose cost reaches
+/// `usize::MAX` is considered too expensive to represent.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+struct SearchCost {
+    cost: usize,
+    steps: usize,
+}
+
+impl core::ops::Add for SearchCost {
+    type Output = SearchCost;
+
+    fn add(self, other: SearchCost) -> SearchCost {
+        SearchCost {
+            cost: self.cost.saturating_add(other.cost),
+            steps: self.steps.saturating_add(other.steps),
+        }
+    }
+}
+
+/// Scales a heuristic estimate by the weighted A* weight.
+fn weighted(estimate: usize, weight: f64) -> usize {
+    if weight == 1.0 { estimate } else { (estimate as f64 * weight) as usize }
 }
 
 /// Creates a plan using a specified planning strategy.
@@ -119,116 +1134,1714 @@ pub enum PlanningStrategy {
 ///
 /// # Arguments
 /// * `strategy` - Planning strategy to use
+/// * `heuristic_kind` - Heuristic guiding the search (see [`HeuristicKind`])
 /// * `start` - Initial world state
 /// * `actions` - Available actions that can be performed
 /// * `goal` - Desired goal state
 ///
 /// # Returns
-/// * `Some((path, total_cost))` if a plan is found, where `path` is a sequence
-///   of nodes from start to goal and `total_cost` is the sum of all action costs
+/// * `Some(plan)` if a plan is found, holding the nodes from start to goal
+///   and the sum of all action costs
 /// * `None` if no valid plan exists
 ///
+/// # Example
+/// ```rust
+/// use rust_goap::prelude::*;
+///
+/// let start = WorldState::new().set("distance", 100);
+/// let goal = Goal::new().with("distance", Assert::eq(0));
+///
+/// // Walking covers 50 units for a cost of 1, teleporting costs 5
+/// let walk = Action::new("walk")
+///     .with_precondition(("distance", Assert::gt_eq(50)))
+///     .with_effect(Effect::new().add_mutation(Mutation::decrement("distance", 50)));
+/// let teleport = Action::new("teleport")
+///     .with_effect(Effect::new().add_mutation(Mutation::set("distance", 0)).with_cost(5));
+/// let actions = [walk, teleport];
+///
+/// // The distance-based heuristic overestimates the cost of walking...
+/// let cost = make_plan_with_strategy(
+///     PlanningStrategy::StartToGoal,
+///     HeuristicKind::SumOfDistances,
+///     &start,
+///     &actions,
+///     &goal,
+/// )
+/// .unwrap()
+/// .cost();
+/// assert_eq!(cost, 5);
+///
+/// // ...while the admissible zero heuristic finds the true optimum
+/// let cost = make_plan_with_strategy(
+///     PlanningStrategy::StartToGoal,
+///     HeuristicKind::Zero,
+///     &start,
+///     &actions,
+///     &goal,
+/// )
+/// .unwrap()
+/// .cost();
+/// assert_eq!(cost, 2);
+/// ```
+///
 /// # See Also
 /// [`make_plan`] - Higher-level function that uses the default strategy
+/// [`make_plan_with_config`] - Planning with every search option available
 pub fn make_plan_with_strategy(
     strategy: PlanningStrategy,
+    heuristic_kind: HeuristicKind,
     start: &WorldState,
     actions: &[Action],
     goal: &Goal,
-) -> Option<(Vec<Node>, usize)> {
-    match strategy {
-        PlanningStrategy::StartToGoal => {
-            let start_node = Node::State(start.clone());
-            pathfinding::directed::astar::astar(
-                &start_node,
-                |node| successors(node, actions).collect::<Vec<_>>().into_iter(),
-                |node| heuristic(node, goal),
-                |node| is_goal(node, goal),
-            )
-        },
-    }
+) -> Option<Plan> {
+    let config = PlanningConfig::new().with_strategy(strategy).with_heuristic(heuristic_kind);
+    make_plan_with_config(&config, start, actions, goal)
 }
 
-/// Creates an optimal plan from start state to goal state.
+/// Creates a plan using the search options of a [`PlanningConfig`].
 ///
-/// This is the main planning function that finds the lowest-cost sequence
-/// of actions to transition from the initial world state to a state that
-/// satisfies all goal requirements.
-///
-/// Uses the A* pathfinding algorithm with custom heuristics to efficiently
-/// search through the space of possible action sequences.
+/// This is the most flexible planning function; [`make_plan`] and
+/// [`make_plan_with_strategy`] are shortcuts for common configurations.
 ///
 /// # Arguments
+/// * `config` - Search options to use
 /// * `start` - Initial world state
 /// * `actions` - Available actions that can be performed
-/// * `goal` - Desired goal state with requirements
+/// * `goal` - Desired goal state
 ///
 /// # Returns
-/// * `Some((path, total_cost))` if a plan is found
-/// * `None` if no valid plan exists
+/// * `Some(plan)` if a plan is found
+/// * `None` if no valid plan exists or the expansion budget ran out
 ///
 /// # Example
 /// ```rust
 /// use rust_goap::prelude::*;
 ///
-/// let start = WorldState::new().set("has_food", false).set("is_hungry", true);
-/// let goal = Goal::new().with("is_hungry", Assert::eq(false));
-/// let eat_action = Action {
-///     key: "eat".to_string(),
-///     preconditions: vec![("has_food".to_string(), Assert::eq(true))],
-///     effect: Some(Effect {
-///         mutations: vec![Mutation::set("is_hungry", false)],
-///         cost: 1,
-///     }),
-/// };
+/// let start = WorldState::new().set("x", 0).set("y", 0);
+/// let goal = Goal::new().with("x", Assert::eq(3)).with("y", Assert::eq(3));
 ///
-/// if let Some((plan, cost)) = make_plan(&start, &[eat_action], &goal) {
-///     println!("Found plan with cost: {}", cost);
-/// }
+/// let step = |mutation: Mutation| Effect::new().add_mutation(mutation).with_cost(2);
+/// let actions = [
+///     Action::new("right").with_effect(step(Mutation::increment("x", 1))),
+///     Action::new("up").with_effect(step(Mutation::increment("y", 1))),
+///     Action::new("left").with_effect(step(Mutation::decrement("x", 1))),
+///     Action::new("down").with_effect(step(Mutation::decrement("y", 1))),
+/// ];
+///
+/// // Regular A* needs more than 10 expansions to find the optimal plan
+/// let regular = PlanningConfig::new();
+/// let optimal_cost = make_plan_with_config(&regular, &start, &actions, &goal).unwrap().cost();
+/// assert_eq!(optimal_cost, 12);
+/// let budgeted = regular.with_max_expansions(10);
+/// assert!(make_plan_with_config(&budgeted, &start, &actions, &goal).is_none());
+///
+/// // Weighted A* expands fewer nodes and finds a plan within the same budget
+/// let greedy = PlanningConfig::new().with_weight(3.0).with_max_expansions(10);
+/// let cost = make_plan_with_config(&greedy, &start, &actions, &goal).unwrap().cost();
+/// assert!(cost <= 3 * optimal_cost);
 /// ```
-pub fn make_plan(
+pub fn make_plan_with_config(
+    config: &PlanningConfig,
     start: &WorldState,
     actions: &[Action],
     goal: &Goal,
-) -> Option<(Vec<Node>, usize)> {
-    // Default to using Start -> Goal planning
-    make_plan_with_strategy(PlanningStrategy::StartToGoal, start, actions, goal)
+) -> Option<Plan> {
+    let preconditions = Preconditions::new(actions, &|_| true).pruned(config, goal);
+    search(config, start, &preconditions, goal, &mut PlanStats::default(), |state| {
+        heuristic(state, goal, actions, config)
+    })
 }
 
-/// Extracts all effects from a plan, filtering out initial state nodes.
+/// Creates a plan using a caller-provided heuristic.
+///
+/// The closure replaces the built-in heuristics (see [`HeuristicKind`]) and
+/// estimates the remaining cost from a world state to the goal. This lets a
+/// domain with better knowledge, such as positions on a grid encoded in the
+/// state, guide the search more effectively than the generic sum of distances.
 ///
-/// Converts a plan (sequence of nodes) into an iterator over the actual
-/// actions and their effects. This is useful for executing the plan
-/// or analyzing the specific actions that need to be performed.
+/// The returned plan is only guaranteed to be the lowest-cost plan if the
+/// heuristic never overestimates the true remaining cost. A heuristic that
+/// overestimates forfeits optimality in exchange for (usually) faster search.
 ///
 /// # Arguments
-/// * `plan` - Plan containing both state and effect nodes
+/// * `start` - Initial world state
+/// * `actions` - Available actions that can be performed
+/// * `goal` - Desired goal state with requirements
+/// * `heuristic` - Estimates the remaining cost from a state to the goal
 ///
 /// # Returns
-/// Iterator over tuples of (action_key, effect, resulting_state)
+/// * `Some(plan)` if a plan is found
+/// * `None` if no valid plan exists
 ///
-/// # Note
-/// Initial state nodes (Node::State) are filtered out since they
-/// don't represent actions that need to be executed.
-pub fn get_effects_from_plan(
-    plan: impl IntoIterator<Item = Node>,
-) -> impl Iterator<Item = (String, Effect, WorldState)> {
-    plan.into_iter().filter_map(|node| match node {
-        Node::Effect((action_key, effect, state)) => Some((action_key, effect, state)),
-        Node::State(_) => None,
-    })
-}
-
-/// Formats a plan into a human-readable string for debugging or display.
+/// # Example
+/// ```rust
+/// use rust_goap::prelude::*;
 ///
-/// Creates a detailed textual representation of a plan showing:
-/// - Initial world state
-/// - Each action to execute with its mutations
-/// - Intermediate states after each action
-/// - Final state and total plan cost
+/// let start = WorldState::new().set("position", 0);
+/// let goal = Goal::new().with("position", Assert::gt_eq(100));
+///
+/// let actions = [
+///     Action::new("walk")
+///         .with_effect(Effect::new().add_mutation(Mutation::increment("position", 10))),
+///     Action::new("teleport").with_effect(
+///         Effect::new().add_mutation(Mutation::set("position", 100)).with_cost(50),
+///     ),
+/// ];
+///
+/// // The default heuristic overestimates and settles for the expensive teleport
+/// let plan = make_plan(&start, &actions, &goal).unwrap();
+/// assert_eq!(plan.cost(), 50);
+///
+/// // A heuristic of 0 turns the search into Dijkstra's algorithm, which is optimal
+/// let plan = make_plan_with_heuristic(&start, &actions, &goal, |_, _| 0).unwrap();
+/// assert_eq!(plan.cost(), 10);
+/// assert_eq!(plan.len(), 10);
+///
+/// // A domain heuristic: every walk covers at most 10 units for a cost of 1
+/// let remaining_walks = |state: &WorldState, _: &Goal| match state.get("position") {
+///     Some(Value::I64(position)) => ((100 - position).max(0) as usize).div_ceil(10),
+///     _ => 0,
+/// };
+/// let cost = make_plan_with_heuristic(&start, &actions, &goal, remaining_walks).unwrap().cost();
+/// assert_eq!(cost, 10);
+/// ```
+pub fn make_plan_with_heuristic(
+    start: &WorldState,
+    actions: &[Action],
+    goal: &Goal,
+    heuristic: impl Fn(&WorldState, &Goal) -> usize,
+) -> Option<Plan> {
+    let config = PlanningConfig::new();
+    let preconditions = Preconditions::new(actions, &|_| true);
+    search(&config, start, &preconditions, goal, &mut PlanStats::default(), |state| {
+        heuristic(state, goal)
+    })
+}
+
+/// Creates a plan using only the actions accepted by a predicate.
+///
+/// This temporarily disables actions, for example during certain game phases,
+/// without rebuilding the action list. Apart from the filter, planning works
+/// like [`make_plan`].
+///
+/// # Arguments
+/// * `start` - Initial world state
+/// * `actions` - Available actions that can be performed
+/// * `goal` - Desired goal state with requirements
+/// * `allow` - Returns whether an action may be part of the plan
+///
+/// # Returns
+/// * `Some(plan)` if a plan is found
+/// * `None` if no valid plan exists with the allowed actions
+///
+/// # Example
+/// ```rust
+/// use rust_goap::prelude::*;
+///
+/// let start = WorldState::new().set("gold", 0).set("in_town", true);
+/// let goal = Goal::new().with("gold", Assert::gt_eq(10));
+///
+/// let actions = [
+///     Action::new("rob_merchant")
+///         .with_effect(Effect::new().add_mutation(Mutation::increment("gold", 10))),
+///     Action::new("work_at_forge").with_effect(
+///         Effect::new().add_mutation(Mutation::increment("gold", 10)).with_cost(5),
+///     ),
+/// ];
+///
+/// let plan = make_plan(&start, &actions, &goal).unwrap();
+/// assert_eq!(plan.steps()[1].action_key(), Some("rob_merchant"));
+///
+/// // No violence in town
+/// let peaceful = |action: &Action| !action.key.starts_with("rob");
+/// let plan = make_plan_filtered(&start, &actions, &goal, peaceful).unwrap();
+/// assert_eq!(plan.steps()[1].action_key(), Some("work_at_forge"));
+/// assert_eq!(plan.cost(), 5);
+///
+/// assert!(make_plan_filtered(&start, &actions, &goal, |_| false).is_none());
+/// ```
+pub fn make_plan_filtered(
+    start: &WorldState,
+    actions: &[Action],
+    goal: &Goal,
+    allow: impl Fn(&Action) -> bool,
+) -> Option<Plan> {
+    let config = PlanningConfig::new();
+    let preconditions = Preconditions::new(actions, &allow);
+    search(&config, start, &preconditions, goal, &mut PlanStats::default(), |state| {
+        heuristic(state, goal, actions, &config)
+    })
+}
+
+/// Creates a plan like [`make_plan`] and reports the effort the search took.
+///
+/// This is meant for tuning: comparing the [`PlanStats`] of different
+/// heuristics or domain models shows which one explores fewer states.
+///
+/// # Arguments
+/// * `start` - Initial world state
+/// * `actions` - Available actions that can be performed
+/// * `goal` - Desired goal state with requirements
+///
+/// # Returns
+/// The result of [`make_plan`], along with the statistics of the search
+///
+/// # Example
+/// ```rust
+/// use rust_goap::prelude::*;
+///
+/// let start = WorldState::new().set("wood", 0);
+/// let chop = Action::new("chop")
+///     .with_effect(Effect::new().add_mutation(Mutation::increment("wood", 1)));
+///
+/// let goal = Goal::new().with("wood", Assert::eq(3));
+/// let (plan, stats) = make_plan_with_stats(&start, &[chop.clone()], &goal);
+/// assert_eq!(plan.unwrap().cost(), 3);
+/// assert_eq!(stats.nodes_expanded, 3);
+/// assert_eq!(stats.nodes_generated, 3);
+/// assert_eq!(stats.peak_frontier, 1);
+///
+/// // Nothing is expanded when the start state already satisfies the goal
+/// let goal = Goal::new().with("wood", Assert::eq(0));
+/// let (plan, stats) = make_plan_with_stats(&start, &[chop], &goal);
+/// assert_eq!(plan.unwrap().cost(), 0);
+/// assert_eq!(stats.nodes_expanded, 0);
+/// ```
+pub fn make_plan_with_stats(
+    start: &WorldState,
+    actions: &[Action],
+    goal: &Goal,
+) -> (Option<Plan>, PlanStats) {
+    make_plan_with_config_and_stats(&PlanningConfig::new(), start, actions, goal)
+}
+
+/// Creates a plan with custom search options, measuring the search like
+/// [`make_plan_with_stats`].
+///
+/// # Arguments
+/// * `config` - The search options to use
+/// * `start` - Initial world state
+/// * `actions` - Available actions that can be performed
+/// * `goal` - Desired goal state with requirements
+///
+/// # Returns
+/// The result of [`make_plan_with_config`], along with the statistics of the search
+///
+/// # Example
+/// ```rust
+/// use rust_goap::prelude::*;
+///
+/// let start = WorldState::new().set("wood", 0);
+/// let chop = Action::new("chop")
+///     .with_effect(Effect::new().add_mutation(Mutation::increment("wood", 1)));
+/// let goal = Goal::new().with("wood", Assert::eq(3));
+///
+/// let config = PlanningConfig::new().with_max_expansions(2);
+/// let (plan, stats) = make_plan_with_config_and_stats(&config, &start, &[chop], &goal);
+/// assert!(plan.is_none());
+/// assert_eq!(stats.nodes_expanded, 2);
+/// ```
+pub fn make_plan_with_config_and_stats(
+    config: &PlanningConfig,
+    start: &WorldState,
+    actions: &[Action],
+    goal: &Goal,
+) -> (Option<Plan>, PlanStats) {
+    #[cfg(feature = "std")]
+    let started = std::time::Instant::now();
+
+    let mut stats = PlanStats::default();
+    let preconditions = Preconditions::new(actions, &|_| true).pruned(config, goal);
+    let plan = search(config, start, &preconditions, goal, &mut stats, |state| {
+        heuristic(state, goal, actions, config)
+    });
+
+    #[cfg(feature = "std")]
+    {
+        stats.elapsed = started.elapsed();
+    }
+    (plan, stats)
+}
+
+/// Heuristic weights tried by [`make_plan_anytime`], from greediest to regular A*.
+const ANYTIME_WEIGHTS: [f64; 4] = [8.0, 4.0, 2.0, 1.0];
+
+/// Creates a plan quickly, then keeps looking for cheaper ones.
+///
+/// The search is run repeatedly with a decreasing heuristic weight (see
+/// [`PlanningConfig::weight`]): the first, greedy rounds find a feasible plan
+/// after few expansions, and the last round is the regular search of
+/// [`make_plan`]. Whenever a round finds a plan cheaper than every plan found
+/// before, `on_improved` is called with it, so an agent can start acting on an
+/// early plan and switch once a better one arrives.
+///
+/// Since every round is a complete search, finding the final plan takes longer
+/// than calling [`make_plan`] directly.
+///
+/// # Arguments
+/// * `start` - Initial world state
+/// * `actions` - Available actions that can be performed
+/// * `goal` - Desired goal state with requirements
+/// * `on_improved` - Called with each improved plan
+///
+/// # Returns
+/// * `Some(plan)` with the cheapest plan found
+/// * `None` if no valid plan exists
+///
+/// # Example
+/// ```rust
+/// use rust_goap::prelude::*;
+///
+/// let start = WorldState::new().set("distance", 10);
+/// let goal = Goal::new().with("distance", Assert::eq(0));
+///
+/// let actions = [
+///     Action::new("walk")
+///         .with_effect(Effect::new().add_mutation(Mutation::decrement("distance", 1))),
+///     Action::new("take_taxi").with_effect(
+///         Effect::new().add_mutation(Mutation::decrement("distance", 10)).with_cost(20),
+///     ),
+/// ];
+///
+/// let mut costs = Vec::new();
+/// let plan = make_plan_anytime(&start, &actions, &goal, |plan| costs.push(plan.cost())).unwrap();
+///
+/// // The greedy first round takes the taxi, later rounds find that walking is cheaper
+/// assert_eq!(costs, [20, 10]);
+/// assert!(costs.windows(2).all(|pair| pair[1] < pair[0]));
+/// assert_eq!(plan.cost(), 10);
+/// assert!(plan.effects().all(|(key, _, _)| key == "walk"));
+/// ```
+pub fn make_plan_anytime(
+    start: &WorldState,
+    actions: &[Action],
+    goal: &Goal,
+    mut on_improved: impl FnMut(&Plan),
+) -> Option<Plan> {
+    let mut best: Option<Plan> = None;
+    for weight in ANYTIME_WEIGHTS {
+        let config = PlanningConfig::new().with_weight(weight);
+        let Some(plan) = make_plan_with_config(&config, start, actions, goal) else {
+            // A round without a plan proves there is none, as weights don't prune states
+            return None;
+        };
+        if best.as_ref().is_none_or(|best| plan.cost() < best.cost()) {
+            on_improved(&plan);
+            best = Some(plan);
+        }
+    }
+    best
+}
+
+/// Logs the expansion of a search node at trace level.
+///
+/// # Arguments
+/// * `node` - The node being expanded
+/// * `actions` - The actions the node's action index refers to
+/// * `cost` - The cost of the path to the node (g)
+/// * `estimate` - Computes the heuristic estimate for the node (h), only
+///   called if trace logging is enabled
+#[cfg(feature = "log")]
+fn trace_expansion(
+    node: &SearchNode,
+    actions: &[Action],
+    cost: usize,
+    estimate: impl FnOnce() -> usize,
+) {
+    if !log::log_enabled!(log::Level::Trace) {
+        return;
+    }
+    let (state, estimate) = (&node.state, estimate());
+    match node.action {
+        Some(index) => {
+            let key = &actions[index].key;
+            log::trace!("expanding after action {key:?} (g = {cost}, h = {estimate}): {state}");
+        },
+        None => log::trace!("expanding initial state (g = {cost}, h = {estimate}): {state}"),
+    }
+}
+
+/// Does nothing, since logging is disabled.
+#[cfg(not(feature = "log"))]
+fn trace_expansion(
+    _node: &SearchNode,
+    _actions: &[Action],
+    _cost: usize,
+    _estimate: impl FnOnce() -> usize,
+) {
+}
+
+/// Logs the outcome of a search at debug level.
+///
+/// # Arguments
+/// * `plan` - The number of nodes and total cost of the plan found, if any
+/// * `expansions` - The number of nodes expanded by the search
+#[cfg(feature = "log")]
+fn debug_completion(plan: Option<(usize, usize)>, expansions: &Cell<usize>) {
+    match plan {
+        Some((nodes, cost)) => log::debug!(
+            "found a plan with {nodes} nodes and total cost {cost} after {} expansions",
+            expansions.get()
+        ),
+        None => log::debug!("found no plan after {} expansions", expansions.get()),
+    }
+}
+
+/// Does nothing, since logging is disabled.
+#[cfg(not(feature = "log"))]
+fn debug_completion(_plan: Option<(usize, usize)>, _expansions: &Cell<usize>) {}
+
+/// Checks that every goal requirement could possibly be satisfied.
+///
+/// A requirement on a key that is missing from `start` and that no allowed
+/// action ever writes can never hold (unless it is [`Assert::Absent`](crate::prelude::Assert::Absent)), so
+/// the search would explore every reachable state in vain.
+///
+/// # Returns
+/// `true` if each required key is present in `start` or written by a mutation
+/// of some allowed action, `false` otherwise
+fn goal_keys_reachable(
+    start: &WorldState,
+    actions: &[Action],
+    goal: &Goal,
+    allow: &dyn Fn(&Action) -> bool,
+) -> bool {
+    goal.requirements.iter().all(|(key, assert)| {
+        assert.holds_when_missing()
+            || start.contains_key(key)
+            || actions.iter().filter(|action| allow(action)).any(|action| {
+                action
+                    .effects()
+                    .flat_map(|effect| &effect.mutations)
+                    .any(|mutation| mutation_keys(mutation).1 == key)
+            })
+    })
+}
+
+/// Runs the search selected by `config`, guided by the given heuristic.
+///
+/// The configured [`HeuristicKind`] is ignored in favor of `estimate`, while
+/// the heuristic weight still applies. Only the actions of `preconditions`
+/// it allows are used. Goals with a requirement no action can satisfy fail
+/// without searching. The effort of the search is recorded in `stats`. When
+/// `preconditions` reports misconfigured actions, the search stops at the
+/// first one.
+fn search(
+    config: &PlanningConfig,
+    start: &WorldState,
+    preconditions: &Preconditions,
+    goal: &Goal,
+    stats: &mut PlanStats,
+    estimate: impl Fn(&WorldState) -> usize,
+) -> Option<Plan> {
+    let (actions, allow) = (preconditions.actions, preconditions.allow);
+    if !goal_keys_reachable(start, actions, goal, allow) {
+        return None;
+    }
+
+    let expansions = Cell::new(0_usize);
+    let generated = Cell::new(0_usize);
+
+    // Counts an expansion, unless the budget is used up or a misconfigured
+    // action was found, which exhausts the search
+    let expand = || {
+        if config.max_expansions.is_some_and(|max| expansions.get() >= max)
+            || preconditions.error.borrow().is_some()
+        {
+            return false;
+        }
+        expansions.set(expansions.get() + 1);
+        true
+    };
+    let start_node = SearchNode::new(start.clone());
+    let heuristic = |state: &WorldState| match config.strategy {
+        PlanningStrategy::Dijkstra => 0,
+        PlanningStrategy::StartToGoal
+        | PlanningStrategy::Bidirectional
+        | PlanningStrategy::IterativeDeepening => weighted(estimate(state), config.weight),
+    };
+
+    let path = match config.strategy {
+        PlanningStrategy::Bidirectional
+            if config.max_steps.is_none()
+                && config.beam_width.is_none()
+                && actions.iter().filter(|action| allow(action)).all(is_regressable) =>
+        {
+            bidirectional(
+                &start_node,
+                goal,
+                |node| {
+                    if !expand() {
+                        return Vec::new();
+                    }
+                    let successors = successors(node, preconditions, None, config.validate_effects)
+                        .map(|(successor, cost)| (successor, SearchCost { cost, steps: 1 }))
+                        .collect::<Vec<_>>();
+                    generated.set(generated.get() + successors.len());
+                    successors
+                },
+                |goal| {
+                    if !expand() {
+                        return Vec::new();
+                    }
+                    let predecessors = actions
+                        .iter()
+                        .filter(|action| allow(action) && action.is_relevant_to(goal))
+                        .flat_map(|action| {
+                            action.effects().filter_map(move |effect| {
+                                let cost = SearchCost { cost: effect.fixed_cost(), steps: 1 };
+                                Some((regress(goal, action, effect)?, cost))
+                            })
+                        })
+                        .collect::<Vec<_>>();
+                    generated.set(generated.get() + predecessors.len());
+                    predecessors
+                },
+                |node, goal| is_goal(&node.state, goal),
+                |node, goals| follow_regressions(node, goals, preconditions, config),
+                &mut stats.peak_frontier,
+            )
+        },
+        PlanningStrategy::StartToGoal
+        | PlanningStrategy::Bidirectional
+        | PlanningStrategy::Dijkstra => astar(
+            &start_node,
+            |node, cost: SearchCost| {
+                if !expand() {
+                    return Vec::new().into_iter();
+                }
+                trace_expansion(node, actions, cost.cost, || heuristic(&node.state));
+                let successors =
+                    successors(node, preconditions, config.max_steps, config.validate_effects)
+                        .map(|(successor, cost)| (successor, SearchCost { cost, steps: 1 }))
+                        // Drop paths whose cost would overflow
+                        .filter(|(_, move_cost)| (cost + *move_cost).cost < usize::MAX)
+                        .collect::<Vec<_>>();
+                generated.set(generated.get() + successors.len());
+                successors.into_iter()
+            },
+            |node| SearchCost {
+                cost: heuristic(&node.state),
+                // Estimating no remaining steps keeps the tie-break exact
+                steps: 0,
+            },
+            |node| is_goal(&node.state, goal),
+            config.beam_width,
+            &mut stats.peak_frontier,
+        ),
+        PlanningStrategy::IterativeDeepening => ida_star(
+            &start_node,
+            |node, cost: SearchCost| {
+                if !expand() {
+                    return Vec::new();
+                }
+                trace_expansion(node, actions, cost.cost, || heuristic(&node.state));
+                let successors =
+                    successors(node, preconditions, config.max_steps, config.validate_effects)
+                        .map(|(successor, cost)| (successor, SearchCost { cost, steps: 1 }))
+                        .filter(|(_, move_cost)| (cost + *move_cost).cost < usize::MAX)
+                        .collect::<Vec<_>>();
+                generated.set(generated.get() + successors.len());
+                successors
+            },
+            |node| SearchCost { cost: heuristic(&node.state), steps: 0 },
+            |node| is_goal(&node.state, goal),
+            |visited, node| visited.state == node.state,
+            &mut stats.peak_frontier,
+        ),
+    };
+
+    stats.nodes_expanded = expansions.get();
+    stats.nodes_generated = generated.get();
+    stats.precondition_checks = preconditions.checks.get();
+    // Bidirectional paths aren't pruned while searching, so reject saturated costs here
+    let path = path.filter(|(_, cost)| cost.cost < usize::MAX);
+    debug_completion(path.as_ref().map(|(path, cost)| (path.len(), cost.cost)), &expansions);
+    let (path, cost) = path?;
+    Some(Plan::new(
+        path.into_iter().map(|node| node.into_node(actions)).collect(),
+        cost.cost,
+    ))
+}
+
+/// Follows a chain of regressed goals forward from a search node.
+///
+/// Each step applies the cheapest action outcome leading to a state that
+/// satisfies the next goal of the chain.
+///
+/// # Arguments
+/// * `node` - The search node satisfying the first goal of the chain
+/// * `goals` - The regressed goals, ending with the planning goal
+/// * `preconditions` - The available actions, and the actions allowed at all
+/// * `config` - The search options
+///
+/// # Returns
+/// The search nodes following `node` and the cost of reaching them, or `None`
+/// if some goal of the chain can't be reached
+fn follow_regressions(
+    node: &SearchNode,
+    goals: &[Goal],
+    preconditions: &Preconditions,
+    config: &PlanningConfig,
+) -> Option<(Vec<SearchNode>, SearchCost)> {
+    let mut path: Vec<SearchNode> = Vec::new();
+    let mut total_cost = SearchCost::default();
+    for goal in &goals[1..] {
+        let current = path.last().unwrap_or(node);
+        let (next, cost) = successors(current, preconditions, None, config.validate_effects)
+            .filter(|(successor, _)| is_goal(&successor.state, goal))
+            .min_by_key(|(_, cost)| *cost)?;
+        total_cost = total_cost + SearchCost { cost, steps: 1 };
+        path.push(next);
+    }
+    Some((path, total_cost))
+}
+
+/// Creates an optimal plan from start state to goal state.
+///
+/// This is the main planning function that finds the lowest-cost sequence
+/// of actions to transition from the initial world state to a state that
+/// satisfies all goal requirements.
+///
+/// Uses the A* pathfinding algorithm with custom heuristics to efficiently
+/// search through the space of possible action sequences.
+///
+/// # Tie-Breaking
+/// When several plans have the same total cost, the one with the fewest
+/// actions is returned. This applies to every planning function based on the
+/// A* search, such as [`make_plan_with_config`].
+///
+/// # Determinism
+/// Planning is deterministic: identical inputs always give the identical
+/// plan. Among plans of equal cost and length, the choice only depends on the
+/// action keys, not on the order of `actions`, since the actions applicable in
+/// a state are always explored in key order.
+///
+/// ```rust
+/// use rust_goap::prelude::*;
+///
+/// // Three chores, done in any order, all lead to the same clean house
+/// let start = WorldState::new().set("dishes", false).set("laundry", false).set("floor", false);
+/// let goal = Goal::new()
+///     .with("dishes", Assert::eq(true))
+///     .with("laundry", Assert::eq(true))
+///     .with("floor", Assert::eq(true));
+/// let chore = |key: &str| {
+///     Action::new(format!("do_{key}"))
+///         .with_effect(Effect::new().add_mutation(Mutation::set(key, true)))
+/// };
+/// let actions = vec![chore("dishes"), chore("laundry"), chore("floor")];
+///
+/// let keys = |plan: Plan| plan.effects().map(|(key, _, _)| key.to_string()).collect::<Vec<_>>();
+/// let first = keys(make_plan(&start, &actions, &goal).unwrap());
+/// for _ in 0..20 {
+///     assert_eq!(keys(make_plan(&start, &actions, &goal).unwrap()), first);
+/// }
+///
+/// // Reordering the actions doesn't change the plan either
+/// let reversed: Vec<Action> = actions.iter().rev().cloned().collect();
+/// assert_eq!(keys(make_plan(&start, &reversed, &goal).unwrap()), first);
+/// ```
+///
+/// # Satisfied Goals
+/// If `start` already satisfies `goal`, the plan consisting of the start state
+/// alone is returned at a cost of 0 without setting up a search, which keeps
+/// replanning cheap for idle agents.
+///
+/// # Unreachable Goals
+/// Before searching, the goal requirements are checked against the start state
+/// and the actions: if a required key is missing from `start` and no action
+/// mutates it, `None` is returned right away instead of exploring every
+/// reachable state first.
+///
+/// # Cost Overflow
+/// Plan costs are `usize`, so the most expensive plan that can be returned
+/// costs `usize::MAX - 1`. Costs are added with saturation and paths whose cost
+/// would reach `usize::MAX` are dropped from the search, so huge action costs
+/// can never wrap around and make an expensive plan look cheap. If every plan
+/// is too expensive to represent, `None` is returned.
+///
+/// # Logging
+/// With the `log` feature, the A* searches log every node expansion at trace
+/// level (the action that led to the node, its path cost `g`, its heuristic
+/// estimate `h`, and its state) and the outcome at debug level (the number of
+/// plan nodes, the total cost, and the number of expansions). Actions that
+/// don't change the state they are applied to, which are likely configuration
+/// mistakes (see [`Action::is_noop_in`]), are also reported at debug level.
+/// Without the feature, no logging code is compiled in.
+///
+#[cfg_attr(feature = "log", doc = "```rust")]
+#[cfg_attr(not(feature = "log"), doc = "```rust,ignore")]
+/// use std::sync::Mutex;
+/// use rust_goap::prelude::*;
+///
+/// // A logger that keeps every message
+/// struct CapturingLogger(Mutex<Vec<(log::Level, String)>>);
+///
+/// impl log::Log for CapturingLogger {
+///     fn enabled(&self, _: &log::Metadata) -> bool {
+///         true
+///     }
+///
+///     fn log(&self, record: &log::Record) {
+///         self.0.lock().unwrap().push((record.level(), record.args().to_string()));
+///     }
+///
+///     fn flush(&self) {}
+/// }
+///
+/// static LOGGER: CapturingLogger = CapturingLogger(Mutex::new(Vec::new()));
+/// log::set_logger(&LOGGER).unwrap();
+/// log::set_max_level(log::LevelFilter::Trace);
+///
+/// let start = WorldState::new().set("wood", 0);
+/// let goal = Goal::new().with("wood", Assert::eq(2));
+/// let chop = Action::new("chop")
+///     .with_effect(Effect::new().add_mutation(Mutation::increment("wood", 1)));
+/// make_plan(&start, &[chop], &goal).unwrap();
+///
+/// let messages = LOGGER.0.lock().unwrap();
+/// let expansions: Vec<&String> = messages
+///     .iter()
+///     .filter(|(level, _)| *level == log::Level::Trace)
+///     .map(|(_, message)| message)
+///     .collect();
+/// assert_eq!(expansions.len(), 2);
+/// assert!(expansions[0].starts_with("expanding initial state (g = 0, h = 2)"));
+/// assert!(expansions[1].starts_with("expanding after action \"chop\" (g = 1, h = 1)"));
+///
+/// let completion = "found a plan with 3 nodes and total cost 2 after 2 expansions";
+/// assert!(messages.contains(&(log::Level::Debug, completion.to_string())));
+/// ```
+///
+/// # Arguments
+/// * `start` - Initial world state
+/// * `actions` - Available actions that can be performed
+/// * `goal` - Desired goal state with requirements
+///
+/// # Returns
+/// * `Some(plan)` with the path and its total cost if a plan is found
+/// * `None` if no valid plan exists
+///
+/// # Example
+/// ```rust
+/// use rust_goap::prelude::*;
+///
+/// let start = WorldState::new().set("has_food", false).set("is_hungry", true);
+/// let goal = Goal::new().with("is_hungry", Assert::eq(false));
+/// let eat_action = Action {
+///     key: "eat".to_string(),
+///     preconditions: vec![("has_food".to_string(), Assert::eq(true))],
+///     effect: Some(Effect {
+///         mutations: vec![Mutation::set("is_hungry", false)],
+///         cost: 1,
+///         ..Default::default()
+///     }),
+///     ..Default::default()
+/// };
+///
+/// if let Some(plan) = make_plan(&start, &[eat_action], &goal) {
+///     println!("Found plan with cost: {}", plan.cost());
+/// }
+/// ```
+///
+/// Equal-cost plans are broken by length:
+/// ```rust
+/// use rust_goap::prelude::*;
+///
+/// let start = WorldState::new().set("prepared", false).set("done", false).set("looked", false);
+/// let goal = Goal::new().with("done", Assert::eq(true));
+///
+/// let set = |key: &str| Effect::new().add_mutation(Mutation::set(key, true));
+/// let actions = [
+///     Action::new("prepare").with_effect(set("prepared")),
+///     Action::new("finish").with_precondition(("prepared", Assert::eq(true))).with_effect(set("done")),
+///     Action::new("finish_directly").with_effect(set("done").with_cost(2)),
+///     Action::new("look_around").with_effect(set("looked")),
+/// ];
+///
+/// // "prepare" then "finish" costs as much as "finish_directly", which is shorter
+/// for heuristic in [HeuristicKind::Zero, HeuristicKind::SumOfDistances] {
+///     let config = PlanningConfig::new().with_heuristic(heuristic);
+///     let plan = make_plan_with_config(&config, &start, &actions, &goal).unwrap();
+///     assert_eq!(plan.cost(), 2);
+///     assert_eq!(plan.len(), 1);
+///     assert_eq!(plan.steps()[1].action_key(), Some("finish_directly"));
+/// }
+/// ```
+///
+/// Goals on keys that nothing can set fail fast, even when the actions could
+/// otherwise be applied forever:
+/// ```rust
+/// use rust_goap::prelude::*;
+///
+/// let start = WorldState::new().set("steps_taken", 0);
+/// let walk = Action::new("walk")
+///     .with_effect(Effect::new().add_mutation(Mutation::increment("steps_taken", 1)));
+///
+/// // No action sets "treasure_found", so the endless walking is never explored
+/// let goal = Goal::new().with("treasure_found", Assert::eq(true));
+/// assert!(make_plan(&start, &[walk], &goal).is_none());
+/// ```
+///
+/// Goals that already hold need no actions:
+/// ```rust
+/// use rust_goap::prelude::*;
+///
+/// let start = WorldState::new().set("door_open", true);
+/// let goal = Goal::new().with("door_open", Assert::eq(true));
+///
+/// // Checking this action's precondition would panic on the missing "door_code"
+/// // key with the `strict` feature, so the start state is never expanded
+/// let unlock = Action::new("unlock")
+///     .with_precondition(("door_code", Assert::eq(1234)))
+///     .with_effect(Effect::new().add_mutation(Mutation::set("door_open", true)));
+///
+/// let plan = make_plan(&start, &[unlock], &goal).unwrap();
+/// assert_eq!(plan.cost(), 0);
+/// assert!(plan.is_empty());
+/// assert!(plan.steps()[0].is_initial());
+/// assert_eq!(plan.steps()[0].state(), &start);
+/// ```
+///
+/// Effects that mix value types are skipped instead of panicking:
+/// ```rust
+/// use rust_goap::prelude::*;
+///
+/// let start = WorldState::new().set("gold", 0);
+/// let goal = Goal::new().with("gold", Assert::gt_eq(3));
+///
+/// // Misconfigured: "gold" holds an integer
+/// let mine = Action::new("mine")
+///     .with_effect(Effect::new().add_mutation(Mutation::increment("gold", 1.5)));
+/// assert!(make_plan(&start, &[mine.clone()], &goal).is_none());
+///
+/// // Other actions are still used
+/// let trade = Action::new("trade")
+///     .with_effect(Effect::new().add_mutation(Mutation::increment("gold", 3)).with_cost(5));
+/// let plan = make_plan(&start, &[mine, trade], &goal).unwrap();
+/// assert_eq!(plan.cost(), 5);
+/// ```
+///
+/// Plans too expensive to represent are never returned:
+/// ```rust
+/// use rust_goap::prelude::*;
+///
+/// let start = WorldState::new().set("stage", 0);
+/// let goal = Goal::new().with("stage", Assert::eq(2));
+/// let advance = |cost: usize| {
+///     Effect::new().add_mutation(Mutation::increment("stage", 1)).with_cost(cost)
+/// };
+///
+/// // Two steps of this action cost more than `usize::MAX`, which would wrap
+/// // around to 2 without saturation
+/// let leap = Action::new("leap").with_effect(advance(usize::MAX / 2 + 2));
+/// assert!(make_plan(&start, &[leap.clone()], &goal).is_none());
+///
+/// // A cheaper way is preferred over the overflowing one
+/// let step = Action::new("step").with_effect(advance(10));
+/// let plan = make_plan(&start, &[leap, step], &goal).unwrap();
+/// assert_eq!(plan.cost(), 20);
+/// assert!(plan.effects().all(|(key, _, _)| key == "step"));
+/// ```
+pub fn make_plan(start: &WorldState, actions: &[Action], goal: &Goal) -> Option<Plan> {
+    if goal.is_satisfied_by(start) {
+        return Some(Plan::new(vec![Node::State(start.clone())], 0));
+    }
+
+    // Default to using Start -> Goal planning
+    make_plan_with_strategy(
+        PlanningStrategy::StartToGoal,
+        HeuristicKind::default(),
+        start,
+        actions,
+        goal,
+    )
+}
+
+/// Creates an optimal plan like [`make_plan`], reporting misconfigured actions
+/// instead of skipping them.
+///
+/// The other planning functions treat a precondition on a key missing from
+/// the state as not holding, and skip effects that mix value types or
+/// overflow (see [`PlanningConfig::validate_effects`]). Both usually point to a mistake in
+/// the domain, such as a misspelled key or a key set to the wrong type, so
+/// this stops at the first one the search runs into and returns it as an
+/// error. Checking every precondition for missing keys costs some speed, so
+/// this is best used while developing a domain.
+///
+/// # Arguments
+/// * `start` - Initial world state
+/// * `actions` - Available actions that can be performed
+/// * `goal` - Desired goal state with requirements
+///
+/// # Returns
+/// * `Ok(plan)` with the same plan as [`make_plan`]
+/// * `Err(PlanError::MissingKey { .. })` if a precondition reads a key
+///   missing from a reached state
+/// * `Err(PlanError::InvalidEffect { .. })` if an effect can't be applied to a
+///   reached state
+/// * `Err(PlanError::NoPlan)` if the goal can't be reached
+///
+/// # Example
+/// ```
+/// use rust_goap::prelude::*;
+///
+/// let start = WorldState::new().set("hungry", true).set("food", 0);
+/// let goal = Goal::new().with("hungry", Assert::eq(false));
+/// let cook = Action::new("cook")
+///     .with_effect(Effect::new().add_mutation(Mutation::increment("food", 1)));
+/// let eat = Action::new("eat")
+///     .with_precondition(("food", Assert::gt(0)))
+///     .with_effect(Effect::new().add_mutation(Mutation::set("hungry", false)));
+///
+/// let plan = try_make_plan(&start, &[cook.clone(), eat.clone()], &goal).unwrap();
+/// assert_eq!(plan.len(), 2);
+///
+/// // A misspelled key in a precondition
+/// let eat_typo = eat.clone().with_precondition(("fod", Assert::gt(0)));
+/// assert_eq!(
+///     try_make_plan(&start, &[cook.clone(), eat_typo], &goal),
+///     Err(PlanError::MissingKey { action: "eat".to_string(), key: "fod".to_string() })
+/// );
+///
+/// // An effect incrementing a boolean, which can't be applied
+/// let burn = Action::new("burn")
+///     .with_effect(Effect::new().add_mutation(Mutation::increment("hungry", 1)));
+/// let error = try_make_plan(&start, &[burn, cook, eat.clone()], &goal).unwrap_err();
+/// assert!(matches!(error, PlanError::InvalidEffect { action, .. } if action == "burn"));
+///
+/// // An effect overflowing an integer, which the other planners skip
+/// let vault = WorldState::new().set("gold", i64::MAX - 1);
+/// let broke = Goal::new().with("gold", Assert::eq(0));
+/// let mint = Action::new("mint")
+///     .with_effect(Effect::new().add_mutation(Mutation::increment("gold", 1)));
+/// assert_eq!(
+///     try_make_plan(&vault, &[mint.clone()], &broke),
+///     Err(PlanError::InvalidEffect {
+///         action: "mint".to_string(),
+///         error: EffectError::Overflow { key: "gold".to_string() },
+///     })
+/// );
+/// assert!(make_plan(&vault, &[mint], &broke).is_none());
+///
+/// // A goal that can't be reached
+/// assert_eq!(try_make_plan(&start, &[eat], &goal), Err(PlanError::NoPlan));
+/// ```
+pub fn try_make_plan(
+    start: &WorldState,
+    actions: &[Action],
+    goal: &Goal,
+) -> Result<Plan, PlanError> {
+    if goal.is_satisfied_by(start) {
+        return Ok(Plan::new(vec![Node::State(start.clone())], 0));
+    }
+
+    let config = PlanningConfig::new();
+    let preconditions = Preconditions::new(actions, &|_| true).reporting();
+    let plan = search(&config, start, &preconditions, goal, &mut PlanStats::default(), |state| {
+        heuristic(state, goal, actions, &config)
+    });
+    match preconditions.error.take() {
+        Some(error) => Err(error),
+        None => plan.ok_or(PlanError::NoPlan),
+    }
+}
+
+/// Creates a plan for the most important reachable goal out of several goals.
+///
+/// Each goal is planned for independently using [`make_plan`]. Among the goals
+/// that can be reached, the winner is chosen with the following ranking rule:
+///
+/// 1. The goal with the highest [`Goal::priority`] wins, regardless of plan cost.
+/// 2. Among goals with equal priority, the goal with the lowest plan cost wins.
+/// 3. If both priority and cost are equal, the goal that appears first in
+///    `goals` wins.
+///
+/// Unreachable goals are skipped. Since the default priority is 0, goals that
+/// never set a priority are simply ranked by cost.
+///
+/// # Arguments
+/// * `start` - Initial world state
+/// * `actions` - Available actions that can be performed
+/// * `goals` - Candidate goals to choose between
+///
+/// # Returns
+/// * `Some((goal_index, plan))` for the winning goal, where
+///   `goal_index` is its position in `goals`
+/// * `None` if none of the goals can be reached
+///
+/// # Example
+/// ```rust
+/// use rust_goap::prelude::*;
+///
+/// let start = WorldState::new().set("has_snack", false).set("is_safe", false);
+///
+/// let grab_snack = Action::new("grab_snack")
+///     .with_effect(Effect::new().add_mutation(Mutation::set("has_snack", true)));
+/// let run_to_shelter = Action::new("run_to_shelter")
+///     .with_effect(Effect::new().add_mutation(Mutation::set("is_safe", true)).with_cost(10));
+///
+/// let snack = Goal::new().with("has_snack", Assert::eq(true));
+/// let safety = Goal::new().with("is_safe", Assert::eq(true)).with_priority(5);
+///
+/// // Without priorities the cheapest goal wins
+/// let (index, plan) =
+///     make_plan_for_goals(&start, &[grab_snack.clone(), run_to_shelter.clone()], &[
+///         snack.clone(),
+///         safety.clone().with_priority(0),
+///     ])
+///     .unwrap();
+/// assert_eq!((index, plan.cost()), (0, 1));
+///
+/// // The higher-priority goal wins even though its plan is more expensive
+/// let (index, plan) =
+///     make_plan_for_goals(&start, &[grab_snack, run_to_shelter], &[snack, safety]).unwrap();
+/// assert_eq!((index, plan.cost()), (1, 10));
+/// ```
+pub fn make_plan_for_goals(
+    start: &WorldState,
+    actions: &[Action],
+    goals: &[Goal],
+) -> Option<(usize, Plan)> {
+    let mut best: Option<(usize, Plan)> = None;
+
+    for (index, goal) in goals.iter().enumerate() {
+        let Some(plan) = make_plan(start, actions, goal) else {
+            continue;
+        };
+
+        let is_better = match &best {
+            None => true,
+            Some((best_index, best_plan)) => {
+                let best_priority = goals[*best_index].priority;
+                goal.priority > best_priority
+                    || (goal.priority == best_priority && plan.cost() < best_plan.cost())
+            },
+        };
+
+        if is_better {
+            best = Some((index, plan));
+        }
+    }
+
+    best
+}
+
+/// Plans from many start states toward the same goal, in parallel.
+///
+/// Each start state is planned independently with [`make_plan`] on the
+/// `rayon` thread pool, sharing the actions and goal between threads. The
+/// results are in the same order as `starts` and identical to calling
+/// `make_plan` for each start state in turn.
+///
+/// Requires the `rayon` feature.
+///
+/// # Arguments
+/// * `starts` - Initial world states, one per plan
+/// * `actions` - Available actions that can be performed
+/// * `goal` - Target state requirements shared by every plan
+///
+/// # Returns
+/// One entry per start state: `Some(plan)` if a plan was found,
+/// `None` otherwise.
+///
+/// # Example
+/// ```rust
+/// use rust_goap::prelude::*;
+///
+/// let starts: Vec<WorldState> =
+///     (0..32).map(|wood| WorldState::new().set("wood", wood)).chain([WorldState::new()]).collect();
+/// let goal = Goal::new().with("wood", Assert::gt_eq(10));
+/// let chop = Action::new("chop")
+///     .with_precondition(("wood", Assert::exists()))
+///     .with_effect(Effect::new().add_mutation(Mutation::increment("wood", 3)));
+/// let actions = [chop];
+///
+/// let plans = make_plans_parallel(&starts, &actions, &goal);
+/// assert_eq!(plans.len(), starts.len());
+/// assert_eq!(plans[0].as_ref().unwrap().cost(), 4);
+/// assert_eq!(plans[10].as_ref().unwrap().cost(), 0);
+/// assert!(plans[32].is_none());
+///
+/// // Same results as planning each start state sequentially
+/// for (start, parallel) in starts.iter().zip(plans) {
+///     assert_eq!(make_plan(start, &actions, &goal), parallel);
+/// }
+/// ```
+#[cfg(feature = "rayon")]
+pub fn make_plans_parallel(
+    starts: &[WorldState],
+    actions: &[Action],
+    goal: &Goal,
+) -> Vec<Option<Plan>> {
+    use rayon::prelude::*;
+
+    starts.par_iter().map(|start| make_plan(start, actions, goal)).collect()
+}
+
+/// The number of states [`make_plan_partial`] expands at most.
+const PARTIAL_MAX_EXPANSIONS: usize = 10_000;
+
+/// Creates a plan to the goal, or a best-effort plan that gets as close as possible.
+///
+/// This behaves like [`make_plan`] when the goal can be reached. When it
+/// can't, instead of giving up, the path to the explored state with the
+/// smallest [`WorldState::distance_to_goal`] is returned. This gives agents a
+/// sensible "move toward the goal" behavior instead of standing still.
+///
+/// A single A* search is run, expanding at most 10,000 states, so planning
+/// terminates even when the goal is unreachable and the actions can be
+/// applied forever. Use [`make_plan_partial_with_config`] to choose another
+/// budget. When several explored states are equally close, the cheapest one
+/// is chosen.
+///
+/// # Arguments
+/// * `start` - Initial world state
+/// * `actions` - Available actions that can be performed
+/// * `goal` - Desired goal state with requirements
+///
+/// # Returns
+/// `(plan, remaining_distance)`, where `remaining_distance` is 0 when the goal
+/// is reached. The plan has no steps when no action gets closer to the goal
+/// than `start`.
+///
+/// # Example
+/// ```rust
+/// use rust_goap::prelude::*;
+///
+/// let start = WorldState::new().set("gold", 0);
+/// let goal = Goal::new().with("gold", Assert::gt_eq(10));
+///
+/// // The best we can do is dig up 5 gold
+/// let dig = Action::new("dig")
+///     .with_precondition(("gold", Assert::eq(0)))
+///     .with_effect(Effect::new().add_mutation(Mutation::set("gold", 5)));
+///
+/// assert!(make_plan(&start, &[dig.clone()], &goal).is_none());
+///
+/// let (plan, remaining) = make_plan_partial(&start, &[dig.clone()], &goal);
+/// assert_eq!(plan.len(), 1);
+/// assert_eq!(plan.cost(), 1);
+/// assert_eq!(remaining, 5);
+///
+/// // Once the gold is dug up, there's nothing left to do
+/// let dug = WorldState::new().set("gold", 5);
+/// let (plan, remaining) = make_plan_partial(&dug, &[dig], &goal);
+/// assert!(plan.is_empty());
+/// assert_eq!(remaining, 5);
+/// ```
+///
+/// Unreachable goals with endless actions still terminate:
+/// ```rust
+/// use rust_goap::prelude::*;
+///
+/// let start = WorldState::new().set("gold", 0).set("crown", false);
+/// let goal = Goal::new().with("gold", Assert::gt_eq(3)).with("crown", Assert::eq(true));
+/// let mine = Action::new("mine")
+///     .with_effect(Effect::new().add_mutation(Mutation::increment("gold", 1)));
+///
+/// // Nothing sets "crown", but mining gets the gold requirement done
+/// let (plan, remaining) = make_plan_partial(&start, &[mine], &goal);
+/// assert_eq!((plan.len(), plan.cost(), remaining), (3, 3, 1));
+/// ```
+pub fn make_plan_partial(start: &WorldState, actions: &[Action], goal: &Goal) -> (Plan, u64) {
+    let config = PlanningConfig::new().with_max_expansions(PARTIAL_MAX_EXPANSIONS);
+    make_plan_partial_with_config(&config, start, actions, goal)
+}
+
+/// Creates a plan to the goal, or a best-effort plan that gets as close as
+/// possible, like [`make_plan_partial`], using custom planning configuration.
+///
+/// The search expands at most [`PlanningConfig::max_expansions`] states, or
+/// 10,000 if no budget is set, so it always terminates. The heuristic, the
+/// maximum plan length and effect validation are taken from `config` as well.
+///
+/// # Arguments
+/// * `config` - Planning configuration
+/// * `start` - Initial world state
+/// * `actions` - Available actions that can be performed
+/// * `goal` - Desired goal state with requirements
+///
+/// # Returns
+/// `(plan, remaining_distance)` like [`make_plan_partial`]
+///
+/// # Example
+/// ```rust
+/// use rust_goap::prelude::*;
+///
+/// let start = WorldState::new().set("gold", 0).set("crown", false);
+/// let goal = Goal::new().with("gold", Assert::gt_eq(10)).with("crown", Assert::eq(true));
+/// let mine = Action::new("mine")
+///     .with_effect(Effect::new().add_mutation(Mutation::increment("gold", 1)));
+///
+/// // Two expansions only get two steps toward the gold
+/// let config = PlanningConfig::new().with_max_expansions(2);
+/// let (plan, remaining) = make_plan_partial_with_config(&config, &start, &[mine], &goal);
+/// assert_eq!((plan.len(), remaining), (2, 9));
+/// ```
+pub fn make_plan_partial_with_config(
+    config: &PlanningConfig,
+    start: &WorldState,
+    actions: &[Action],
+    goal: &Goal,
+) -> (Plan, u64) {
+    let max_expansions = config.max_expansions.unwrap_or(PARTIAL_MAX_EXPANSIONS);
+    let preconditions = Preconditions::new(actions, &|_| true);
+    let expansions = Cell::new(0_usize);
+    let (reached, found) = astar_reached(
+        &SearchNode::new(start.clone()),
+        |node, cost: SearchCost| {
+            if expansions.get() >= max_expansions {
+                return Vec::new();
+            }
+            expansions.set(expansions.get() + 1);
+            successors(node, &preconditions, config.max_steps, config.validate_effects)
+                .map(|(successor, cost)| (successor, SearchCost { cost, steps: 1 }))
+                .filter(|(_, move_cost)| (cost + *move_cost).cost < usize::MAX)
+                .collect::<Vec<_>>()
+        },
+        |node| SearchCost { cost: heuristic(&node.state, goal, actions, config), steps: 0 },
+        |node| is_goal(&node.state, goal),
+        None,
+        &mut 0,
+    );
+
+    // Without reaching the goal, prefer the cheapest of the closest states,
+    // which is the start itself when no action gets closer
+    let (distance, cost, closest) = match found {
+        Some(index) => (0, reached.cost(index), index),
+        None => reached
+            .iter()
+            .map(|(index, node, cost)| (node.state.distance_to_goal(goal), cost, index))
+            .min()
+            .unwrap_or((start.distance_to_goal(goal), SearchCost::default(), 0)),
+    };
+
+    let path = reached.path(closest);
+    let nodes = path.into_iter().map(|node| node.into_node(actions)).collect();
+    (Plan::new(nodes, cost.cost), distance)
+}
+
+/// Re-plans from the current state, reusing the previous plan when it is still valid.
+///
+/// In a dynamic world, the state can change while a plan is being executed.
+/// Instead of always searching again, this first checks whether the remaining
+/// steps of `previous_plan` can still be executed from `current_state`: every
+/// step's action must still exist in `actions` and have its preconditions met
+/// when the step is reached, and the last step must satisfy the goal.
+///
+/// Since the caller doesn't need to track how far execution got, every suffix
+/// of the previous plan is tried, starting with the shortest one, so steps that
+/// were already executed aren't repeated. Only if no suffix is valid does this
+/// fall back to a full [`make_plan`] from `current_state`.
+///
+/// Reused steps are replayed with the current definitions from `actions`, so
+/// the returned states and cost reflect `current_state`.
+///
+/// # Arguments
+/// * `current_state` - The state of the world now
+/// * `actions` - Available actions that can be performed
+/// * `goal` - Desired goal state with requirements
+/// * `previous_plan` - The nodes of the plan being executed
+///
+/// # Returns
+/// * `Some(plan)` starting at `current_state`, either reused
+///   from `previous_plan` or newly planned
+/// * `None` if the previous plan is invalid and no new plan exists
+///
+/// # Example
+/// ```rust
+/// use rust_goap::prelude::*;
+///
+/// let start = WorldState::new().set("has_food", false).set("is_hungry", true);
+/// let goal = Goal::new().with("is_hungry", Assert::eq(false));
+///
+/// let buy_food = Action::new("buy_food")
+///     .with_effect(Effect::new().add_mutation(Mutation::set("has_food", true)).with_cost(5));
+/// let eat = Action::new("eat")
+///     .with_precondition(("has_food", Assert::eq(true)))
+///     .with_effect(
+///         Effect::new()
+///             .add_mutation(Mutation::set("is_hungry", false))
+///             .add_mutation(Mutation::set("has_food", false)),
+///     );
+/// let plan = make_plan(&start, &[buy_food.clone(), eat.clone()], &goal).unwrap();
+///
+/// // After buying food, the remaining step is still valid and is reused
+/// let current = WorldState::new().set("has_food", true).set("is_hungry", true);
+/// let new_plan =
+///     replan(&current, &[buy_food.clone(), eat.clone()], &goal, plan.steps()).unwrap();
+/// let keys: Vec<&str> = new_plan.effects().map(|(key, _, _)| key).collect();
+/// assert_eq!(keys, ["eat"]);
+/// assert_eq!(new_plan.cost(), 1);
+///
+/// // The food got stolen: the plan is invalidated and a new one is made,
+/// // using an action that wasn't available originally
+/// let stolen = WorldState::new().set("has_food", false).set("is_hungry", true);
+/// let forage = Action::new("forage")
+///     .with_effect(Effect::new().add_mutation(Mutation::set("has_food", true)).with_cost(2));
+/// let new_plan = replan(&stolen, &[buy_food, eat, forage], &goal, &plan.steps()[2..]).unwrap();
+/// let keys: Vec<&str> = new_plan.effects().map(|(key, _, _)| key).collect();
+/// assert_eq!(keys, ["forage", "eat"]);
+/// assert_eq!(new_plan.cost(), 3);
+/// ```
+pub fn replan(
+    current_state: &WorldState,
+    actions: &[Action],
+    goal: &Goal,
+    previous_plan: &[Node],
+) -> Option<Plan> {
+    // Try the shortest suffix first so that executed steps aren't repeated
+    for skipped in (0..previous_plan.len()).rev() {
+        if let Ok((path, cost)) = replay(current_state, &previous_plan[skipped + 1..], actions)
+            && path.last().is_some_and(|node| is_goal(node.state(), goal))
+        {
+            return Some(Plan::new(path, cost));
+        }
+    }
+
+    make_plan(current_state, actions, goal)
+}
+
+/// Checks that a plan can still be executed from a given start state.
+///
+/// Before executing a cached or deserialized plan, the world may have changed
+/// in a way that makes it illegal. This replays the plan from `start`: for
+/// every `Effect` node, the action with the same key is looked up in
+/// `actions`, its preconditions are checked against the state at that point,
+/// and its effect is applied. The initial `State` node of the plan is ignored,
+/// since `start` replaces it.
+///
+/// The signature takes `actions` because plan nodes only record the effect
+/// of each step, not the preconditions of its action. Preconditions on keys
+/// missing from the state fail (unless they are [`Assert::Absent`](crate::prelude::Assert::Absent)) rather
+/// than panicking.
+///
+/// # Arguments
+/// * `start` - The state to replay the plan from
+/// * `actions` - The actions the plan was made with
+/// * `plan` - The nodes of the plan
+///
+/// # Returns
+/// * `Ok(final_state)` if every step could be executed
+/// * `Err(index)` with the index in `plan` of the first step whose
+///   preconditions fail, whose effect can't be applied (see
+///   [`Effect::validate`]), or whose action is missing from `actions`
+///
+/// # Example
+/// ```rust
+/// use rust_goap::prelude::*;
+///
+/// let start = WorldState::new().set("gold", 10).set("has_sword", false);
+/// let goal = Goal::new().with("has_sword", Assert::eq(true));
+///
+/// let buy_sword = Action::new("buy_sword")
+///     .with_precondition(("gold", Assert::gt_eq(10)))
+///     .with_effect(
+///         Effect::new()
+///             .add_mutation(Mutation::decrement("gold", 10))
+///             .add_mutation(Mutation::set("has_sword", true)),
+///     );
+/// let actions = [buy_sword];
+/// let plan = make_plan(&start, &actions, &goal).unwrap();
+///
+/// // The plan is valid from the state it was made for
+/// let final_state = validate_plan(&start, &actions, plan.steps()).unwrap();
+/// assert_eq!(final_state.get("has_sword"), Some(&Value::Bool(true)));
+/// assert_eq!(final_state.get("gold"), Some(&Value::I64(0)));
+///
+/// // Some gold was spent in the meantime: the first step is no longer legal
+/// let poorer = start.set("gold", 5);
+/// assert_eq!(validate_plan(&poorer, &actions, plan.steps()), Err(1));
+/// ```
+pub fn validate_plan(
+    start: &WorldState,
+    actions: &[Action],
+    plan: &[Node],
+) -> Result<WorldState, usize> {
+    let (path, _) = replay(start, plan, actions)?;
+    Ok(path.last().map_or_else(|| start.clone(), |node| node.state().clone()))
+}
+
+/// Computes the state a plan ends in by replaying its effects.
+///
+/// Starting from `start`, the mutations of every `Effect` node are applied
+/// in order; `State` nodes are skipped. Unlike [`validate_plan`], no
+/// preconditions are checked, so no actions are needed. Replaying a plan
+/// from the state it was made for gives the state of its last node, which
+/// makes this a cheap cross-check of a stored or edited plan.
+///
+/// # Arguments
+/// * `start` - The state to replay the plan from
+/// * `plan` - The nodes of the plan
+///
+/// # Returns
+/// The state after every effect of the plan, or a copy of `start` for a
+/// plan without effects.
+///
+/// Mutations that can't be applied to the state at that point (see
+/// [`Effect::validate`]) leave their value unchanged.
+///
+/// # Panics
+/// With the `strict` feature, panics if an effect can't be applied to the
+/// state at that point.
+///
+/// # Example
+/// ```rust
+/// use rust_goap::prelude::*;
+///
+/// let start = WorldState::new().set("wood", 0).set("has_axe", false);
+/// let goal = Goal::new().with("wood", Assert::gt_eq(6));
+/// let actions = [
+///     Action::new("buy_axe")
+///         .with_effect(Effect::new().add_mutation(Mutation::set("has_axe", true))),
+///     Action::new("chop")
+///         .with_precondition(("has_axe", Assert::eq(true)))
+///         .with_effect(Effect::new().add_mutation(Mutation::increment("wood", 3))),
+/// ];
+///
+/// let plan = make_plan(&start, &actions, &goal).unwrap();
+/// let end = apply_plan(&start, plan.steps());
+/// assert_eq!(&end, plan.steps().last().unwrap().state());
+/// assert_eq!(end, WorldState::new().set("wood", 6).set("has_axe", true));
+///
+/// // Replaying from elsewhere ignores the preconditions
+/// let stocked = WorldState::new().set("wood", 10).set("has_axe", false);
+/// assert_eq!(apply_plan(&stocked, plan.steps()).get("wood"), Some(&Value::I64(16)));
+/// assert_eq!(apply_plan(&stocked, &[]), stocked);
+/// ```
+#[must_use]
+pub fn apply_plan(start: &WorldState, plan: &[Node]) -> WorldState {
+    let mut state = start.clone();
+    for node in plan {
+        if let Node::Effect((_, effect, _)) = node {
+            effect.apply_to(&mut state);
+        }
+    }
+    state
+}
+
+/// Replays the actions of a sequence of plan nodes from a start state.
+///
+/// Each `Effect` node is replayed with the action of the same key from
+/// `actions`, whose preconditions must hold at that point; `State` nodes are
+/// skipped. The outcome recorded in the node is replayed if the action still
+/// has it, and the action's effect otherwise. Preconditions on keys missing from the state don't hold (unless
+/// they are [`Assert::Absent`](crate::prelude::Assert::Absent)) instead of panicking.
+///
+/// # Returns
+/// * `Ok((path, total_cost))` with the replayed nodes, starting at `start`
+/// * `Err(index)` with the index in `steps` of the first step that can't be
+///   replayed, either because its preconditions fail, its effect can't be
+///   applied (see [`Effect::validate`]) or its action is unknown
+fn replay(
+    start: &WorldState,
+    steps: &[Node],
+    actions: &[Action],
+) -> Result<(Vec<Node>, usize), usize> {
+    let mut path = vec![Node::State(start.clone())];
+    let mut state = start.clone();
+    let mut cost = 0_usize;
+
+    for (index, step) in steps.iter().enumerate() {
+        let Node::Effect((action_key, step_effect, _)) = step else {
+            continue;
+        };
+        let action = actions.iter().find(|action| &action.key == action_key).ok_or(index)?;
+        let effect = replayed_effect(action, step_effect).ok_or(index)?;
+        let preconditions_hold =
+            action.preconditions.iter().all(|(key, assert)| assert.check(&state, key));
+        if !preconditions_hold {
+            return Err(index);
+        }
+
+        let new_state = effect.try_apply(&state).map_err(|_| index)?;
+        cost = cost.saturating_add(effect.transition_cost(&state, &new_state));
+        path.push(Node::Effect((action_key.clone(), effect.clone(), new_state.clone())));
+        state = new_state;
+    }
+
+    Ok((path, cost))
+}
+
+/// Returns the outcome of `action` to replay for a plan step recording
+/// `step_effect`: the same outcome if the action still has it, and the
+/// action's effect otherwise.
+pub(crate) fn replayed_effect<'a>(action: &'a Action, step_effect: &Effect) -> Option<&'a Effect> {
+    action.effects().find(|effect| *effect == step_effect).or(action.effect.as_ref())
+}
+
+/// Checks that planning is deterministic for the given inputs.
+///
+/// Runs [`make_plan`] `runs` times and compares every result against the first
+/// one. The results are considered identical when they produce the same
+/// sequence of action keys and the same total cost (or all fail to find a
+/// plan). This is intended for CI checks that catch accidental dependence on
+/// unordered iteration, such as `HashMap` ordering, inside the search.
+///
+/// # Arguments
+/// * `start` - Initial world state
+/// * `actions` - Available actions that can be performed
+/// * `goal` - Desired goal state with requirements
+/// * `runs` - Number of times to run the planner
+///
+/// # Returns
+/// `true` if every run produced the same plan, `false` otherwise
+///
+/// # Example
+/// ```rust
+/// use rust_goap::prelude::*;
+///
+/// // The scenario from the `long_plan` example
+/// let start = WorldState::new().set("energy", 30_i64).set("hunger", 70_i64).set("gold", 0_i64);
+/// let goal = Goal::new().with("gold", Assert::eq(7_i64));
+///
+/// let sleep = Action::new("sleep")
+///     .with_effect(Effect { mutations: vec![Mutation::increment("energy", 10)], cost: 1, ..Default::default() });
+/// let eat = Action::new("eat")
+///     .with_effect(Effect { mutations: vec![Mutation::decrement("hunger", 10)], cost: 1, ..Default::default() })
+///     .with_precondition(("energy", Assert::gt_eq(26_i64)));
+/// let rob = Action::new("rob")
+///     .with_effect(Effect {
+///         mutations: vec![
+///             Mutation::increment("gold", 1),
+///             Mutation::decrement("energy", 5),
+///             Mutation::increment("hunger", 5),
+///         ],
+///         cost: 1,
+///         ..Default::default()
+///     })
+///     .with_precondition(("hunger", Assert::lt_eq(50_i64)))
+///     .with_precondition(("energy", Assert::gt_eq(50_i64)));
+///
+/// assert!(assert_deterministic(&start, &[sleep, eat, rob], &goal, 50));
+/// ```
+pub fn assert_deterministic(
+    start: &WorldState,
+    actions: &[Action],
+    goal: &Goal,
+    runs: usize,
+) -> bool {
+    let summarize = |plan: Option<Plan>| {
+        plan.map(|plan| {
+            let keys: Vec<String> = plan.effects().map(|(key, _, _)| key.to_string()).collect();
+            (keys, plan.cost())
+        })
+    };
+
+    let mut results = (0..runs).map(|_| summarize(make_plan(start, actions, goal)));
+    match results.next() {
+        Some(first) => results.all(|result| result == first),
+        None => true,
+    }
+}
+
+/// Extracts all effects from a plan, filtering out initial state nodes.
+///
+/// Converts a plan into an iterator over the actual actions and their
+/// effects. This is useful for executing the plan or analyzing the specific
+/// actions that need to be performed. The items are owned copies; use
+/// [`Plan::effects`] to borrow them instead.
+///
+/// # Arguments
+/// * `plan` - Plan containing both state and effect nodes
+///
+/// # Returns
+/// Iterator over tuples of (action_key, effect, resulting_state)
+///
+/// # Note
+/// Initial state nodes (Node::State) are filtered out since they
+/// don't represent actions that need to be executed.
+pub fn get_effects_from_plan(plan: &Plan) -> impl Iterator<Item = (String, Effect, WorldState)> {
+    plan.effects()
+        .map(|(action_key, effect, state)| (action_key.to_string(), effect.clone(), state.clone()))
+}
+
+/// Iterates over the effects of a sequence of plan nodes without copying them.
+///
+/// Works like [`get_effects_from_plan`], but borrows the nodes. This is the
+/// counterpart of [`Plan::effects`] for slices of nodes, such as the
+/// remaining steps of a plan being executed.
+///
+/// # Arguments
+/// * `plan` - Plan nodes, both state and effect nodes
+///
+/// # Returns
+/// Iterator over tuples of (action_key, effect, resulting_state)
+///
+/// # Example
+/// ```rust
+/// use rust_goap::prelude::*;
+///
+/// let start = WorldState::new().set("wood", 0);
+/// let goal = Goal::new().with("wood", Assert::eq(2));
+/// let chop = Action::new("chop")
+///     .with_effect(Effect::new().add_mutation(Mutation::increment("wood", 1)));
+///
+/// let config = PlanningConfig::new();
+/// let plan = make_plan_with_config(&config, &start, &[chop.clone()], &goal).unwrap();
+/// let steps: Vec<_> =
+///     effects_ref(plan.steps()).map(|(key, _, state)| (key, state.get("wood"))).collect();
+/// assert_eq!(steps, [("chop", Some(&Value::I64(1))), ("chop", Some(&Value::I64(2)))]);
+///
+/// // The plan is still available
+/// assert_eq!(validate_plan(&start, &[chop], plan.steps()), Ok(plan.steps()[2].state().clone()));
+/// ```
+pub fn effects_ref(plan: &[Node]) -> impl Iterator<Item = (&str, &Effect, &WorldState)> {
+    plan.iter().filter_map(|node| match node {
+        Node::Effect((action_key, effect, state)) => Some((action_key.as_str(), effect, state)),
+        Node::State(_) => None,
+    })
+}
+
+/// Extracts all effects from a plan together with their step index.
+///
+/// Works like [`get_effects_from_plan`], but also yields the position of each
+/// action in the plan, which helps to correlate steps with progress reporting.
+///
+/// # Arguments
+/// * `plan` - Plan containing both state and effect nodes
+///
+/// # Returns
+/// Iterator over tuples of (step_index, action_key, effect, resulting_state),
+/// where the index is 0-based and does not count the initial state node
+///
+/// # Example
+/// ```rust
+/// use rust_goap::prelude::*;
+///
+/// let start = WorldState::new().set("wood", 0_i64);
+/// let goal = Goal::new().with("wood", Assert::eq(3_i64));
+/// let chop = Action::new("chop")
+///     .with_effect(Effect::new().add_mutation(Mutation::increment("wood", 1)));
+///
+/// let plan = make_plan(&start, &[chop], &goal).unwrap();
+/// let indices: Vec<usize> = enumerate_plan_steps(&plan).map(|(index, _, _, _)| index).collect();
+/// assert_eq!(indices, [0, 1, 2]);
+/// ```
+pub fn enumerate_plan_steps(
+    plan: &Plan,
+) -> impl Iterator<Item = (usize, String, Effect, WorldState)> {
+    get_effects_from_plan(plan)
+        .enumerate()
+        .map(|(index, (action_key, effect, state))| (index, action_key, effect, state))
+}
+
+/// Formats a plan into a human-readable string for debugging or display.
+///
+/// Creates a detailed textual representation of a plan showing:
+/// - Initial world state
+/// - Each action to execute with its mutations
+/// - Intermediate states after each action
+/// - Final state and total plan cost
+///
+/// This uses the default [`PlanFormatter`]; use the formatter directly to
+/// change the indentation, hide intermediate states or print one line per step.
 ///
 /// # Arguments
-/// * `plan` - Tuple containing the node sequence and total cost
+/// * `plan` - The plan to format (tuples from the other planning functions
+///   convert with `Plan::from`)
 ///
 /// # Returns
 /// Formatted string representation of the plan
@@ -250,36 +2863,594 @@ pub fn get_effects_from_plan(
 ///         is_hungry = Value:Bool(false)
 /// ```
 #[must_use]
-pub fn format_plan(plan: (Vec<Node>, usize)) -> String {
-    let mut output = String::new();
-    let nodes = plan.0;
-    let cost = plan.1;
-    let mut last_state: WorldState = WorldState::new();
+pub fn format_plan(plan: &Plan) -> String {
+    PlanFormatter::default().format(plan)
+}
+
+/// Configurable text layout for plans.
+///
+/// The default layout is the one of [`format_plan`]: a block per node,
+/// indented with two tabs, listing the mutations of each action and the state
+/// it leads to. The compact layout prints one line per node instead, like the
+/// [`Display`](core::fmt::Display) implementation of [`Node`] but with the
+/// cost each step actually added to the plan, followed by a line with the
+/// total cost. Without intermediate states, the compact layout prints the
+/// actions alone and the final state on a line of its own.
+///
+/// # Example
+/// ```
+/// use rust_goap::prelude::*;
+///
+/// let start = WorldState::new().set("has_food", false).set("is_hungry", true);
+/// let goal = Goal::new().with("is_hungry", Assert::eq(false));
+/// let actions = [
+///     Action::new("buy_food")
+///         .with_effect(Effect::new().add_mutation(Mutation::set("has_food", true)).with_cost(2)),
+///     Action::new("eat")
+///         .with_precondition(("has_food", Assert::eq(true)))
+///         .with_effect(Effect::new().add_mutation(Mutation::set("is_hungry", false))),
+/// ];
+/// let plan = make_plan(&start, &actions, &goal).unwrap();
+///
+/// // One line per step
+/// let compact = PlanFormatter::default().compact(true).indent("").format(&plan);
+/// let lines: Vec<&str> = compact.lines().collect();
+/// assert_eq!(lines.len(), 4);
+/// assert_eq!(lines[0], "Initial: WorldState { has_food: Value:Bool(false), is_hungry: Value:Bool(true) }");
+/// assert!(lines[1].starts_with("Action \"buy_food\" (cost 2) -> "));
+/// assert_eq!(lines[3], "Total cost: 3");
+///
+/// // Only the actions, without the states between them
+/// let steps = PlanFormatter::default()
+///     .compact(true)
+///     .show_intermediate_states(false)
+///     .indent("  ")
+///     .format(&plan);
+/// assert_eq!(
+///     steps,
+///     "  Initial: WorldState { has_food: Value:Bool(false), is_hungry: Value:Bool(true) }\n  \
+///      Action \"buy_food\" (cost 2)\n  \
+///      Action \"eat\" (cost 1)\n  \
+///      Final: WorldState { has_food: Value:Bool(true), is_hungry: Value:Bool(false) }\n  \
+///      Total cost: 3\n"
+/// );
+///
+/// // The block layout can hide intermediate states too
+/// let blocks = PlanFormatter::default().show_intermediate_states(false).format(&plan);
+/// assert!(!blocks.contains("current state:"));
+/// assert!(blocks.contains("\t\t= FINAL STATE (COST: 3)"));
+/// assert_eq!(PlanFormatter::default().format(&plan), format_plan(&plan));
+///
+/// // Step costs include costs per unit of change, and add up to the total
+/// let start = WorldState::new().set("distance", 0);
+/// let goal = Goal::new().with("distance", Assert::gt_eq(4));
+/// let walk = Action::new("walk").with_effect(
+///     Effect::new()
+///         .add_mutation(Mutation::increment("distance", 4))
+///         .with_cost(1)
+///         .with_cost_per_unit("distance", 2),
+/// );
+/// let plan = make_plan(&start, &[walk], &goal).unwrap();
+/// let compact = PlanFormatter::default().compact(true).indent("").format(&plan);
+/// assert!(compact.contains("Action \"walk\" (cost 9) -> "));
+/// assert!(compact.ends_with("Total cost: 9\n"));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PlanFormatter {
+    indent: String,
+    show_intermediate_states: bool,
+    compact: bool,
+}
+
+impl Default for PlanFormatter {
+    fn default() -> Self {
+        Self { indent: String::from("\t\t"), show_intermediate_states: true, compact: false }
+    }
+}
+
+impl PlanFormatter {
+    /// Creates a formatter with the default layout of [`format_plan`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the prefix of every heading and compact line (two tabs by default).
+    pub fn indent(mut self, indent: impl Into<String>) -> Self {
+        self.indent = indent.into();
+        self
+    }
+
+    /// Sets whether the states between actions are printed (the default).
+    ///
+    /// The initial and final states are always printed.
+    pub fn show_intermediate_states(mut self, show: bool) -> Self {
+        self.show_intermediate_states = show;
+        self
+    }
+
+    /// Sets whether every node is printed on a single line.
+    pub fn compact(mut self, compact: bool) -> Self {
+        self.compact = compact;
+        self
+    }
+
+    /// Formats a plan with this layout.
+    ///
+    /// # Arguments
+    /// * `plan` - The plan to format
+    ///
+    /// # Returns
+    /// Formatted string representation of the plan
+    #[must_use]
+    pub fn format(&self, plan: &Plan) -> String {
+        if self.compact { self.format_compact(plan) } else { self.format_blocks(plan) }
+    }
+
+    fn format_compact(&self, plan: &Plan) -> String {
+        let indent = &self.indent;
+        let mut output = String::new();
+        let mut previous: Option<&WorldState> = None;
+        for node in plan {
+            match node {
+                Node::State(state) => {
+                    output.push_str(&format!("{indent}{node}\n"));
+                    previous = Some(state);
+                },
+                Node::Effect((action_key, effect, state)) => {
+                    // The cost of the step, including costs per unit of change
+                    let cost = previous.map_or_else(
+                        || effect.fixed_cost(),
+                        |previous| effect.transition_cost(previous, state),
+                    );
+                    output.push_str(&format!("{indent}Action {action_key:?} (cost {cost})"));
+                    if self.show_intermediate_states {
+                        output.push_str(&format!(" -> {state}"));
+                    }
+                    output.push('\n');
+                    previous = Some(state);
+                },
+            }
+        }
+        if !self.show_intermediate_states
+            && let Some(Node::Effect((_, _, state))) = plan.steps().last()
+        {
+            output.push_str(&format!("{indent}Final: {state}\n"));
+        }
+        output.push_str(&format!("{indent}Total cost: {}\n", plan.cost()));
+        output
+    }
+
+    fn format_blocks(&self, plan: &Plan) -> String {
+        let indent = &self.indent;
+        let mut output = String::new();
+        let cost = plan.cost();
+        let mut last_state: WorldState = WorldState::new();
+
+        for node in plan {
+            match node {
+                Node::Effect((action_key, effect, state)) => {
+                    output.push_str(&format!("{indent}= DO ACTION {:#?}\n", action_key));
+                    output.push_str(&format!("{indent}MUTATES:\n"));
+                    output.push_str(&format_mutations(effect.mutations.clone()));
+                    if self.show_intermediate_states {
+                        output.push_str(&format!("current state:\n{:?}\n", state));
+                    }
+                    last_state = state.clone();
+                },
+                Node::State(s) => {
+                    output.push_str(&format!("{indent}= INITIAL STATE\n"));
+                    for (k, v) in s.iter() {
+                        output.push_str(&format!("{indent}{k} = {v}\n"));
+                    }
+                    last_state = s.clone();
+                },
+            }
+            output.push_str(&format!("\n{indent}---\n"));
+        }
+
+        output.push_str(&format!("{indent}= FINAL STATE (COST: {cost})\n"));
+        for (k, v) in last_state.iter() {
+            output.push_str(&format!("{indent}{k} = {v}\n"));
+        }
+
+        output
+    }
+}
+
+/// Exports a plan as a GraphViz DOT digraph for visualization.
+///
+/// Every node of the plan becomes a graph node labeled with its world state
+/// (using the `Display` implementation of [`WorldState`]), and consecutive
+/// nodes are connected by an edge labeled with the action key and the cost of
+/// that step. The initial state is highlighted in blue and the final state in
+/// green. The output can be rendered with e.g. `dot -Tsvg plan.dot`.
+///
+/// # Arguments
+/// * `plan` - The plan to export
+///
+/// # Returns
+/// The plan as a DOT `digraph`
+///
+/// # Example
+/// ```rust
+/// use rust_goap::prelude::*;
+///
+/// let start = WorldState::new().set("has_food", false).set("is_hungry", true);
+/// let goal = Goal::new().with("is_hungry", Assert::eq(false));
+/// let actions = [
+///     Action::new("buy_food")
+///         .with_effect(Effect::new().add_mutation(Mutation::set("has_food", true))),
+///     Action::new("eat")
+///         .with_precondition(("has_food", Assert::eq(true)))
+///         .with_effect(Effect::new().add_mutation(Mutation::set("is_hungry", false))),
+/// ];
+///
+/// let plan = make_plan(&start, &actions, &goal).unwrap();
+/// let dot = plan_to_dot(&plan);
+///
+/// assert!(dot.starts_with("digraph plan {"));
+/// assert!(dot.contains("n0 -> n1 [label=\"buy_food (cost: 1)\"]"));
+/// assert!(dot.contains("n1 -> n2 [label=\"eat (cost: 1)\"]"));
+/// ```
+#[must_use]
+pub fn plan_to_dot(plan: &Plan) -> String {
+    let (nodes, cost) = (plan.steps(), plan.cost());
+    let escape = |text: String| text.replace('\\', "\\\\").replace('"', "\\\"");
+
+    let mut output = String::from("digraph plan {\n");
+    output.push_str(&format!("\tlabel=\"total cost: {cost}\";\n"));
+    output.push_str("\tnode [shape=box, style=filled, fillcolor=white];\n");
+
+    for (index, node) in nodes.iter().enumerate() {
+        let fill = if index == 0 {
+            ", fillcolor=lightblue"
+        } else if index == nodes.len() - 1 {
+            ", fillcolor=palegreen"
+        } else {
+            ""
+        };
+        let label = escape(node.state().to_string());
+        output.push_str(&format!("\tn{index} [label=\"{label}\"{fill}];\n"));
+    }
+
+    for (index, pair) in nodes.windows(2).enumerate() {
+        if let Node::Effect((action_key, effect, state)) = &pair[1] {
+            let step_cost = effect.transition_cost(pair[0].state(), state);
+            let label = escape(format!("{action_key} (cost: {step_cost})"));
+            output.push_str(&format!("\tn{} -> n{} [label=\"{label}\"];\n", index, index + 1));
+        }
+    }
+
+    output.push_str("}\n");
+    output
+}
+
+/// Exports a completed plan as JSON, e.g. for web-based visualizers.
+///
+/// The output is an object with three fields:
+/// - `initial_state`: the world state the plan starts from
+/// - `steps`: one entry per action, each with the `action` key, the applied
+///   `mutations`, the resulting `state`, and the `cost` of that step
+/// - `total_cost`: the total cost of the plan
+///
+/// World states are encoded as flat objects mapping keys to JSON booleans or
+/// numbers. Mutations are encoded as objects with an `op` field (`set`,
+/// `delete`, `increment`, `decrement`, `compute` or `conditional`) and the
+/// operands of that operation.
+///
+/// This function is only available with the `serde_json` feature.
+///
+/// # Arguments
+/// * `plan` - The plan to export
+///
+/// # Returns
+/// The plan as a JSON string
+///
+/// # Example
+/// ```rust
+/// use rust_goap::prelude::*;
+///
+/// let start = WorldState::new().set("has_food", false).set("is_hungry", true);
+/// let goal = Goal::new().with("is_hungry", Assert::eq(false));
+/// let actions = [
+///     Action::new("buy_food")
+///         .with_effect(Effect::new().add_mutation(Mutation::set("has_food", true))),
+///     Action::new("eat")
+///         .with_precondition(("has_food", Assert::eq(true)))
+///         .with_effect(Effect::new().add_mutation(Mutation::set("is_hungry", false))),
+/// ];
+///
+/// let plan = make_plan(&start, &actions, &goal).unwrap();
+/// let json: serde_json::Value = serde_json::from_str(&plan_to_json(&plan)).unwrap();
+///
+/// let effect_count = plan.effects().count();
+/// assert_eq!(json["steps"].as_array().unwrap().len(), effect_count);
+/// assert_eq!(json["initial_state"]["is_hungry"], true);
+/// assert_eq!(json["steps"][1]["action"], "eat");
+/// assert_eq!(json["steps"][1]["mutations"][0]["op"], "set");
+/// assert_eq!(json["total_cost"], 2);
+/// ```
+#[cfg(feature = "serde_json")]
+#[must_use]
+pub fn plan_to_json(plan: &Plan) -> String {
+    use serde_json::json;
+
+    let (nodes, cost) = (plan.steps(), plan.cost());
+    let mut initial_state = serde_json::Value::Null;
+    let mut steps = Vec::new();
+    let mut previous: Option<&WorldState> = None;
 
     for node in nodes {
         match node {
+            Node::State(state) => initial_state = state.to_json_value(),
             Node::Effect((action_key, effect, state)) => {
-                output.push_str(&format!("\t\t= DO ACTION {:#?}\n", action_key));
-                output.push_str("\t\tMUTATES:\n");
-                output.push_str(&format_mutations(effect.mutations.clone()));
-                output.push_str(&format!("current state:\n{:?}\n", state));
-                last_state = state.clone();
-            },
-            Node::State(s) => {
-                output.push_str("\t\t= INITIAL STATE\n");
-                for (k, v) in &s.0 {
-                    output.push_str(&format!("\t\t{k} = {v}\n"));
-                }
-                last_state = s.clone();
+                let step_cost = match previous {
+                    Some(before) => effect.transition_cost(before, state),
+                    None => effect.fixed_cost(),
+                };
+                steps.push(json!({
+                    "action": action_key,
+                    "mutations": effect.mutations.iter().map(mutation_to_json).collect::<Vec<_>>(),
+                    "state": state.to_json_value(),
+                    "cost": step_cost,
+                }));
             },
         }
-        output.push_str("\n\t\t---\n");
+        previous = Some(node.state());
+    }
+
+    json!({
+        "initial_state": initial_state,
+        "steps": steps,
+        "total_cost": cost,
+    })
+    .to_string()
+}
+
+/// Converts a mutation to a JSON object describing the operation.
+#[cfg(feature = "serde_json")]
+fn mutation_to_json(mutation: &Mutation) -> serde_json::Value {
+    use serde_json::json;
+
+    match mutation {
+        Mutation::Set(key, value) => {
+            json!({"op": "set", "key": key, "value": value.to_json_value()})
+        },
+        Mutation::Delete(key) => json!({"op": "delete", "key": key}),
+        Mutation::Increment(key, value) => {
+            json!({"op": "increment", "key": key, "value": value.to_json_value()})
+        },
+        Mutation::Decrement(key, value) => {
+            json!({"op": "decrement", "key": key, "value": value.to_json_value()})
+        },
+        Mutation::DecrementSaturating(key, value) => {
+            json!({"op": "decrement_saturating", "key": key, "value": value.to_json_value()})
+        },
+        Mutation::AddFromKey(key, source) => {
+            json!({"op": "add_from_key", "key": key, "source": source})
+        },
+        Mutation::SubtractFromKey(key, source) => {
+            json!({"op": "subtract_from_key", "key": key, "source": source})
+        },
+        Mutation::SetMin(key, value) => {
+            json!({"op": "set_min", "key": key, "value": value.to_json_value()})
+        },
+        Mutation::SetMax(key, value) => {
+            json!({"op": "set_max", "key": key, "value": value.to_json_value()})
+        },
+        Mutation::SetIfAbsent(key, value) => {
+            json!({"op": "set_if_absent", "key": key, "value": value.to_json_value()})
+        },
+        Mutation::Compute { target, expr } => {
+            json!({"op": "compute", "key": target, "expr": expr.to_string()})
+        },
+        Mutation::Conditional { on_key, condition, mutation } => json!({
+            "op": "conditional",
+            "on_key": on_key,
+            "condition": condition.to_string(),
+            "mutation": mutation_to_json(mutation),
+        }),
+    }
+}
+
+/// Finds the actions that can help reach a goal, by index, working backward
+/// from the keys the goal constrains (see
+/// [`PlanningConfig::prune_irrelevant_actions`]).
+fn relevant_actions(actions: &[Action], goal: &Goal) -> Vec<bool> {
+    let mut keys: BTreeSet<&str> = goal.keys().collect();
+    let mut relevant = vec![false; actions.len()];
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for (index, action) in actions.iter().enumerate() {
+            let mutations = || action.effects().flat_map(|effect| &effect.mutations);
+            if relevant[index]
+                || !mutations().any(|mutation| keys.contains(mutation_keys(mutation).1))
+            {
+                continue;
+            }
+            relevant[index] = true;
+            changed = true;
+            keys.extend(action.preconditions.iter().map(|(key, _)| key.as_str()));
+            keys.extend(mutations().flat_map(|mutation| mutation_keys(mutation).0));
+        }
     }
+    relevant
+}
 
-    output.push_str(&format!("\t\t= FINAL STATE (COST: {cost})\n"));
-    for (k, v) in &last_state.0 {
-        output.push_str(&format!("\t\t{k} = {v}\n"));
+/// Returns the world state keys a mutation reads and the key it writes.
+fn mutation_keys(mutation: &Mutation) -> (Vec<&str>, &str) {
+    match mutation {
+        Mutation::Set(key, _) | Mutation::Delete(key) => (vec![], key),
+        Mutation::Increment(key, _)
+        | Mutation::Decrement(key, _)
+        | Mutation::DecrementSaturating(key, _)
+        | Mutation::SetMin(key, _)
+        | Mutation::SetMax(key, _)
+        | Mutation::SetIfAbsent(key, _) => (vec![key], key),
+        Mutation::AddFromKey(key, source) | Mutation::SubtractFromKey(key, source) => {
+            (vec![key, source], key)
+        },
+        Mutation::Compute { target, expr } => (expr.keys(), target),
+        Mutation::Conditional { on_key, mutation, .. } => {
+            let (mut reads, write) = mutation_keys(mutation);
+            reads.push(on_key);
+            (reads, write)
+        },
     }
+}
 
-    output
+/// Reorders the actions of a plan so that related actions are grouped together.
+///
+/// A* returns actions in whatever order it happened to find them, which can
+/// interleave unrelated work. This post-processing pass produces an equivalent
+/// plan that is easier to read: actions are topologically reordered so that
+/// actions advancing the same goal requirement are clustered together, while
+/// every read/write dependency between actions is respected.
+///
+/// Two actions depend on each other (and keep their relative order) when one
+/// writes a key that the other reads (through its preconditions or an
+/// increment/decrement) or writes. Among the actions whose dependencies are
+/// satisfied, the pass prefers one advancing the same goal requirement as the
+/// previously emitted action, and otherwise falls back to the original order.
+///
+/// Intermediate states are recomputed from the initial state. The effects come
+/// from the plan's [`Node::Effect`] entries, but plan nodes don't record the
+/// preconditions of their actions, and without them a step could be moved
+/// ahead of the step that enables it. That's why the actions are passed in:
+/// their preconditions are looked up by action key, both to find the keys each
+/// step reads and to check every step again in the new order. The original
+/// plan is returned unchanged if an action can't be found, if a precondition
+/// fails or an effect can't be applied (see [`Effect::validate`]) in the new
+/// order, or if the reordered plan no longer satisfies the goal. This never
+/// panics, not even with the `strict` feature.
+///
+/// # Arguments
+/// * `plan` - The plan to reorder
+/// * `actions` - The actions the plan was made from, providing the
+///   preconditions of its steps
+/// * `goal` - The goal the plan satisfies
+///
+/// # Returns
+/// The reordered node sequence, starting with the initial state node
+///
+/// # Example
+/// ```rust
+/// use rust_goap::prelude::*;
+///
+/// let start = WorldState::new().set("wood", 0).set("stone", 0);
+/// let goal = Goal::new().with("wood", Assert::eq(2)).with("stone", Assert::eq(2));
+///
+/// let actions = vec![
+///     Action::new("chop_wood")
+///         .with_effect(Effect::new().add_mutation(Mutation::increment("wood", 1))),
+///     Action::new("mine_stone")
+///         .with_effect(Effect::new().add_mutation(Mutation::increment("stone", 1))),
+/// ];
+///
+/// let plan = make_plan(&start, &actions, &goal).unwrap();
+/// let final_state = plan.steps().last().unwrap().state().clone();
+///
+/// let reordered = reorder_plan_for_display(plan, &actions, &goal);
+/// let keys: Vec<&str> = effects_ref(&reordered).map(|(k, _, _)| k).collect();
+///
+/// // Actions serving the same requirement are grouped together
+/// assert_eq!(keys, ["chop_wood", "chop_wood", "mine_stone", "mine_stone"]);
+/// // The reordered plan reaches the same final state
+/// assert_eq!(reordered.last().unwrap().state(), &final_state);
+///
+/// // Steps only linked by a precondition keep their order
+/// let start = WorldState::new().set("has_key", false).set("door_open", false);
+/// let goal = Goal::new().with("door_open", Assert::eq(true));
+/// let actions = vec![
+///     Action::new("open_door")
+///         .with_precondition(("has_key", Assert::eq(true)))
+///         .with_effect(Effect::new().add_mutation(Mutation::set("door_open", true))),
+///     Action::new("take_key")
+///         .with_effect(Effect::new().add_mutation(Mutation::set("has_key", true))),
+/// ];
+///
+/// let plan = make_plan(&start, &actions, &goal).unwrap();
+/// let reordered = reorder_plan_for_display(plan.clone(), &actions, &goal);
+/// let keys: Vec<&str> = effects_ref(&reordered).map(|(k, _, _)| k).collect();
+/// assert_eq!(keys, ["take_key", "open_door"]);
+///
+/// // A plan that can't be replayed is returned unchanged
+/// let mismatched =
+///     [actions[0].clone().with_precondition(("mana", Assert::gt(0))), actions[1].clone()];
+/// assert_eq!(reorder_plan_for_display(plan.clone(), &mismatched, &goal), plan.steps());
+/// ```
+pub fn reorder_plan_for_display(plan: Plan, actions: &[Action], goal: &Goal) -> Vec<Node> {
+    let (nodes, _) = plan.into_parts();
+    let Some(Node::State(start)) = nodes.first() else {
+        return nodes;
+    };
+
+    // Collect the read/write key sets of every step
+    let mut steps = Vec::new();
+    for node in &nodes[1..] {
+        let Node::Effect((action_key, effect, _)) = node else {
+            return nodes;
+        };
+        let Some(action) = actions.iter().find(|action| &action.key == action_key) else {
+            return nodes;
+        };
+
+        let mut reads: Vec<&str> = action.preconditions.iter().map(|(k, _)| k.as_str()).collect();
+        let mut writes = Vec::new();
+        for mutation in &effect.mutations {
+            let (read, write) = mutation_keys(mutation);
+            reads.extend(read);
+            writes.push(write);
+        }
+
+        // The first goal requirement this step advances, used for clustering
+        let cluster = goal.requirements.keys().position(|k| writes.contains(&k.as_str()));
+        steps.push((action, effect, reads, writes, cluster));
+    }
+
+    let depends_on = |later: usize, earlier: usize| {
+        let (_, _, later_reads, later_writes, _) = &steps[later];
+        let (_, _, earlier_reads, earlier_writes, _) = &steps[earlier];
+        earlier_writes.iter().any(|k| later_reads.contains(k) || later_writes.contains(k))
+            || earlier_reads.iter().any(|k| later_writes.contains(k))
+    };
+
+    // Kahn's algorithm, preferring to stay within the current cluster
+    let mut order: Vec<usize> = Vec::with_capacity(steps.len());
+    let mut emitted = vec![false; steps.len()];
+    while order.len() < steps.len() {
+        let ready: Vec<usize> = (0..steps.len())
+            .filter(|&i| !emitted[i] && (0..i).all(|j| emitted[j] || !depends_on(i, j)))
+            .collect();
+        let current_cluster = order.last().and_then(|&i| steps[i].4);
+        let next = ready
+            .iter()
+            .copied()
+            .find(|&i| current_cluster.is_some() && steps[i].4 == current_cluster)
+            .unwrap_or(ready[0]);
+        emitted[next] = true;
+        order.push(next);
+    }
+
+    // Replay the new order to recompute the intermediate states
+    let mut reordered = vec![Node::State(start.clone())];
+    let mut state = start.clone();
+    for index in order {
+        let (action, effect, _, _, _) = &steps[index];
+        if !action.preconditions.iter().all(|(key, assert)| assert.check(&state, key)) {
+            return nodes;
+        }
+        let Ok(next) = effect.try_apply(&state) else {
+            return nodes;
+        };
+        state = next;
+        reordered.push(Node::Effect((action.key.clone(), (*effect).clone(), state.clone())));
+    }
+
+    if !goal.is_satisfied_by(&state) {
+        return nodes;
+    }
+
+    reordered
 }
@@ -0,0 +1,125 @@
+//! Goal regression for the backward half of bidirectional search.
+//!
+//! Regressing a goal through an action gives the goal a state must satisfy
+//! for the action to be applicable and to lead to a state satisfying the
+//! original goal. Only mutations whose effect on a requirement can be undone
+//! exactly are supported: sets, deletes, increments and decrements.
+
+use crate::{
+    action::Action,
+    basic::assert::{Assert, compare_values},
+    basic::mutation::Mutation,
+    basic::value::Value,
+    effect::Effect,
+    goal::Goal,
+};
+
+/// Checks whether goals can be regressed exactly through every outcome of an
+/// action.
+///
+/// Besides supported mutations, this requires fixed costs and no usage limits
+/// or groups, since regressed goals don't track how a state was reached.
+pub(crate) fn is_regressable(action: &Action) -> bool {
+    action.max_uses.is_none()
+        && action.group.is_none()
+        && action.effects().all(|effect| {
+            effect.cost_per_unit.is_none()
+                && effect.mutations.iter().all(|mutation| {
+                    matches!(
+                        mutation,
+                        Mutation::Set(..)
+                            | Mutation::Delete(_)
+                            | Mutation::Increment(..)
+                            | Mutation::Decrement(..)
+                    )
+                })
+        })
+}
+
+/// Regresses a goal through one outcome of an action.
+///
+/// # Arguments
+/// * `goal` - The goal to hold after the action
+/// * `action` - The action, whose preconditions must hold before it
+/// * `effect` - The outcome of the action
+///
+/// # Returns
+/// * `Some(goal)` with the goal to hold before the action
+/// * `None` if the outcome doesn't write any required key, contradicts a
+///   requirement, or can't be regressed
+pub(crate) fn regress(goal: &Goal, action: &Action, effect: &Effect) -> Option<Goal> {
+    let relevant = effect.mutations.iter().any(|mutation| match mutation {
+        Mutation::Set(key, _)
+        | Mutation::Delete(key)
+        | Mutation::Increment(key, _)
+        | Mutation::Decrement(key, _) => goal.requirements.contains_key(key),
+        _ => false,
+    });
+    if !relevant {
+        return None;
+    }
+
+    let mut regressed = Goal::new();
+    regressed.requirements = goal.requirements.clone();
+    // Undo the mutations from last to first
+    for mutation in effect.mutations.iter().rev() {
+        let requirements = &mut regressed.requirements;
+        match mutation {
+            Mutation::Set(key, value) => {
+                if let Some(assert) = requirements.remove(key)
+                    && !compare_values(&assert, value)
+                {
+                    return None;
+                }
+            },
+            Mutation::Delete(key) => {
+                if let Some(assert) = requirements.remove(key)
+                    && !assert.holds_when_missing()
+                {
+                    return None;
+                }
+            },
+            Mutation::Increment(key, amount) | Mutation::Decrement(key, amount) => {
+                if let Some(assert) = requirements.get_mut(key) {
+                    let increment = matches!(mutation, Mutation::Increment(..));
+                    *assert = shift(assert, amount, increment)?;
+                }
+            },
+            _ => return None,
+        }
+    }
+
+    regressed.merge(&Goal::from_reqs(&action.preconditions))
+}
+
+/// Shifts the values an assertion compares against, so that it holds for a
+/// value before an increment (or decrement) by `amount` exactly when the
+/// original assertion holds after it.
+///
+/// # Returns
+/// The shifted assertion, or `None` if the types don't match or the shifted
+/// value overflows.
+fn shift(assert: &Assert, amount: &Value, increment: bool) -> Option<Assert> {
+    let shifted = |value: &Value| match (value, amount) {
+        (Value::I64(value), Value::I64(amount)) if increment => {
+            value.checked_sub(*amount).map(Value::I64)
+        },
+        (Value::I64(value), Value::I64(amount)) => value.checked_add(*amount).map(Value::I64),
+        (Value::F64(value), Value::F64(amount)) if increment => Some(Value::F64(value - amount)),
+        (Value::F64(value), Value::F64(amount)) => Some(Value::F64(value + amount)),
+        _ => None,
+    };
+    Some(match assert {
+        Assert::Equals(value) => Assert::Equals(shifted(value)?),
+        Assert::NotEquals(value) => Assert::NotEquals(shifted(value)?),
+        Assert::GreaterThan(value) => Assert::GreaterThan(shifted(value)?),
+        Assert::GreaterThanEquals(value) => Assert::GreaterThanEquals(shifted(value)?),
+        Assert::LessThan(value) => Assert::LessThan(shifted(value)?),
+        Assert::LessThanEquals(value) => Assert::LessThanEquals(shifted(value)?),
+        // Increments of missing keys do nothing, so presence is unchanged
+        Assert::Exists | Assert::Absent => assert.clone(),
+        Assert::All(asserts) => Assert::All(
+            asserts.iter().map(|assert| shift(assert, amount, increment)).collect::<Option<_>>()?,
+        ),
+    })
+}
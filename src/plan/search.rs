@@ -0,0 +1,462 @@
+//! Graph searches used by the planner.
+//!
+//! These are small A*, IDA* and bidirectional implementations built on `alloc`
+//! collections only, so that planning works without the standard library.
+//! Nodes are stored once in a vector and looked up through a hash table of
+//! their indices, which avoids cloning states into a separate map.
+
+use alloc::collections::BinaryHeap;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::hash::{BuildHasher, Hash};
+use core::ops::Add;
+
+use hashbrown::HashTable;
+use rustc_hash::FxBuildHasher;
+
+/// The nodes reached by a search.
+///
+/// Each node is stored with the index of its parent on the cheapest known path
+/// (`None` for the start node) and the cost of that path.
+pub(crate) struct Reached<N, C> {
+    nodes: Vec<(N, Option<usize>, C)>,
+    table: HashTable<usize>,
+}
+
+impl<N: Eq + Hash, C: Copy> Reached<N, C> {
+    /// Creates the set of reached nodes, containing only `start` at `cost`.
+    fn new(start: N, cost: C) -> Self {
+        let mut reached = Self { nodes: Vec::new(), table: HashTable::new() };
+        reached.insert(start, None, cost);
+        reached
+    }
+
+    /// Records a path to `node` through `parent`, unless the node was already
+    /// reached at a cost not greater than `cost`.
+    ///
+    /// # Returns
+    /// The index of `node` if the path was recorded, `None` otherwise.
+    fn relax(&mut self, node: N, parent: usize, cost: C) -> Option<usize>
+    where
+        C: Ord,
+    {
+        let hash = FxBuildHasher.hash_one(&node);
+        let found = self.table.find(hash, |&index| self.nodes[index].0 == node).copied();
+        match found {
+            Some(index) if self.nodes[index].2 > cost => {
+                self.nodes[index].1 = Some(parent);
+                self.nodes[index].2 = cost;
+                Some(index)
+            },
+            Some(_) => None,
+            None => Some(self.insert(node, Some(parent), cost)),
+        }
+    }
+
+    /// Inserts a node that wasn't reached before and returns its index.
+    fn insert(&mut self, node: N, parent: Option<usize>, cost: C) -> usize {
+        let index = self.nodes.len();
+        let hash = FxBuildHasher.hash_one(&node);
+        let nodes = &self.nodes;
+        self.table.insert_unique(hash, index, |&index| FxBuildHasher.hash_one(&nodes[index].0));
+        self.nodes.push((node, parent, cost));
+        index
+    }
+
+    /// Returns every reached node with its index and the cost of the cheapest
+    /// known path to it, in the order the nodes were first reached.
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (usize, &N, C)> {
+        self.nodes.iter().enumerate().map(|(index, (node, _, cost))| (index, node, *cost))
+    }
+
+    /// Returns the cost of the cheapest known path to the node at `index`.
+    pub(crate) fn cost(&self, index: usize) -> C {
+        self.nodes[index].2
+    }
+
+    /// Returns the cheapest known path from the start node to the node at `index`.
+    pub(crate) fn path(&self, index: usize) -> Vec<N>
+    where
+        N: Clone,
+    {
+        let mut path = Vec::new();
+        let mut current = Some(index);
+        while let Some(index) = current {
+            let (node, parent, _) = &self.nodes[index];
+            path.push(node.clone());
+            current = *parent;
+        }
+        path.reverse();
+        path
+    }
+}
+
+/// A node waiting to be expanded by [`astar`].
+///
+/// Candidates are ordered so that the binary heap pops the smallest estimated
+/// total cost first and, among equal estimates, the largest cost so far, which
+/// favors nodes closer to the goal.
+struct Candidate<C> {
+    estimate: C,
+    cost: C,
+    index: usize,
+}
+
+impl<C: Ord> PartialEq for Candidate<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<C: Ord> Eq for Candidate<C> {}
+
+impl<C: Ord> PartialOrd for Candidate<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C: Ord> Ord for Candidate<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.cmp(&self.estimate).then_with(|| self.cost.cmp(&other.cost))
+    }
+}
+
+/// Finds the cheapest path from `start` to a node satisfying `success`.
+///
+/// The returned path is the cheapest one if `heuristic` never overestimates
+/// the remaining cost. A node is only revisited when a strictly cheaper path
+/// to it is found.
+///
+/// # Arguments
+/// * `start` - The node to start from
+/// * `successors` - Returns the neighbors of a node, given the cost of the
+///   path to it, with the cost of moving there
+/// * `heuristic` - Estimates the remaining cost from a node to the goal
+/// * `success` - Whether a node is a goal
+/// * `beam_width` - The number of nodes kept waiting to be expanded, dropping
+///   the ones with the largest estimates beyond it, or `None` to keep all
+/// * `peak_frontier` - Raised to the largest number of nodes waiting to be
+///   expanded at any point of the search
+///
+/// # Returns
+/// * `Some((path, total_cost))` with the path starting at `start`
+/// * `None` if no reachable node satisfies `success`, or none is reached
+///   because the beam dropped the nodes leading to it
+pub(crate) fn astar<N, C, I>(
+    start: &N,
+    successors: impl FnMut(&N, C) -> I,
+    heuristic: impl FnMut(&N) -> C,
+    success: impl FnMut(&N) -> bool,
+    beam_width: Option<usize>,
+    peak_frontier: &mut usize,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let (reached, found) =
+        astar_reached(start, successors, heuristic, success, beam_width, peak_frontier);
+    let index = found?;
+    Some((reached.path(index), reached.cost(index)))
+}
+
+/// Runs the search of [`astar`], returning every node it reached.
+///
+/// The search stops at the first node satisfying `success`, or once no
+/// node is left to expand. Callers can bound the search by returning no
+/// successors once their budget is used up.
+///
+/// # Returns
+/// The reached nodes with their cheapest known paths, and the index of the
+/// node satisfying `success`, if one was found.
+pub(crate) fn astar_reached<N, C, I>(
+    start: &N,
+    mut successors: impl FnMut(&N, C) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut success: impl FnMut(&N) -> bool,
+    beam_width: Option<usize>,
+    peak_frontier: &mut usize,
+) -> (Reached<N, C>, Option<usize>)
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut reached = Reached::new(start.clone(), C::default());
+    let mut to_see = BinaryHeap::new();
+    to_see.push(Candidate { estimate: C::default(), cost: C::default(), index: 0 });
+    *peak_frontier = (*peak_frontier).max(to_see.len());
+
+    while let Some(Candidate { cost, index, .. }) = to_see.pop() {
+        let (node, _, best_cost) = &reached.nodes[index];
+        if success(node) {
+            return (reached, Some(index));
+        }
+        // A node is pushed again whenever a cheaper path to it is found, so
+        // skip the entries of the paths that were improved upon
+        if cost > *best_cost {
+            continue;
+        }
+
+        for (successor, move_cost) in successors(node, cost) {
+            let new_cost = cost + move_cost;
+            if let Some(successor) = reached.relax(successor, index, new_cost) {
+                let estimate = new_cost + heuristic(&reached.nodes[successor].0);
+                to_see.push(Candidate { estimate, cost: new_cost, index: successor });
+            }
+        }
+        if let Some(width) = beam_width {
+            narrow(&mut to_see, width);
+        }
+        *peak_frontier = (*peak_frontier).max(to_see.len());
+    }
+
+    (reached, None)
+}
+
+/// Finds the cheapest path from `start` to a node satisfying `success` with
+/// iterative deepening A* (IDA*).
+///
+/// Each iteration is a depth-first search that abandons paths whose
+/// estimated total cost (cost so far plus `heuristic`) exceeds a threshold.
+/// The first threshold is the estimate of `start`, and each following one is
+/// the smallest estimate that exceeded the previous threshold. Only the
+/// current path and the unexplored siblings of its nodes are held in memory,
+/// at the price of expanding nodes again in every iteration and whenever
+/// they are reached by several paths. Paths never revisit a node on them
+/// (compared with `same`), so cycles are cut.
+///
+/// Like [`astar`], the returned path is the cheapest one if `heuristic` never
+/// overestimates the remaining cost.
+///
+/// # Arguments
+/// * `start` - The node to start from
+/// * `successors` - Returns the neighbors of a node, given the cost of the
+///   path to it, with the cost of moving there
+/// * `heuristic` - Estimates the remaining cost from a node to the goal
+/// * `success` - Whether a node is a goal
+/// * `same` - Whether two nodes stand for the same point of the search
+/// * `peak_frontier` - Raised to the largest number of nodes held at any
+///   point of the search: the current path and the siblings waiting to be
+///   explored
+///
+/// # Returns
+/// * `Some((path, total_cost))` with the path starting at `start`
+/// * `None` if no reachable node satisfies `success`
+pub(crate) fn ida_star<N, C, I>(
+    start: &N,
+    mut successors: impl FnMut(&N, C) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut success: impl FnMut(&N) -> bool,
+    same: impl Fn(&N, &N) -> bool,
+    peak_frontier: &mut usize,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    if success(start) {
+        return Some((alloc::vec![start.clone()], C::default()));
+    }
+
+    let mut threshold = heuristic(start);
+    loop {
+        // The smallest estimate beyond the threshold, if any path was cut
+        let mut next_threshold: Option<C> = None;
+        let mut path = alloc::vec![(start.clone(), C::default())];
+        let mut waiting =
+            alloc::vec![successors(start, C::default()).into_iter().collect::<Vec<_>>()];
+        *peak_frontier = (*peak_frontier).max(path.len() + waiting[0].len());
+
+        while let Some(siblings) = waiting.last_mut() {
+            let Some((node, move_cost)) = siblings.pop() else {
+                waiting.pop();
+                path.pop();
+                continue;
+            };
+            if path.iter().any(|(visited, _)| same(visited, &node)) {
+                continue;
+            }
+            let cost = path[path.len() - 1].1 + move_cost;
+            let estimate = cost + heuristic(&node);
+            if estimate > threshold {
+                next_threshold = Some(next_threshold.map_or(estimate, |next| next.min(estimate)));
+                continue;
+            }
+            if success(&node) {
+                path.push((node, cost));
+                return Some((path.into_iter().map(|(node, _)| node).collect(), cost));
+            }
+
+            // Siblings are popped from the back, so reverse them to explore
+            // them in the order they were generated
+            let mut children = successors(&node, cost).into_iter().collect::<Vec<_>>();
+            children.reverse();
+            path.push((node, cost));
+            waiting.push(children);
+            let held = path.len() + waiting.iter().map(Vec::len).sum::<usize>();
+            *peak_frontier = (*peak_frontier).max(held);
+        }
+
+        threshold = next_threshold?;
+    }
+}
+
+/// Drops the candidates with the largest estimates until at most `width` are
+/// left.
+///
+/// Dropped nodes stay reached, so they are only pushed again if a cheaper
+/// path to them is found.
+fn narrow<C: Ord>(to_see: &mut BinaryHeap<Candidate<C>>, width: usize) {
+    if to_see.len() <= width {
+        return;
+    }
+    let mut candidates = core::mem::take(to_see).into_vec();
+    // Candidates order the most promising last, so the worst ones come first
+    let dropped = candidates.len() - width;
+    if width > 0 {
+        candidates.select_nth_unstable(dropped);
+    }
+    candidates.drain(..dropped);
+    *to_see = BinaryHeap::from(candidates);
+}
+
+/// Finds the cheapest path from `start` to `goal` by searching forward from
+/// `start` and backward from `goal` at the same time.
+///
+/// The two searches expand nodes alternately, in order of cost like
+/// Dijkstra's algorithm. Backward nodes stand for sets of forward nodes: a
+/// forward node `meets` a backward node if the backward path from there leads
+/// to the goal. Whenever a forward and a backward node meet, `join` turns the
+/// backward path into forward nodes, and the cheapest joined path is kept
+/// until neither search can find a cheaper one.
+///
+/// The returned path is the cheapest one if the backward search is exact,
+/// i.e. if a forward node meets a backward node exactly when following the
+/// backward path from it reaches the goal at the backward cost.
+///
+/// # Arguments
+/// * `start` - The node to start from
+/// * `goal` - The backward node to start from
+/// * `forward` - Returns the neighbors of a forward node with the cost of moving there
+/// * `backward` - Returns the backward neighbors of a backward node with the cost of
+///   moving from them
+/// * `meets` - Whether a forward node meets a backward node
+/// * `join` - Returns the forward nodes following a forward node along a backward
+///   path (from the met node to `goal`) and their cost, or `None` if the path can't
+///   be followed
+/// * `peak_frontier` - Raised to the largest number of nodes waiting to be
+///   expanded by both searches at any point
+///
+/// # Returns
+/// * `Some((path, total_cost))` with the path starting at `start`
+/// * `None` if no forward node meets a backward node
+pub(crate) fn bidirectional<F, B, C, IF, IB>(
+    start: &F,
+    goal: &B,
+    mut forward: impl FnMut(&F) -> IF,
+    mut backward: impl FnMut(&B) -> IB,
+    mut meets: impl FnMut(&F, &B) -> bool,
+    mut join: impl FnMut(&F, &[B]) -> Option<(Vec<F>, C)>,
+    peak_frontier: &mut usize,
+) -> Option<(Vec<F>, C)>
+where
+    F: Eq + Hash + Clone,
+    B: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    IF: IntoIterator<Item = (F, C)>,
+    IB: IntoIterator<Item = (B, C)>,
+{
+    let mut forward_reached = Reached::new(start.clone(), C::default());
+    let mut backward_reached = Reached::new(goal.clone(), C::default());
+    let mut forward_to_see = BinaryHeap::new();
+    let mut backward_to_see = BinaryHeap::new();
+    forward_to_see.push(Candidate { estimate: C::default(), cost: C::default(), index: 0 });
+    backward_to_see.push(Candidate { estimate: C::default(), cost: C::default(), index: 0 });
+    *peak_frontier = (*peak_frontier).max(2);
+
+    let mut best: Option<(Vec<F>, C)> = None;
+    // Joins the paths through a forward and a backward node, keeping the cheapest
+    let mut try_join = |forward_reached: &Reached<F, C>,
+                        backward_reached: &Reached<B, C>,
+                        (forward_index, backward_index): (usize, usize),
+                        best: &mut Option<(Vec<F>, C)>| {
+        let (node, _, cost) = &forward_reached.nodes[forward_index];
+        let (backward_node, _, backward_cost) = &backward_reached.nodes[backward_index];
+        // Paths that can't beat the best one are skipped before checking if they meet
+        if best.as_ref().is_some_and(|(_, best_cost)| *best_cost <= *cost + *backward_cost)
+            || !meets(node, backward_node)
+        {
+            return;
+        }
+        let mut backward_path = backward_reached.path(backward_index);
+        backward_path.reverse();
+        let Some((rest, rest_cost)) = join(node, &backward_path) else {
+            return;
+        };
+        let total_cost = *cost + rest_cost;
+        if best.as_ref().is_none_or(|(_, best_cost)| total_cost < *best_cost) {
+            let mut path = forward_reached.path(forward_index);
+            path.extend(rest);
+            *best = Some((path, total_cost));
+        }
+    };
+    try_join(&forward_reached, &backward_reached, (0, 0), &mut best);
+
+    let mut expand_forward = true;
+    // Once the forward search is exhausted, every path has been joined
+    while let Some(forward_min) = forward_to_see.peek().map(|candidate| candidate.cost) {
+        // A path through unexpanded nodes costs at least both frontier minimums
+        let backward_min = backward_to_see.peek().map_or(C::default(), |candidate| candidate.cost);
+        if best.as_ref().is_some_and(|(_, best_cost)| *best_cost <= forward_min + backward_min) {
+            break;
+        }
+
+        if expand_forward || backward_to_see.is_empty() {
+            let Some(Candidate { cost, index, .. }) = forward_to_see.pop() else { break };
+            if cost > forward_reached.nodes[index].2 {
+                continue;
+            }
+            for (successor, move_cost) in forward(&forward_reached.nodes[index].0) {
+                let new_cost = cost + move_cost;
+                if let Some(successor) = forward_reached.relax(successor, index, new_cost) {
+                    forward_to_see.push(Candidate {
+                        estimate: new_cost,
+                        cost: new_cost,
+                        index: successor,
+                    });
+                    for backward_index in 0..backward_reached.nodes.len() {
+                        let indices = (successor, backward_index);
+                        try_join(&forward_reached, &backward_reached, indices, &mut best);
+                    }
+                }
+            }
+        } else {
+            let Some(Candidate { cost, index, .. }) = backward_to_see.pop() else { break };
+            if cost > backward_reached.nodes[index].2 {
+                continue;
+            }
+            for (predecessor, move_cost) in backward(&backward_reached.nodes[index].0) {
+                let new_cost = cost + move_cost;
+                if let Some(predecessor) = backward_reached.relax(predecessor, index, new_cost) {
+                    backward_to_see.push(Candidate {
+                        estimate: new_cost,
+                        cost: new_cost,
+                        index: predecessor,
+                    });
+                    for forward_index in 0..forward_reached.nodes.len() {
+                        let indices = (forward_index, predecessor);
+                        try_join(&forward_reached, &backward_reached, indices, &mut best);
+                    }
+                }
+            }
+        }
+        expand_forward = !expand_forward;
+        *peak_frontier = (*peak_frontier).max(forward_to_see.len() + backward_to_see.len());
+    }
+
+    best
+}
@@ -1,8 +1,29 @@
-pub use crate::action::Action;
+pub use crate::action::{Action, ActionSet, ActionTemplate, describe_actions};
 pub use crate::basic::assert::{Assert, compare_values};
+pub use crate::basic::expr::Expr;
 pub use crate::basic::mutation::{Mutation, apply_mutator, format_mutations};
-pub use crate::basic::value::Value;
+pub use crate::basic::value::{Value, ValueTypeError};
 pub use crate::effect::Effect;
+pub use crate::error::{ActionError, EffectError, PlanError};
 pub use crate::goal::Goal;
-pub use crate::plan::planner::{format_plan, get_effects_from_plan, make_plan};
+pub use crate::mutations;
+pub use crate::plan::Plan;
+#[cfg(feature = "std")]
+pub use crate::plan::cache::PlanCache;
+pub use crate::plan::executor::{PlanExecutor, StepResult};
+pub use crate::plan::node::Node;
+#[cfg(feature = "rayon")]
+pub use crate::plan::planner::make_plans_parallel;
+#[cfg(feature = "serde_json")]
+pub use crate::plan::planner::plan_to_json;
+pub use crate::plan::planner::{
+    HeuristicKind, PlanFormatter, PlanStats, PlanningConfig, PlanningStrategy, apply_plan,
+    assert_deterministic, effects_ref, enumerate_plan_steps, format_plan, get_effects_from_plan,
+    make_plan, make_plan_anytime, make_plan_filtered, make_plan_for_goals, make_plan_partial,
+    make_plan_partial_with_config, make_plan_with_config, make_plan_with_config_and_stats,
+    make_plan_with_heuristic, make_plan_with_stats, make_plan_with_strategy, plan_to_dot,
+    reorder_plan_for_display, replan, try_make_plan, validate_plan,
+};
 pub use crate::world_state::WorldState;
+#[cfg(feature = "serde_json")]
+pub use crate::world_state::WorldStateJsonError;
@@ -1,6 +1,12 @@
-use std::collections::BTreeMap;
-use std::hash::{Hash, Hasher};
+use alloc::collections::BTreeMap;
+use alloc::collections::btree_map::Entry;
+use alloc::sync::Arc;
+use alloc::{string::String, string::ToString, vec::Vec};
+use core::hash::{BuildHasher, Hash, Hasher};
 
+use rustc_hash::FxBuildHasher;
+
+use crate::basic::assert::{Assert, compare_values};
 use crate::basic::value::Value;
 use crate::goal::Goal;
 
@@ -40,8 +46,21 @@ use crate::goal::Goal;
 ///     .set("time_of_day", 14.5)  // 2:30 PM
 ///     .set("mission_complete", false);
 /// ```
-#[derive(Debug, Clone, Eq, PartialEq, Default)]
-pub struct WorldState(pub(super) BTreeMap<String, Value>);
+///
+/// # Sharing
+/// The underlying map is reference counted and copied on write, so cloning a
+/// `WorldState` is cheap and clones share their storage until one of them is
+/// modified. This keeps the many states explored by the planner inexpensive.
+///
+/// Each state also keeps a running hash of its variables, updated whenever a
+/// variable changes, so that hashing and comparing the states the planner
+/// explores doesn't need to walk the whole map.
+#[derive(Clone, Default)]
+pub struct WorldState(
+    pub(super) Arc<BTreeMap<String, Value>>,
+    /// Wrapping sum of the hashes of every entry, see [`entry_hash`].
+    u64,
+);
 
 impl WorldState {
     /// Creates a new empty world state.
@@ -61,7 +80,7 @@ impl WorldState {
     /// // The state is truly empty - no variables defined
     /// ```
     pub fn new() -> Self {
-        Self(BTreeMap::new())
+        Self(Arc::new(BTreeMap::new()), 0)
     }
 
     /// Sets or updates a variable in the world state.
@@ -91,10 +110,43 @@ impl WorldState {
     /// let updated_state = state.set("health", 85);  // Health reduced to 85
     /// ```
     pub fn set(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
-        self.0.insert(key.into(), value.into());
+        let (key, value) = (key.into(), value.into());
+        self.1 = self.1.wrapping_add(entry_hash(&key, &value));
+        match Arc::make_mut(&mut self.0).entry(key) {
+            Entry::Occupied(mut entry) => {
+                let old = entry.insert(value);
+                self.1 = self.1.wrapping_sub(entry_hash(entry.key(), &old));
+            },
+            Entry::Vacant(entry) => {
+                entry.insert(value);
+            },
+        }
         self
     }
 
+    /// Marks a variable as explicitly unset by storing [`Value::Null`].
+    ///
+    /// The key stays in the state, so `Assert::exists()` still holds for it,
+    /// while `Assert::absent()` and ordering assertions don't.
+    ///
+    /// # Arguments
+    /// * `key` - The name of the variable to unset
+    ///
+    /// # Returns
+    /// A new `WorldState` instance with the variable set to null (for method chaining).
+    ///
+    /// # Example
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let state = WorldState::new().set("target", 7).unset("target");
+    /// assert_eq!(state.get("target"), Some(&Value::Null));
+    /// assert_ne!(state, WorldState::new());
+    /// ```
+    pub fn unset(self, key: impl Into<String>) -> Self {
+        self.set(key, Value::Null)
+    }
+
     /// Calculates the heuristic distance from this world state to a goal.
     ///
     /// This method is used by the A* planning algorithm to estimate how far
@@ -104,9 +156,13 @@ impl WorldState {
     ///
     /// # Distance Calculation
     /// - For each goal requirement, find the corresponding world state value
-    /// - If the value exists: calculate type-specific distance (see `Value::distance`)
-    /// - If the value doesn't exist: apply a penalty of 1
-    /// - Sum all distances to get total heuristic distance
+    /// - If the value exists: measure how far it is from satisfying the
+    ///   requirement (see [`Assert::distance_to`](crate::prelude::Assert::distance_to)),
+    ///   which is 0 for satisfied requirements
+    /// - If the value doesn't exist: apply a penalty of 1 (see
+    ///   [`WorldState::distance_to_goal_with_penalty`] for other penalties),
+    ///   unless the requirement is satisfied by the key being absent
+    /// - Sum all distances to get total heuristic distance, saturating at `u64::MAX`
     ///
     /// # Arguments
     /// * `goal` - The goal to measure distance to
@@ -134,17 +190,119 @@ impl WorldState {
     /// // Calculate distance (health: 20 away, weapon: satisfied, ammo: 5 away)
     /// let distance = state.distance_to_goal(&goal);
     /// // distance = 20 (health) + 0 (weapon) + 5 (ammo) = 25
+    /// assert_eq!(distance, 25);
+    ///
+    /// // Requirements already satisfied add nothing, even if the value differs
+    /// let goal = Goal::new().with("health", Assert::lt_eq(100)).with("ammo", Assert::not_eq(0));
+    /// assert_eq!(state.distance_to_goal(&goal), 0);
+    /// ```
+    ///
+    /// A `not_eq` requirement only adds distance while the value equals the
+    /// excluded one, however far the value is from it:
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let goal = Goal::new().with("alarm_level", Assert::not_eq(0));
+    ///
+    /// assert_eq!(WorldState::new().set("alarm_level", 0).distance_to_goal(&goal), 1);
+    /// assert_eq!(WorldState::new().set("alarm_level", 1).distance_to_goal(&goal), 0);
+    /// assert_eq!(WorldState::new().set("alarm_level", -500).distance_to_goal(&goal), 0);
     /// ```
     pub fn distance_to_goal(&self, goal: &Goal) -> u64 {
+        self.distance_to_goal_with_penalty(goal, 1)
+    }
+
+    /// Calculates the heuristic distance to a goal like
+    /// [`WorldState::distance_to_goal`], with a custom penalty for required
+    /// keys missing from the state.
+    ///
+    /// With the default penalty of 1, a state missing a key that must reach
+    /// `gt_eq(100)` looks much closer to the goal than a state holding that key
+    /// at 0, which can lure the search into exploring states that never create
+    /// the key. A penalty close to the magnitude of the required values avoids
+    /// this. Since the distance heuristic measures value units rather than
+    /// costs, it is not admissible in general, and larger penalties make
+    /// overestimating the remaining cost (and thus suboptimal plans) more likely.
+    ///
+    /// # Arguments
+    /// * `goal` - The goal to measure distance to
+    /// * `penalty` - The distance added for each required key that is missing
+    ///   (unless the requirement is satisfied by the key being absent)
+    ///
+    /// # Returns
+    /// The total heuristic distance to the goal, saturating at `u64::MAX`.
+    ///
+    /// # Example
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let goal = Goal::new().with("gold", Assert::gt_eq(100)).with("has_map", Assert::eq(true));
+    /// let state = WorldState::new().set("has_map", false);
+    ///
+    /// assert_eq!(state.distance_to_goal(&goal), 2);
+    /// assert_eq!(state.distance_to_goal_with_penalty(&goal, 100), 101);
+    /// ```
+    pub fn distance_to_goal_with_penalty(&self, goal: &Goal, penalty: u64) -> u64 {
+        self.weighted_distance_with_penalty(goal, &BTreeMap::new(), penalty)
+    }
+
+    /// Calculates the heuristic distance to a goal like
+    /// [`WorldState::distance_to_goal`], with each key's contribution
+    /// multiplied by a weight.
+    ///
+    /// Weights express how much the requirements matter relative to each
+    /// other: a weight of 10 on `has_key` makes a missing key count as much
+    /// as being 10 units of `distance` away. Keys without a weight count once.
+    ///
+    /// # Arguments
+    /// * `goal` - The goal to measure distance to
+    /// * `weights` - The weight of each key, defaulting to 1
+    ///
+    /// # Returns
+    /// The total weighted distance to the goal, saturating at `u64::MAX`.
+    ///
+    /// # Example
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use rust_goap::prelude::*;
+    ///
+    /// let goal = Goal::new().with("has_key", Assert::eq(true)).with("distance", Assert::eq(0));
+    /// let near_without_key = WorldState::new().set("has_key", false).set("distance", 0);
+    /// let far_with_key = WorldState::new().set("has_key", true).set("distance", 1);
+    ///
+    /// // Both states are 1 unit away from the goal
+    /// assert_eq!(near_without_key.distance_to_goal(&goal), 1);
+    /// assert_eq!(far_with_key.distance_to_goal(&goal), 1);
+    ///
+    /// // But holding the key matters more
+    /// let weights = BTreeMap::from([("has_key".to_string(), 10)]);
+    /// assert_eq!(near_without_key.weighted_distance_to_goal(&goal, &weights), 10);
+    /// assert_eq!(far_with_key.weighted_distance_to_goal(&goal, &weights), 1);
+    /// ```
+    pub fn weighted_distance_to_goal(&self, goal: &Goal, weights: &BTreeMap<String, u64>) -> u64 {
+        self.weighted_distance_with_penalty(goal, weights, 1)
+    }
+
+    /// Combines [`WorldState::weighted_distance_to_goal`] and
+    /// [`WorldState::distance_to_goal_with_penalty`]: the penalty for a
+    /// missing key is multiplied by its weight too.
+    pub(crate) fn weighted_distance_with_penalty(
+        &self,
+        goal: &Goal,
+        weights: &BTreeMap<String, u64>,
+        penalty: u64,
+    ) -> u64 {
         goal.requirements
             .iter()
             .map(|(key, goal_val)| {
-                match self.0.get(key) {
-                    Some(state_val) => state_val.distance(&goal_val.value()),
-                    None => 1, // Penalty for missing keys
-                }
+                let distance = match self.0.get(key) {
+                    Some(state_val) => goal_val.distance_to(state_val),
+                    None if goal_val.holds_when_missing() => 0,
+                    None => penalty,
+                };
+                distance.saturating_mul(weights.get(key).copied().unwrap_or(1))
             })
-            .sum()
+            .fold(0, u64::saturating_add)
     }
 
     /// Retrieves the value of a variable from the world state.
@@ -260,9 +418,477 @@ impl WorldState {
     /// // Variables are iterated in alphabetical order: ammo, health, stamina
     /// assert_eq!(variables.len(), 3);
     /// ```
-    pub fn iter(&self) -> std::collections::btree_map::Iter<'_, String, Value> {
+    pub fn iter(&self) -> alloc::collections::btree_map::Iter<'_, String, Value> {
         self.0.iter()
     }
+
+    /// Returns a new world state containing only the given keys.
+    ///
+    /// Keys that aren't present in this state are ignored, so the result may
+    /// hold fewer variables than `keys` lists.
+    ///
+    /// # Arguments
+    /// * `keys` - The names of the variables to keep
+    ///
+    /// # Returns
+    /// A new `WorldState` with the selected variables and their values.
+    ///
+    /// # Example
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let state = WorldState::new().set("health", 80).set("ammo", 12).set("gold", 300);
+    ///
+    /// let combat = state.subset(&["health", "ammo", "armor"]);
+    /// assert_eq!(combat, WorldState::new().set("health", 80).set("ammo", 12));
+    /// assert!(state.subset(&[]).is_empty());
+    /// ```
+    pub fn subset(&self, keys: &[&str]) -> WorldState {
+        keys.iter()
+            .filter_map(|key| self.0.get_key_value(*key))
+            .map(|(key, value)| (key.clone(), *value))
+            .collect()
+    }
+
+    /// Counts the variables whose value satisfies an assertion.
+    ///
+    /// Values of a type the assertion can't be compared with are skipped, so
+    /// they are never counted, not even by `Assert::not_eq`. Numbers of either
+    /// type are compared with each other.
+    ///
+    /// # Arguments
+    /// * `assert` - The assertion to check every value against
+    ///
+    /// # Returns
+    /// The number of variables satisfying the assertion.
+    ///
+    /// # Example
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let state = WorldState::new()
+    ///     .set("wood", 40)
+    ///     .set("stone", 5)
+    ///     .set("gold", 12.5)
+    ///     .set("has_mine", true)
+    ///     .unset("iron");
+    ///
+    /// // How many resources are above the threshold
+    /// assert_eq!(state.count_matching(&Assert::gt(10)), 2);
+    /// assert_eq!(state.count_matching(&Assert::all([Assert::gt_eq(5), Assert::lt(20)])), 2);
+    ///
+    /// // The boolean and null values are skipped rather than counted as different
+    /// assert_eq!(state.count_matching(&Assert::not_eq(5)), 2);
+    /// assert_eq!(state.count_matching(&Assert::eq(true)), 1);
+    /// assert_eq!(state.count_matching(&Assert::exists()), 5);
+    /// ```
+    pub fn count_matching(&self, assert: &Assert) -> usize {
+        self.0
+            .values()
+            .filter(|value| assert.is_comparable_with(value) && compare_values(assert, value))
+            .count()
+    }
+
+    /// Checks whether two states are equal once the `ignore`d variables are
+    /// left out.
+    ///
+    /// A variable counts as different if its values differ or if only one
+    /// state has it, unless it is ignored.
+    ///
+    /// # Arguments
+    /// * `other` - The state to compare with
+    /// * `ignore` - Keys of variables that don't matter
+    ///
+    /// # Returns
+    /// `true` if both states hold the same variables with equal values, apart
+    /// from the ignored ones, `false` otherwise.
+    ///
+    /// # Example
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let state = WorldState::new().set("frame", 120).set("health", 80);
+    ///
+    /// // Only the ignored frame counter differs, or is missing
+    /// assert!(state.eq_ignoring(&state.clone().set("frame", 121), &["frame"]));
+    /// assert!(state.eq_ignoring(&WorldState::new().set("health", 80), &["frame"]));
+    ///
+    /// // A relevant variable differs
+    /// assert!(!state.eq_ignoring(&state.clone().set("health", 60), &["frame"]));
+    /// assert!(!state.eq_ignoring(&state.clone().set("ammo", 3), &["frame"]));
+    /// assert!(!state.eq_ignoring(&state.clone().set("frame", 121), &[]));
+    /// ```
+    pub fn eq_ignoring(&self, other: &WorldState, ignore: &[&str]) -> bool {
+        let relevant = |(key, _): &(&String, &Value)| !ignore.contains(&key.as_str());
+        self.0.iter().filter(relevant).eq(other.0.iter().filter(relevant))
+    }
+
+    /// Computes a checksum of the world state that is stable across runs,
+    /// processes and platforms.
+    ///
+    /// Unlike the [`Hash`] implementation, whose result depends on the hasher
+    /// (and, for the standard `DefaultHasher`, may change between Rust
+    /// versions), this always uses 64-bit FNV-1a over the variables in key
+    /// order. Each key is encoded as its length and UTF-8 bytes, and each
+    /// value as a type tag and its little-endian bytes, with floats encoded
+    /// by [`f64::to_bits`]. This makes checksums comparable between machines,
+    /// e.g. to detect a server and client whose states have diverged.
+    ///
+    /// Equal states always have equal checksums, including states holding
+    /// `0.0` and `-0.0`, or different `NaN`s. Differing states almost always
+    /// have different checksums, but collisions are possible.
+    ///
+    /// # Returns
+    /// The 64-bit checksum.
+    ///
+    /// # Example
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let state = WorldState::new().set("health", 80).set("speed", 1.5).set("is_alive", true);
+    ///
+    /// // The order variables were set in doesn't matter
+    /// let same = WorldState::new().set("is_alive", true).set("speed", 1.5).set("health", 80);
+    /// assert_eq!(state.checksum(), same.checksum());
+    ///
+    /// // The checksum is fixed, so it can be compared with one computed elsewhere
+    /// assert_eq!(WorldState::new().checksum(), 0xcbf2_9ce4_8422_2325);
+    /// assert_eq!(WorldState::new().set("health", 80).checksum(), 0x984e_84ea_0a49_f965);
+    /// assert_eq!(state.checksum(), state.clone().checksum());
+    ///
+    /// // Any differing value, type or key changes it
+    /// assert_ne!(state.checksum(), state.clone().set("health", 79).checksum());
+    /// assert_ne!(state.checksum(), state.clone().set("health", 80.0).checksum());
+    /// assert_ne!(state.checksum(), state.clone().set("armor", 0).checksum());
+    /// assert_ne!(state.checksum(), state.clone().unset("speed").checksum());
+    /// ```
+    pub fn checksum(&self) -> u64 {
+        const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+        let mut hash = OFFSET_BASIS;
+        let mut write = |bytes: &[u8]| {
+            for byte in bytes {
+                hash = (hash ^ u64::from(*byte)).wrapping_mul(PRIME);
+            }
+        };
+        for (key, value) in self.0.iter() {
+            write(&(key.len() as u64).to_le_bytes());
+            write(key.as_bytes());
+            match value {
+                Value::Null => write(&[0]),
+                Value::Bool(b) => write(&[1, u8::from(*b)]),
+                Value::I64(i) => {
+                    write(&[2]);
+                    write(&i.to_le_bytes());
+                },
+                Value::F64(f) => {
+                    // Equal floats get equal bits: one NaN, and 0.0 for -0.0
+                    let f = if f.is_nan() { f64::NAN } else { f + 0.0 };
+                    write(&[3]);
+                    write(&f.to_bits().to_le_bytes());
+                },
+            }
+        }
+        hash
+    }
+
+    /// Checks whether this state holds every variable of `other` with an equal value.
+    ///
+    /// This state may contain additional variables. Every state is a superset
+    /// of itself and of the empty state.
+    ///
+    /// # Arguments
+    /// * `other` - The state whose variables must all be present here
+    ///
+    /// # Returns
+    /// `true` if all of `other`'s keys are present with equal values, `false` otherwise.
+    ///
+    /// # Example
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let state = WorldState::new().set("health", 80).set("ammo", 12).set("gold", 300);
+    ///
+    /// // Exact matches and partial overlaps with equal values
+    /// assert!(state.is_superset_of(&state));
+    /// assert!(state.is_superset_of(&WorldState::new().set("ammo", 12)));
+    /// assert!(state.is_superset_of(&WorldState::new()));
+    ///
+    /// // A differing value or a missing key
+    /// assert!(!state.is_superset_of(&WorldState::new().set("ammo", 11)));
+    /// assert!(!state.is_superset_of(&WorldState::new().set("ammo", 12).set("armor", 5)));
+    /// assert!(!WorldState::new().set("ammo", 12).is_superset_of(&state));
+    /// ```
+    pub fn is_superset_of(&self, other: &WorldState) -> bool {
+        other.0.iter().all(|(key, value)| self.0.get(key) == Some(value))
+    }
+
+    /// Counts the variables whose values differ between two world states.
+    ///
+    /// Unlike [`WorldState::distance_to_goal`], this ignores how far apart
+    /// the values are: every key present in either state counts once if its
+    /// values differ, including keys missing from one of the states. The
+    /// count is therefore bounded by the number of keys.
+    ///
+    /// # Arguments
+    /// * `other` - The state to compare with
+    ///
+    /// # Returns
+    /// The number of keys that are missing from one state or hold different
+    /// values in each.
+    ///
+    /// # Example
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let state = WorldState::new().set("health", 80).set("ammo", 12).set("has_key", false);
+    ///
+    /// // Identical states
+    /// assert_eq!(state.hamming_distance(&state.clone()), 0);
+    ///
+    /// // One differing value, however far apart
+    /// assert_eq!(state.hamming_distance(&state.clone().set("health", 10)), 1);
+    /// assert_eq!(state.hamming_distance(&state.clone().set("health", 79)), 1);
+    ///
+    /// // Keys present on only one side
+    /// assert_eq!(state.hamming_distance(&state.clone().set("gold", 5)), 1);
+    /// assert_eq!(state.hamming_distance(&state.clone().unset("ammo")), 1);
+    /// assert_eq!(state.hamming_distance(&WorldState::new()), 3);
+    /// ```
+    pub fn hamming_distance(&self, other: &WorldState) -> usize {
+        let differing =
+            self.0.iter().filter(|(key, value)| other.0.get(*key) != Some(value)).count();
+        let added = other.0.keys().filter(|key| !self.0.contains_key(*key)).count();
+        differing + added
+    }
+
+    /// Modifies a variable in place, inserting a default value first if it
+    /// doesn't exist.
+    ///
+    /// This avoids looking the key up once to check for it and again to set
+    /// it, which is handy when accumulating values in a loop.
+    ///
+    /// # Arguments
+    /// * `key` - The name of the variable to update
+    /// * `default` - The value to insert if the variable doesn't exist
+    /// * `f` - Function applied to the stored value
+    ///
+    /// # Example
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let mut state = WorldState::new().set("gold", 10);
+    ///
+    /// // Modify an existing value
+    /// state.update("gold", Value::I64(0), |gold| *gold += Value::I64(5));
+    /// assert_eq!(state.get("gold"), Some(&Value::I64(15)));
+    ///
+    /// // Insert the default, then modify it
+    /// for _ in 0..3 {
+    ///     state.update("kills", Value::I64(0), |kills| *kills += Value::I64(1));
+    /// }
+    /// assert_eq!(state.get("kills"), Some(&Value::I64(3)));
+    ///
+    /// // The default alone is stored if `f` leaves it unchanged
+    /// state.update("has_key", Value::Bool(false), |_| {});
+    /// assert_eq!(state.get("has_key"), Some(&Value::Bool(false)));
+    /// ```
+    pub fn update(&mut self, key: &str, default: Value, f: impl FnOnce(&mut Value)) {
+        let map = Arc::make_mut(&mut self.0);
+        let value = match map.get_mut(key) {
+            Some(value) => {
+                self.1 = self.1.wrapping_sub(entry_hash(key, value));
+                value
+            },
+            None => map.entry(key.to_string()).or_insert(default),
+        };
+        f(value);
+        self.1 = self.1.wrapping_add(entry_hash(key, value));
+    }
+
+    /// Keeps only the variables for which `f` returns `true`, like
+    /// [`BTreeMap::retain`].
+    ///
+    /// Variables are visited in key order, and the rest are removed.
+    ///
+    /// # Arguments
+    /// * `f` - Predicate called with each variable's key and value
+    ///
+    /// # Example
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let mut state = WorldState::new()
+    ///     .set("health", 80)
+    ///     .set("tmp_path_length", 12)
+    ///     .set("tmp_visited", true)
+    ///     .set("wood", 3);
+    ///
+    /// // Drop the scratch keys left over from planning
+    /// state.retain(|key, _| !key.starts_with("tmp_"));
+    /// assert_eq!(state, WorldState::new().set("health", 80).set("wood", 3));
+    ///
+    /// // Keep only the keys with a given prefix
+    /// let mut state = WorldState::new().set("enemy_a", 1).set("enemy_b", 2).set("ally_a", 3);
+    /// state.retain(|key, _| key.starts_with("enemy_"));
+    /// assert_eq!(state.iter().map(|(key, _)| key.as_str()).collect::<Vec<_>>(), ["enemy_a", "enemy_b"]);
+    /// ```
+    pub fn retain(&mut self, mut f: impl FnMut(&str, &Value) -> bool) {
+        let hash = &mut self.1;
+        Arc::make_mut(&mut self.0).retain(|key, value| {
+            let keep = f(key, value);
+            if !keep {
+                *hash = hash.wrapping_sub(entry_hash(key, value));
+            }
+            keep
+        });
+    }
+
+    /// Converts the world state to a flat JSON object.
+    ///
+    /// Every variable becomes a member of the object, holding a JSON boolean
+    /// or number. `F64` values are written with a fractional part (`3.0`
+    /// rather than `3`), so they read back as `F64`; see
+    /// [`from_json`](Self::from_json). Non-finite floats and `Null` values are
+    /// written as `null`.
+    ///
+    /// This function is only available with the `serde_json` feature.
+    ///
+    /// # Returns
+    /// The world state as a JSON string, with keys in alphabetical order
+    ///
+    /// # Example
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let state = WorldState::new().set("health", 100).set("hungry", true).set("speed", 3.0);
+    /// assert_eq!(state.to_json(), r#"{"health":100,"hungry":true,"speed":3.0}"#);
+    /// ```
+    #[cfg(feature = "serde_json")]
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        self.to_json_value().to_string()
+    }
+
+    /// Parses a world state from a flat JSON object.
+    ///
+    /// Each member of the object becomes a variable. Booleans become `Bool`
+    /// values and `null` becomes `Null`. A number becomes an `I64` if it is
+    /// written as an integer that fits in an `i64`, and an `F64` otherwise:
+    /// `3` is an `I64`, while `3.0`, `3e0` and `1e20` are `F64` values. The
+    /// type depends on how the number is written, not on whether its value
+    /// happens to be integral, so [`to_json`](Self::to_json) output reads
+    /// back as the same state.
+    ///
+    /// This function is only available with the `serde_json` feature.
+    ///
+    /// # Arguments
+    /// * `json` - A JSON object mapping keys to booleans, numbers or `null`
+    ///
+    /// # Returns
+    /// * `Ok(state)` with the parsed world state
+    /// * `Err(WorldStateJsonError::Parse(_))` if `json` isn't a valid JSON object
+    /// * `Err(WorldStateJsonError::UnsupportedValue { .. })` if a member holds
+    ///   a string, an array or an object
+    ///
+    /// # Example
+    /// ```
+    /// use rust_goap::prelude::*;
+    ///
+    /// let state = WorldState::from_json(r#"{"health": 100, "hungry": true, "speed": 3.0}"#).unwrap();
+    /// assert_eq!(state.get("health"), Some(&Value::I64(100)));
+    /// assert_eq!(state.get("hungry"), Some(&Value::Bool(true)));
+    /// assert_eq!(state.get("speed"), Some(&Value::F64(3.0)));
+    ///
+    /// // Round trip, including a float that happens to be integral
+    /// let state = WorldState::new().set("ammo", 3).set("distance", 3.0).set("armed", false);
+    /// assert_eq!(WorldState::from_json(&state.to_json()).unwrap(), state);
+    ///
+    /// assert!(matches!(WorldState::from_json("[1, 2]"), Err(WorldStateJsonError::Parse(_))));
+    /// assert_eq!(
+    ///     WorldState::from_json(r#"{"name": "hero"}"#).unwrap_err(),
+    ///     WorldStateJsonError::UnsupportedValue { key: "name".to_string() }
+    /// );
+    /// ```
+    #[cfg(feature = "serde_json")]
+    pub fn from_json(json: &str) -> Result<WorldState, WorldStateJsonError> {
+        let object: serde_json::Map<String, serde_json::Value> = serde_json::from_str(json)
+            .map_err(|error| WorldStateJsonError::Parse(error.to_string()))?;
+        object
+            .into_iter()
+            .map(|(key, value)| {
+                let value = match value {
+                    serde_json::Value::Bool(b) => Value::Bool(b),
+                    serde_json::Value::Number(number) => match number.as_i64() {
+                        Some(i) => Value::I64(i),
+                        None => Value::F64(number.as_f64().unwrap_or(f64::NAN)),
+                    },
+                    serde_json::Value::Null => Value::Null,
+                    _ => return Err(WorldStateJsonError::UnsupportedValue { key }),
+                };
+                Ok((key, value))
+            })
+            .collect()
+    }
+
+    /// Converts the world state to a flat JSON object.
+    #[cfg(feature = "serde_json")]
+    pub(crate) fn to_json_value(&self) -> serde_json::Value {
+        self.0.iter().map(|(key, value)| (key.clone(), value.to_json_value())).collect()
+    }
+
+    /// Stores `value` under `key`, copying the shared map only if the state
+    /// actually changes.
+    pub(crate) fn put(&mut self, key: &str, value: Value) {
+        if self.0.get(key) == Some(&value) {
+            return;
+        }
+        self.1 = self.1.wrapping_add(entry_hash(key, &value));
+        let map = Arc::make_mut(&mut self.0);
+        match map.get_mut(key) {
+            Some(current) => {
+                self.1 = self.1.wrapping_sub(entry_hash(key, current));
+                *current = value;
+            },
+            None => {
+                map.insert(key.to_string(), value);
+            },
+        }
+    }
+
+    /// Removes `key`, copying the shared map only if the key is present.
+    pub(crate) fn remove(&mut self, key: &str) {
+        if self.0.contains_key(key)
+            && let Some(old) = Arc::make_mut(&mut self.0).remove(key)
+        {
+            self.1 = self.1.wrapping_sub(entry_hash(key, &old));
+        }
+    }
+}
+
+/// Hashes a single variable of a world state.
+///
+/// World states sum the hashes of their entries, which doesn't depend on the
+/// order the variables were set in and lets a single entry be replaced by
+/// subtracting its old hash and adding the new one.
+fn entry_hash(key: &str, value: &Value) -> u64 {
+    FxBuildHasher.hash_one((key, value))
+}
+
+impl PartialEq for WorldState {
+    fn eq(&self, other: &Self) -> bool {
+        // Equal values hash equally, so differing hashes rule out equality
+        self.1 == other.1 && (Arc::ptr_eq(&self.0, &other.0) || self.0 == other.0)
+    }
+}
+
+impl Eq for WorldState {}
+
+impl core::fmt::Debug for WorldState {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("WorldState").field(&self.0).finish()
+    }
 }
 
 impl Hash for WorldState {
@@ -272,6 +898,10 @@ impl Hash for WorldState {
     /// 1. The number of variables (for quick differentiation of different-sized states)
     /// 2. Each key-value pair (ensuring different variable sets hash differently)
     ///
+    /// The key-value pairs are not hashed again on every call: the state keeps
+    /// a running sum of their hashes, which is updated whenever a variable is
+    /// set, mutated or removed, so hashing takes constant time.
+    ///
     /// This enables using `WorldState` instances as keys in hash-based collections,
     /// which is crucial for the planning algorithm's state caching and duplicate detection.
     ///
@@ -309,12 +939,43 @@ impl Hash for WorldState {
     /// assert_eq!(hasher1.finish(), hasher2.finish()); // Same content
     /// assert_ne!(hasher1.finish(), hasher3.finish()); // Different content
     /// ```
+    ///
+    /// States reached through any series of changes hash like states built
+    /// from scratch with the same variables:
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::{Hash, Hasher};
+    /// use rust_goap::prelude::*;
+    ///
+    /// let hash = |state: &WorldState| {
+    ///     let mut hasher = DefaultHasher::new();
+    ///     state.hash(&mut hasher);
+    ///     hasher.finish()
+    /// };
+    ///
+    /// let mut state = WorldState::new().set("gold", 10).set("wood", 3).set("has_axe", false);
+    /// state = state.set("has_axe", true);
+    /// state.update("gold", Value::I64(0), |gold| *gold += Value::I64(5));
+    /// state.update("stone", Value::I64(2), |_| {});
+    /// Effect::new()
+    ///     .add_mutation(Mutation::delete("wood"))
+    ///     .add_mutation(Mutation::decrement("gold", 7))
+    ///     .add_mutation(Mutation::set("iron", 1.5))
+    ///     .apply_to(&mut state);
+    ///
+    /// let fresh = WorldState::from([
+    ///     ("gold", Value::I64(8)),
+    ///     ("has_axe", Value::Bool(true)),
+    ///     ("iron", Value::F64(1.5)),
+    ///     ("stone", Value::I64(2)),
+    /// ]);
+    /// assert_eq!(state, fresh);
+    /// assert_eq!(hash(&state), hash(&fresh));
+    /// assert_ne!(hash(&state), hash(&fresh.set("stone", 3)));
+    /// ```
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.0.len().hash(state);
-        for (key, value) in &self.0 {
-            key.hash(state);
-            value.hash(state);
-        }
+        self.1.hash(state);
     }
 }
 
@@ -337,11 +998,56 @@ impl Hash for WorldState {
 /// ```
 impl From<Vec<(String, Value)>> for WorldState {
     fn from(pairs: Vec<(String, Value)>) -> Self {
-        let mut state = WorldState::new();
-        for (key, value) in pairs {
-            state = state.set(key, value);
-        }
-        state
+        pairs.into_iter().collect()
+    }
+}
+
+/// Creates a world state from an array of key-value pairs.
+///
+/// Keys can be any type convertible into a `String` and values any type
+/// convertible into a [`Value`]. If a key appears more than once, the last
+/// value wins.
+///
+/// # Example
+/// ```
+/// use rust_goap::prelude::*;
+///
+/// let state = WorldState::from([("health", 100), ("ammo", 50)]);
+///
+/// assert_eq!(state.len(), 2);
+/// assert_eq!(state.get("health"), Some(&Value::I64(100)));
+/// ```
+impl<K: Into<String>, V: Into<Value>, const N: usize> From<[(K, V); N]> for WorldState {
+    fn from(pairs: [(K, V); N]) -> Self {
+        pairs.into_iter().map(|(key, value)| (key, value.into())).collect()
+    }
+}
+
+/// Collects key-value pairs into a world state.
+///
+/// Keys can be any type convertible into a `String`. If a key appears more
+/// than once, the last value wins.
+///
+/// # Example
+/// ```
+/// use rust_goap::prelude::*;
+/// use std::collections::HashMap;
+///
+/// let state = WorldState::from_iter([("hp", 10.into()), ("alive", true.into())]);
+/// assert_eq!(state, WorldState::new().set("hp", 10).set("alive", true));
+///
+/// // Collect only the numeric entries of a map
+/// let stats = HashMap::from([("hp", Value::I64(10)), ("alive", Value::Bool(true))]);
+/// let numeric: WorldState =
+///     stats.into_iter().filter(|(_, value)| !matches!(value, Value::Bool(_))).collect();
+/// assert_eq!(numeric, WorldState::new().set("hp", 10));
+/// ```
+impl<K: Into<String>> FromIterator<(K, Value)> for WorldState {
+    fn from_iter<I: IntoIterator<Item = (K, Value)>>(pairs: I) -> Self {
+        let map: BTreeMap<String, Value> =
+            pairs.into_iter().map(|(key, value)| (key.into(), value)).collect();
+        let hash = map.iter().map(|(key, value)| entry_hash(key, value)).fold(0, u64::wrapping_add);
+        WorldState(Arc::new(map), hash)
     }
 }
 
@@ -368,11 +1074,11 @@ impl From<Vec<(String, Value)>> for WorldState {
 /// assert!(output.contains("is_hero"));
 /// assert!(output.contains("active"));
 /// ```
-impl std::fmt::Display for WorldState {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for WorldState {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "WorldState {{ ")?;
         let mut first = true;
-        for (key, value) in &self.0 {
+        for (key, value) in self.0.iter() {
             if !first {
                 write!(f, ", ")?;
             }
@@ -382,3 +1088,41 @@ impl std::fmt::Display for WorldState {
         write!(f, " }}")
     }
 }
+
+/// Error returned by [`WorldState::from_json`].
+///
+/// This type is only available with the `serde_json` feature.
+///
+/// # Example
+/// ```
+/// use rust_goap::prelude::*;
+///
+/// let error = WorldState::from_json(r#"{"items": [1, 2]}"#).unwrap_err();
+/// assert_eq!(error.to_string(), "unsupported value for key \"items\": expected a boolean, a number or null");
+/// ```
+#[cfg(feature = "serde_json")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WorldStateJsonError {
+    /// The input isn't a valid JSON object; holds the parser's message
+    Parse(String),
+    /// A member of the object holds a string, an array or an object
+    UnsupportedValue {
+        /// The key of the member
+        key: String,
+    },
+}
+
+#[cfg(feature = "serde_json")]
+impl core::fmt::Display for WorldStateJsonError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            WorldStateJsonError::Parse(message) => write!(f, "invalid world state JSON: {message}"),
+            WorldStateJsonError::UnsupportedValue { key } => {
+                write!(f, "unsupported value for key {key:?}: expected a boolean, a number or null")
+            },
+        }
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl core::error::Error for WorldStateJsonError {}